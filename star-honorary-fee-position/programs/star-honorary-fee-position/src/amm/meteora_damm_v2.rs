@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use crate::amm::{
+    require_position_nft_held, with_owner_delegate, AmmAccounts, AmmAdapter, CloseAccounts, OpenAccounts, OpenedPosition,
+    PoolInfo, UnclaimedFees,
+};
+use crate::damm_v2;
+use crate::errors::ErrorCode;
+use crate::instructions::InitializePositionParams;
+use crate::state::PositionState;
+
+// Venue account layout (opening and position accounts alike):
+// [position, token_a_vault, token_b_vault, position_nft_account, pool_authority, event_authority]
+const POSITION: usize = 0;
+const TOKEN_A_VAULT: usize = 1;
+const TOKEN_B_VAULT: usize = 2;
+const POSITION_NFT_ACCOUNT: usize = 3;
+const POOL_AUTHORITY: usize = 4;
+const EVENT_AUTHORITY: usize = 5;
+
+/// Meteora DAMM v2 - quote-only by the pool's `OnlyB` collect-fee mode
+///
+/// DAMM v2 positions span the whole price range, so the quote mint is always
+/// token B and the base mint token A.
+pub struct MeteoraDammV2Adapter;
+
+impl MeteoraDammV2Adapter {
+    fn position_accounts<'a, 'info>(accounts: &AmmAccounts<'a, 'info>) -> damm_v2::PositionAccounts<'a, 'info> {
+        let venue = accounts.venue;
+        let (token_a, token_b) = (accounts.base(), accounts.quote());
+        
+        damm_v2::PositionAccounts {
+            pool_authority: &venue[POOL_AUTHORITY],
+            pool: accounts.pool,
+            position: &venue[POSITION],
+            token_a_account: token_a.account,
+            token_b_account: token_b.account,
+            token_a_vault: &venue[TOKEN_A_VAULT],
+            token_b_vault: &venue[TOKEN_B_VAULT],
+            token_a_mint: token_a.mint,
+            token_b_mint: token_b.mint,
+            position_nft_account: &venue[POSITION_NFT_ACCOUNT],
+            owner: accounts.position_owner,
+            token_a_program: token_a.token_program,
+            token_b_program: token_b.token_program,
+            event_authority: &venue[EVENT_AUTHORITY],
+        }
+    }
+    
+    fn require_program_authorities(venue: &[AccountInfo]) -> Result<()> {
+        require!(
+            venue[POOL_AUTHORITY].key() == damm_v2::pool_authority()
+                && venue[EVENT_AUTHORITY].key() == damm_v2::event_authority(),
+            ErrorCode::InvalidCpAmmAccount
+        );
+        
+        Ok(())
+    }
+}
+
+impl AmmAdapter for MeteoraDammV2Adapter {
+    const OPEN_ACCOUNTS: usize = 6;
+    const POSITION_ACCOUNTS: usize = 6;
+    
+    fn program_id() -> Pubkey {
+        damm_v2::program_id()
+    }
+    
+    fn read_pool(pool: &AccountInfo) -> Result<PoolInfo> {
+        let pool = damm_v2::read_pool(pool)?;
+        
+        Ok(PoolInfo {
+            token_mint_0: pool.token_a_mint,
+            token_mint_1: pool.token_b_mint,
+            token_vault_0: pool.token_a_vault,
+            token_vault_1: pool.token_b_vault,
+            sqrt_price: pool.sqrt_price,
+            tick_current: None,
+            tick_spacing: None,
+        })
+    }
+    
+    fn validate_quote_only(
+        pool_account: &AccountInfo,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        _params: &InitializePositionParams,
+    ) -> Result<()> {
+        let snapshot = damm_v2::read_pool(pool_account)?;
+        let quote_mint = if is_quote_token_0 { pool.token_mint_0 } else { pool.token_mint_1 };
+        
+        // CRITICAL VALIDATION: positions span the whole price range, so only a
+        // pool charging every fee in token B (the quote mint) qualifies
+        require!(
+            damm_v2::is_quote_only_pool(&snapshot, &quote_mint),
+            ErrorCode::PoolNotQuoteOnly
+        );
+        
+        msg!("DAMM v2 collect-fee mode: {}", snapshot.collect_fee_mode);
+        
+        Ok(())
+    }
+    
+    fn open_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        open: &OpenAccounts<'a, 'info>,
+        pool: &PoolInfo,
+        _is_quote_token_0: bool,
+        params: &InitializePositionParams,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<OpenedPosition> {
+        let venue = accounts.venue;
+        let nft_mint = open.position_nft_mint.key();
+        require!(
+            venue[POSITION].key() == damm_v2::position_address(&nft_mint)
+                && venue[POSITION_NFT_ACCOUNT].key() == damm_v2::position_nft_account_address(&nft_mint)
+                && venue[TOKEN_A_VAULT].key() == pool.token_vault_0
+                && venue[TOKEN_B_VAULT].key() == pool.token_vault_1,
+            ErrorCode::InvalidCpAmmAccount
+        );
+        Self::require_program_authorities(venue)?;
+        
+        // 1. Create the position - the NFT is minted to the position owner PDA
+        damm_v2::create_position(
+            accounts.amm_program,
+            damm_v2::CreatePosition {
+                owner: accounts.position_owner,
+                position_nft_mint: open.position_nft_mint,
+                position_nft_account: &venue[POSITION_NFT_ACCOUNT],
+                pool: accounts.pool,
+                position: &venue[POSITION],
+                pool_authority: &venue[POOL_AUTHORITY],
+                payer: open.payer,
+                token_program: accounts.token_program_2022,
+                system_program: open.system_program,
+                event_authority: &venue[EVENT_AUTHORITY],
+            },
+        )?;
+        
+        // 2. DAMM v2 pulls deposits from accounts the NFT owner controls
+        // (amount_0_max is token A / base, amount_1_max token B / quote)
+        with_owner_delegate(accounts, open.payer, params.amount_1_max, params.amount_0_max, || {
+            damm_v2::add_liquidity(
+                accounts.amm_program,
+                Self::position_accounts(accounts),
+                params.liquidity,
+                params.amount_0_max,
+                params.amount_1_max,
+                signer_seeds,
+            )
+        })?;
+        
+        // Tick fields do not apply to DAMM v2
+        Ok(OpenedPosition {
+            position_id: venue[POSITION].key(),
+            liquidity: damm_v2::read_position_liquidity(&venue[POSITION])?,
+            ..OpenedPosition::default()
+        })
+    }
+    
+    fn validate_position_accounts(
+        venue: &[AccountInfo],
+        position: &PositionState,
+        position_owner: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            venue[POSITION].key() == position.position_id,
+            ErrorCode::PositionAccountsMismatch
+        );
+        Self::require_program_authorities(venue)?;
+        
+        require_position_nft_held(&venue[POSITION_NFT_ACCOUNT], position, position_owner)
+    }
+    
+    fn read_unclaimed_fees(venue: &[AccountInfo], _position: &PositionState) -> Result<UnclaimedFees> {
+        let (fee_a, fee_b) = damm_v2::read_position_pending_fees(&venue[POSITION])?;
+        
+        Ok(UnclaimedFees { quote: fee_b, base: fee_a })
+    }
+    
+    fn collect_fees<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        _position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        damm_v2::claim_position_fee(accounts.amm_program, Self::position_accounts(accounts), signer_seeds)
+    }
+    
+    fn withdraw_all<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        _position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u128> {
+        let liquidity = damm_v2::read_position_liquidity(&accounts.venue[POSITION])?;
+        damm_v2::remove_all_liquidity(accounts.amm_program, Self::position_accounts(accounts), signer_seeds)?;
+        
+        Ok(liquidity)
+    }
+    
+    fn close_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        close: &CloseAccounts<'a, 'info>,
+        _position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let venue = accounts.venue;
+        
+        damm_v2::close_position(
+            accounts.amm_program,
+            damm_v2::ClosePosition {
+                position_nft_mint: close.position_nft_mint,
+                position_nft_account: &venue[POSITION_NFT_ACCOUNT],
+                pool: accounts.pool,
+                position: &venue[POSITION],
+                pool_authority: &venue[POOL_AUTHORITY],
+                rent_receiver: close.rent_receiver,
+                owner: accounts.position_owner,
+                token_program: accounts.token_program_2022,
+                event_authority: &venue[EVENT_AUTHORITY],
+            },
+            signer_seeds,
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,382 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token, token_2022};
+use anchor_spl::token_interface::{self, Approve, Revoke, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::InitializePositionParams;
+use crate::state::{AmmKind, PositionState};
+
+mod meteora_damm_v2;
+mod orca_whirlpool;
+mod raydium_clmm;
+
+pub use meteora_damm_v2::MeteoraDammV2Adapter;
+pub use orca_whirlpool::OrcaWhirlpoolAdapter;
+pub use raydium_clmm::RaydiumClmmAdapter;
+
+/// Pool fields every venue exposes
+#[derive(Debug, Clone)]
+pub struct PoolInfo {
+    /// Token 0 mint (token A on DAMM v2 and Whirlpool)
+    pub token_mint_0: Pubkey,
+    
+    /// Token 1 mint (token B on DAMM v2 and Whirlpool)
+    pub token_mint_1: Pubkey,
+    
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+    
+    /// Current sqrt price (Q64.64)
+    pub sqrt_price: u128,
+    
+    /// Current tick (concentrated-liquidity venues only)
+    pub tick_current: Option<i32>,
+    
+    /// Tick spacing (concentrated-liquidity venues only)
+    pub tick_spacing: Option<u16>,
+}
+
+/// Fees accrued to a position but not yet collected, as last recorded by the venue
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnclaimedFees {
+    pub quote: u64,
+    pub base: u64,
+}
+
+/// Position accounts to persist once a position is opened
+#[derive(Debug, Clone, Default)]
+pub struct OpenedPosition {
+    pub position_id: Pubkey,
+    pub personal_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+}
+
+/// Accounts every venue call needs, supplied by the calling instruction
+pub struct AmmAccounts<'a, 'info> {
+    pub amm_program: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    
+    /// Position owner PDA (holds the position NFT and signs for it)
+    pub position_owner: &'a AccountInfo<'info>,
+    
+    pub quote_mint: &'a AccountInfo<'info>,
+    pub base_mint: &'a AccountInfo<'info>,
+    
+    /// Quote token account receiving fees and withdrawals (or funding deposits)
+    pub quote_account: &'a AccountInfo<'info>,
+    
+    /// Base token account receiving fees and withdrawals (or funding deposits)
+    pub base_account: &'a AccountInfo<'info>,
+    
+    pub quote_token_program: &'a AccountInfo<'info>,
+    pub base_token_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_program_2022: &'a AccountInfo<'info>,
+    pub memo_program: &'a AccountInfo<'info>,
+    
+    /// Venue-specific accounts, in the adapter's layout
+    pub venue: &'a [AccountInfo<'info>],
+}
+
+/// Token account, mint and token program for one side of the pool
+#[derive(Clone, Copy)]
+pub struct TokenSide<'a, 'info> {
+    pub account: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> AmmAccounts<'a, 'info> {
+    pub fn quote(&self) -> TokenSide<'a, 'info> {
+        TokenSide {
+            account: self.quote_account,
+            mint: self.quote_mint,
+            token_program: self.quote_token_program,
+        }
+    }
+    
+    pub fn base(&self) -> TokenSide<'a, 'info> {
+        TokenSide {
+            account: self.base_account,
+            mint: self.base_mint,
+            token_program: self.base_token_program,
+        }
+    }
+    
+    /// Token 0 and token 1 sides of the pool
+    pub fn by_token_index(&self, is_quote_token_0: bool) -> (TokenSide<'a, 'info>, TokenSide<'a, 'info>) {
+        if is_quote_token_0 {
+            (self.quote(), self.base())
+        } else {
+            (self.base(), self.quote())
+        }
+    }
+}
+
+/// Extra accounts needed to open a position
+pub struct OpenAccounts<'a, 'info> {
+    /// Funds the position accounts and the deposit
+    pub payer: &'a AccountInfo<'info>,
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub metadata_program: &'a AccountInfo<'info>,
+}
+
+/// Extra accounts needed to close a position
+pub struct CloseAccounts<'a, 'info> {
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    
+    /// Receives the position rent
+    pub rent_receiver: &'a AccountInfo<'info>,
+    
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// A venue the honorary position can live in
+///
+/// Instructions never talk to a venue directly: they dispatch on the
+/// position's `AmmKind` through the functions below, handing the adapter the
+/// shared accounts plus its own venue accounts in the layout it documents.
+/// Supporting a new venue means adding an adapter and an `AmmKind` variant.
+pub trait AmmAdapter {
+    /// Venue accounts `initialize_position` takes as remaining accounts
+    const OPEN_ACCOUNTS: usize;
+    
+    /// Venue accounts needed to claim from, withdraw and close a position
+    const POSITION_ACCOUNTS: usize;
+    
+    fn program_id() -> Pubkey;
+    
+    /// Read the pool's mints, vaults and current price
+    fn read_pool(pool: &AccountInfo) -> Result<PoolInfo>;
+    
+    /// Check a position opened with these parameters can only ever accrue quote fees
+    fn validate_quote_only(
+        pool_account: &AccountInfo,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        params: &InitializePositionParams,
+    ) -> Result<()>;
+    
+    /// Open the position for the owner PDA and deposit its liquidity from the payer
+    fn open_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        open: &OpenAccounts<'a, 'info>,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        params: &InitializePositionParams,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<OpenedPosition>;
+    
+    /// Check the venue accounts belong to the recorded position, including that
+    /// its NFT is still held by `position_owner`
+    fn validate_position_accounts(
+        venue: &[AccountInfo],
+        position: &PositionState,
+        position_owner: &Pubkey,
+    ) -> Result<()>;
+    
+    /// Read the fees the venue has recorded as owed to the position
+    fn read_unclaimed_fees(venue: &[AccountInfo], position: &PositionState) -> Result<UnclaimedFees>;
+    
+    /// Collect the position's fees into the quote / base accounts
+    fn collect_fees<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+    
+    /// Withdraw all liquidity into the quote / base accounts, returning the liquidity removed
+    fn withdraw_all<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u128>;
+    
+    /// Burn the position NFT and close the (empty) position
+    fn close_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        close: &CloseAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+}
+
+macro_rules! dispatch {
+    ($kind:expr, $adapter:ident => $body:expr) => {
+        match $kind {
+            AmmKind::RaydiumClmm => {
+                type $adapter = RaydiumClmmAdapter;
+                $body
+            }
+            AmmKind::MeteoraDammV2 => {
+                type $adapter = MeteoraDammV2Adapter;
+                $body
+            }
+            AmmKind::OrcaWhirlpool => {
+                type $adapter = OrcaWhirlpoolAdapter;
+                $body
+            }
+        }
+    };
+}
+
+/// Program ID of the venue behind `kind`
+pub fn program_id(kind: AmmKind) -> Pubkey {
+    dispatch!(kind, A => A::program_id())
+}
+
+/// Venue accounts `initialize_position` expects in its remaining accounts
+pub fn open_account_count(kind: AmmKind) -> usize {
+    dispatch!(kind, A => A::OPEN_ACCOUNTS)
+}
+
+/// Venue accounts following each crank group header and leading `remove_position`'s remaining accounts
+pub fn position_account_count(kind: AmmKind) -> usize {
+    dispatch!(kind, A => A::POSITION_ACCOUNTS)
+}
+
+/// Parse the venue pool into its mints, vaults and price
+pub fn read_pool(kind: AmmKind, pool: &AccountInfo) -> Result<PoolInfo> {
+    dispatch!(kind, A => A::read_pool(pool))
+}
+
+/// Reject pools or ranges that could accrue fees in the base mint
+pub fn validate_quote_only(
+    kind: AmmKind,
+    pool_account: &AccountInfo,
+    pool: &PoolInfo,
+    is_quote_token_0: bool,
+    params: &InitializePositionParams,
+) -> Result<()> {
+    dispatch!(kind, A => A::validate_quote_only(pool_account, pool, is_quote_token_0, params))
+}
+
+/// Open and fund the honorary position, owned by the position owner PDA
+pub fn open_position<'a, 'info>(
+    kind: AmmKind,
+    accounts: &AmmAccounts<'a, 'info>,
+    open: &OpenAccounts<'a, 'info>,
+    pool: &PoolInfo,
+    is_quote_token_0: bool,
+    params: &InitializePositionParams,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<OpenedPosition> {
+    dispatch!(kind, A => A::open_position(accounts, open, pool, is_quote_token_0, params, signer_seeds))
+}
+
+/// Check the supplied venue accounts against the recorded position
+pub fn validate_position_accounts(
+    kind: AmmKind,
+    venue: &[AccountInfo],
+    position: &PositionState,
+    position_owner: &Pubkey,
+) -> Result<()> {
+    dispatch!(kind, A => A::validate_position_accounts(venue, position, position_owner))
+}
+
+/// Fees the venue already records as owed to the position
+pub fn read_unclaimed_fees(kind: AmmKind, venue: &[AccountInfo], position: &PositionState) -> Result<UnclaimedFees> {
+    dispatch!(kind, A => A::read_unclaimed_fees(venue, position))
+}
+
+/// Claim accrued fees into the quote and base accounts
+pub fn collect_fees<'a, 'info>(
+    accounts: &AmmAccounts<'a, 'info>,
+    position: &PositionState,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    dispatch!(position.amm_kind, A => A::collect_fees(accounts, position, signer_seeds))
+}
+
+/// Withdraw all liquidity, returning the amount removed
+pub fn withdraw_all<'a, 'info>(
+    accounts: &AmmAccounts<'a, 'info>,
+    position: &PositionState,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u128> {
+    dispatch!(position.amm_kind, A => A::withdraw_all(accounts, position, signer_seeds))
+}
+
+/// Close the venue position and burn or release its NFT
+pub fn close_position<'a, 'info>(
+    accounts: &AmmAccounts<'a, 'info>,
+    close: &CloseAccounts<'a, 'info>,
+    position: &PositionState,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    dispatch!(position.amm_kind, A => A::close_position(accounts, close, position, signer_seeds))
+}
+
+/// Require `nft_account` to hold the position's single NFT for `position_owner`
+pub(crate) fn require_position_nft_held(
+    nft_account: &AccountInfo,
+    position: &PositionState,
+    position_owner: &Pubkey,
+) -> Result<()> {
+    require!(
+        *nft_account.owner == token::ID || *nft_account.owner == token_2022::ID,
+        ErrorCode::PositionNftNotHeld
+    );
+    
+    let data = nft_account.try_borrow_data()?;
+    let nft_account = TokenAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(ErrorCode::PositionNftNotHeld))?;
+    
+    require!(
+        nft_account.mint == position.position_nft_mint
+            && nft_account.owner == *position_owner
+            && nft_account.amount == 1,
+        ErrorCode::PositionNftNotHeld
+    );
+    
+    Ok(())
+}
+
+/// Run a deposit the position owner signs for
+///
+/// Venues that pull deposits from accounts the position owner controls get
+/// the payer's approval for the owner PDA to spend up to the maximums; the
+/// approvals are revoked once the deposit is done.
+pub(crate) fn with_owner_delegate<'a, 'info>(
+    accounts: &AmmAccounts<'a, 'info>,
+    payer: &'a AccountInfo<'info>,
+    quote_max: u64,
+    base_max: u64,
+    deposit: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    let sources = [(accounts.quote(), quote_max), (accounts.base(), base_max)];
+    
+    for (side, amount) in sources {
+        token_interface::approve(
+            CpiContext::new(
+                side.token_program.clone(),
+                Approve {
+                    to: side.account.clone(),
+                    delegate: accounts.position_owner.clone(),
+                    authority: payer.clone(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    
+    deposit()?;
+    
+    for (side, _) in sources {
+        token_interface::revoke(CpiContext::new(
+            side.token_program.clone(),
+            Revoke {
+                source: side.account.clone(),
+                authority: payer.clone(),
+            },
+        ))?;
+    }
+    
+    Ok(())
+}
\ No newline at end of file
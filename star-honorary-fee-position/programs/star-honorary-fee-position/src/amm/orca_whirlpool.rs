@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::amm::{
+    require_position_nft_held, with_owner_delegate, AmmAccounts, AmmAdapter, CloseAccounts, OpenAccounts, OpenedPosition,
+    PoolInfo, UnclaimedFees,
+};
+use crate::cp_amm;
+use crate::errors::ErrorCode;
+use crate::instructions::InitializePositionParams;
+use crate::state::PositionState;
+use crate::whirlpool;
+
+// Venue account layout (opening and position accounts alike):
+// [position, position_token_account, token_vault_a, token_vault_b,
+//  tick_array_lower, tick_array_upper]
+const POSITION: usize = 0;
+const POSITION_TOKEN_ACCOUNT: usize = 1;
+const TOKEN_VAULT_A: usize = 2;
+const TOKEN_VAULT_B: usize = 3;
+const TICK_ARRAY_LOWER: usize = 4;
+const TICK_ARRAY_UPPER: usize = 5;
+
+/// Orca Whirlpool - quote-only by tick range, token A playing token0
+pub struct OrcaWhirlpoolAdapter;
+
+impl OrcaWhirlpoolAdapter {
+    fn position_accounts<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        is_quote_token_0: bool,
+    ) -> whirlpool::PositionAccounts<'a, 'info> {
+        let venue = accounts.venue;
+        let (side_a, side_b) = accounts.by_token_index(is_quote_token_0);
+        
+        whirlpool::PositionAccounts {
+            whirlpool: accounts.pool,
+            token_program_a: side_a.token_program,
+            token_program_b: side_b.token_program,
+            memo_program: accounts.memo_program,
+            position_authority: accounts.position_owner,
+            position: &venue[POSITION],
+            position_token_account: &venue[POSITION_TOKEN_ACCOUNT],
+            token_mint_a: side_a.mint,
+            token_mint_b: side_b.mint,
+            token_owner_account_a: side_a.account,
+            token_vault_a: &venue[TOKEN_VAULT_A],
+            token_owner_account_b: side_b.account,
+            token_vault_b: &venue[TOKEN_VAULT_B],
+            tick_array_lower: &venue[TICK_ARRAY_LOWER],
+            tick_array_upper: &venue[TICK_ARRAY_UPPER],
+        }
+    }
+}
+
+impl AmmAdapter for OrcaWhirlpoolAdapter {
+    const OPEN_ACCOUNTS: usize = 6;
+    const POSITION_ACCOUNTS: usize = 6;
+    
+    fn program_id() -> Pubkey {
+        whirlpool::program_id()
+    }
+    
+    fn read_pool(pool: &AccountInfo) -> Result<PoolInfo> {
+        let pool = whirlpool::read_pool(pool)?;
+        
+        Ok(PoolInfo {
+            token_mint_0: pool.token_mint_a,
+            token_mint_1: pool.token_mint_b,
+            token_vault_0: pool.token_vault_a,
+            token_vault_1: pool.token_vault_b,
+            sqrt_price: pool.sqrt_price,
+            tick_current: Some(pool.tick_current),
+            tick_spacing: Some(pool.tick_spacing),
+        })
+    }
+    
+    fn validate_quote_only(
+        _pool_account: &AccountInfo,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        params: &InitializePositionParams,
+    ) -> Result<()> {
+        let tick_spacing = pool.tick_spacing.ok_or(ErrorCode::InvalidCpAmmAccount)?;
+        let tick_current = pool.tick_current.ok_or(ErrorCode::InvalidCpAmmAccount)?;
+        
+        // Whirlpool shares CP-AMM's tick bounds and price orientation
+        cp_amm::validate_tick_range(params.tick_lower, params.tick_upper, tick_spacing)?;
+        require!(
+            cp_amm::validate_quote_only_ticks(
+                tick_current,
+                params.tick_lower,
+                params.tick_upper,
+                is_quote_token_0,
+            ),
+            ErrorCode::PositionWouldAccrueBaseFees
+        );
+        
+        Ok(())
+    }
+    
+    fn open_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        open: &OpenAccounts<'a, 'info>,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        params: &InitializePositionParams,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<OpenedPosition> {
+        let venue = accounts.venue;
+        let position_mint = open.position_nft_mint.key();
+        require!(
+            venue[POSITION].key() == whirlpool::position_address(&position_mint).0
+                && venue[POSITION_TOKEN_ACCOUNT].key()
+                    == get_associated_token_address(&accounts.position_owner.key(), &position_mint)
+                && venue[TOKEN_VAULT_A].key() == pool.token_vault_0
+                && venue[TOKEN_VAULT_B].key() == pool.token_vault_1,
+            ErrorCode::InvalidCpAmmAccount
+        );
+        
+        // 1. Open the position - the position token goes to the owner PDA's ATA
+        whirlpool::open_position(
+            accounts.amm_program,
+            whirlpool::OpenPosition {
+                funder: open.payer,
+                owner: accounts.position_owner,
+                position: &venue[POSITION],
+                position_mint: open.position_nft_mint,
+                position_token_account: &venue[POSITION_TOKEN_ACCOUNT],
+                whirlpool: accounts.pool,
+                token_program: accounts.token_program,
+                system_program: open.system_program,
+                rent: open.rent,
+                associated_token_program: open.associated_token_program,
+            },
+            params.tick_lower,
+            params.tick_upper,
+        )?;
+        
+        // 2. Whirlpool pulls deposits from accounts the position authority controls
+        let (quote_max, base_max) = if is_quote_token_0 {
+            (params.amount_0_max, params.amount_1_max)
+        } else {
+            (params.amount_1_max, params.amount_0_max)
+        };
+        with_owner_delegate(accounts, open.payer, quote_max, base_max, || {
+            whirlpool::increase_liquidity(
+                accounts.amm_program,
+                Self::position_accounts(accounts, is_quote_token_0),
+                params.liquidity,
+                params.amount_0_max,
+                params.amount_1_max,
+                signer_seeds,
+            )
+        })?;
+        
+        Ok(OpenedPosition {
+            position_id: venue[POSITION].key(),
+            personal_position: Pubkey::default(),
+            tick_array_lower: venue[TICK_ARRAY_LOWER].key(),
+            tick_array_upper: venue[TICK_ARRAY_UPPER].key(),
+            tick_lower: params.tick_lower,
+            tick_upper: params.tick_upper,
+            liquidity: whirlpool::read_position(&venue[POSITION])?.liquidity,
+        })
+    }
+    
+    fn validate_position_accounts(
+        venue: &[AccountInfo],
+        position: &PositionState,
+        position_owner: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            venue[POSITION].key() == position.position_id
+                && venue[TICK_ARRAY_LOWER].key() == position.tick_array_lower
+                && venue[TICK_ARRAY_UPPER].key() == position.tick_array_upper,
+            ErrorCode::PositionAccountsMismatch
+        );
+        
+        require_position_nft_held(&venue[POSITION_TOKEN_ACCOUNT], position, position_owner)
+    }
+    
+    fn read_unclaimed_fees(venue: &[AccountInfo], position: &PositionState) -> Result<UnclaimedFees> {
+        let snapshot = whirlpool::read_position(&venue[POSITION])?;
+        
+        Ok(if position.is_quote_token_0 {
+            UnclaimedFees { quote: snapshot.fee_owed_a, base: snapshot.fee_owed_b }
+        } else {
+            UnclaimedFees { quote: snapshot.fee_owed_b, base: snapshot.fee_owed_a }
+        })
+    }
+    
+    fn collect_fees<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        whirlpool::collect_fees(
+            accounts.amm_program,
+            Self::position_accounts(accounts, position.is_quote_token_0),
+            signer_seeds,
+        )
+    }
+    
+    fn withdraw_all<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u128> {
+        let liquidity = whirlpool::read_position(&accounts.venue[POSITION])?.liquidity;
+        whirlpool::decrease_liquidity(
+            accounts.amm_program,
+            Self::position_accounts(accounts, position.is_quote_token_0),
+            liquidity,
+            signer_seeds,
+        )?;
+        
+        Ok(liquidity)
+    }
+    
+    fn close_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        close: &CloseAccounts<'a, 'info>,
+        _position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let venue = accounts.venue;
+        
+        whirlpool::close_position(
+            accounts.amm_program,
+            whirlpool::ClosePosition {
+                position_authority: accounts.position_owner,
+                receiver: close.rent_receiver,
+                position: &venue[POSITION],
+                position_mint: close.position_nft_mint,
+                position_token_account: &venue[POSITION_TOKEN_ACCOUNT],
+                token_program: accounts.token_program,
+            },
+            signer_seeds,
+        )
+    }
+}
\ No newline at end of file
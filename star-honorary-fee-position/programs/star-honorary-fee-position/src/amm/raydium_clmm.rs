@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::amm::{
+    require_position_nft_held, AmmAccounts, AmmAdapter, CloseAccounts, OpenAccounts, OpenedPosition,
+    PoolInfo, UnclaimedFees,
+};
+use crate::cp_amm;
+use crate::errors::ErrorCode;
+use crate::instructions::InitializePositionParams;
+use crate::state::PositionState;
+
+// Venue account layout:
+// [protocol_position, personal_position, token_vault_0, token_vault_1,
+//  tick_array_lower, tick_array_upper, position_nft_account]
+// followed, when opening, by [metadata_account]
+const PROTOCOL_POSITION: usize = 0;
+const PERSONAL_POSITION: usize = 1;
+const TOKEN_VAULT_0: usize = 2;
+const TOKEN_VAULT_1: usize = 3;
+const TICK_ARRAY_LOWER: usize = 4;
+const TICK_ARRAY_UPPER: usize = 5;
+const POSITION_NFT_ACCOUNT: usize = 6;
+const METADATA_ACCOUNT: usize = 7;
+
+/// Raydium concentrated liquidity - quote-only by tick range
+pub struct RaydiumClmmAdapter;
+
+impl RaydiumClmmAdapter {
+    fn decrease_accounts<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+    ) -> cp_amm::DecreaseLiquidity<'a, 'info> {
+        let venue = accounts.venue;
+        let (side_0, side_1) = accounts.by_token_index(position.is_quote_token_0);
+        
+        cp_amm::DecreaseLiquidity {
+            nft_owner: accounts.position_owner,
+            nft_account: &venue[POSITION_NFT_ACCOUNT],
+            personal_position: &venue[PERSONAL_POSITION],
+            pool_state: accounts.pool,
+            protocol_position: &venue[PROTOCOL_POSITION],
+            token_vault_0: &venue[TOKEN_VAULT_0],
+            token_vault_1: &venue[TOKEN_VAULT_1],
+            tick_array_lower: &venue[TICK_ARRAY_LOWER],
+            tick_array_upper: &venue[TICK_ARRAY_UPPER],
+            recipient_token_account_0: side_0.account,
+            recipient_token_account_1: side_1.account,
+            token_program: accounts.token_program,
+            token_program_2022: accounts.token_program_2022,
+            memo_program: accounts.memo_program,
+            vault_0_mint: side_0.mint,
+            vault_1_mint: side_1.mint,
+        }
+    }
+}
+
+impl AmmAdapter for RaydiumClmmAdapter {
+    const OPEN_ACCOUNTS: usize = 8;
+    const POSITION_ACCOUNTS: usize = 7;
+    
+    fn program_id() -> Pubkey {
+        cp_amm::program_id()
+    }
+    
+    fn read_pool(pool: &AccountInfo) -> Result<PoolInfo> {
+        let pool = cp_amm::read_pool_state(pool)?;
+        
+        Ok(PoolInfo {
+            token_mint_0: pool.token_mint_0,
+            token_mint_1: pool.token_mint_1,
+            token_vault_0: pool.token_vault_0,
+            token_vault_1: pool.token_vault_1,
+            sqrt_price: pool.sqrt_price_x64,
+            tick_current: Some(pool.tick_current),
+            tick_spacing: Some(pool.tick_spacing),
+        })
+    }
+    
+    fn validate_quote_only(
+        _pool_account: &AccountInfo,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        params: &InitializePositionParams,
+    ) -> Result<()> {
+        let tick_spacing = pool.tick_spacing.ok_or(ErrorCode::InvalidCpAmmAccount)?;
+        let tick_current = pool.tick_current.ok_or(ErrorCode::InvalidCpAmmAccount)?;
+        
+        // Validate ticks: ordering, global bounds and tick spacing alignment
+        cp_amm::validate_tick_range(params.tick_lower, params.tick_upper, tick_spacing)?;
+        
+        // CRITICAL VALIDATION: Quote-only position check against the live tick
+        // - If quote is token0: tick_lower > current_tick (position above price)
+        // - If quote is token1: tick_upper < current_tick (position below price)
+        require!(
+            cp_amm::validate_quote_only_ticks(
+                tick_current,
+                params.tick_lower,
+                params.tick_upper,
+                is_quote_token_0,
+            ),
+            ErrorCode::PositionWouldAccrueBaseFees
+        );
+        
+        Ok(())
+    }
+    
+    fn open_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        open: &OpenAccounts<'a, 'info>,
+        pool: &PoolInfo,
+        is_quote_token_0: bool,
+        params: &InitializePositionParams,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<OpenedPosition> {
+        let venue = accounts.venue;
+        require!(
+            venue[TOKEN_VAULT_0].key() == pool.token_vault_0
+                && venue[TOKEN_VAULT_1].key() == pool.token_vault_1,
+            ErrorCode::InvalidCpAmmAccount
+        );
+        
+        // CP-AMM pulls the deposit from the payer's accounts
+        let (side_0, side_1) = accounts.by_token_index(is_quote_token_0);
+        
+        cp_amm::open_position(
+            accounts.amm_program,
+            cp_amm::OpenPosition {
+                payer: open.payer,
+                position_nft_owner: accounts.position_owner,
+                position_nft_mint: open.position_nft_mint,
+                position_nft_account: &venue[POSITION_NFT_ACCOUNT],
+                metadata_account: &venue[METADATA_ACCOUNT],
+                pool_state: accounts.pool,
+                protocol_position: &venue[PROTOCOL_POSITION],
+                tick_array_lower: &venue[TICK_ARRAY_LOWER],
+                tick_array_upper: &venue[TICK_ARRAY_UPPER],
+                personal_position: &venue[PERSONAL_POSITION],
+                token_account_0: side_0.account,
+                token_account_1: side_1.account,
+                token_vault_0: &venue[TOKEN_VAULT_0],
+                token_vault_1: &venue[TOKEN_VAULT_1],
+                rent: open.rent,
+                system_program: open.system_program,
+                token_program: accounts.token_program,
+                associated_token_program: open.associated_token_program,
+                metadata_program: open.metadata_program,
+                token_program_2022: accounts.token_program_2022,
+                vault_0_mint: side_0.mint,
+                vault_1_mint: side_1.mint,
+            },
+            cp_amm::OpenPositionArgs {
+                tick_lower_index: params.tick_lower,
+                tick_upper_index: params.tick_upper,
+                tick_array_lower_start_index: params.tick_array_lower_start_index,
+                tick_array_upper_start_index: params.tick_array_upper_start_index,
+                liquidity: params.liquidity,
+                amount_0_max: params.amount_0_max,
+                amount_1_max: params.amount_1_max,
+                with_metadata: true,
+                base_flag: None,
+            },
+            signer_seeds,
+        )?;
+        
+        Ok(OpenedPosition {
+            position_id: venue[PROTOCOL_POSITION].key(),
+            personal_position: venue[PERSONAL_POSITION].key(),
+            tick_array_lower: venue[TICK_ARRAY_LOWER].key(),
+            tick_array_upper: venue[TICK_ARRAY_UPPER].key(),
+            tick_lower: params.tick_lower,
+            tick_upper: params.tick_upper,
+            liquidity: cp_amm::read_position_liquidity(&venue[PERSONAL_POSITION])?,
+        })
+    }
+    
+    fn validate_position_accounts(
+        venue: &[AccountInfo],
+        position: &PositionState,
+        position_owner: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            venue[PROTOCOL_POSITION].key() == position.position_id
+                && venue[PERSONAL_POSITION].key() == position.personal_position
+                && venue[TICK_ARRAY_LOWER].key() == position.tick_array_lower
+                && venue[TICK_ARRAY_UPPER].key() == position.tick_array_upper,
+            ErrorCode::PositionAccountsMismatch
+        );
+        
+        require_position_nft_held(&venue[POSITION_NFT_ACCOUNT], position, position_owner)
+    }
+    
+    fn read_unclaimed_fees(venue: &[AccountInfo], position: &PositionState) -> Result<UnclaimedFees> {
+        let (owed_0, owed_1) = cp_amm::read_position_fees_owed(&venue[PERSONAL_POSITION])?;
+        
+        Ok(if position.is_quote_token_0 {
+            UnclaimedFees { quote: owed_0, base: owed_1 }
+        } else {
+            UnclaimedFees { quote: owed_1, base: owed_0 }
+        })
+    }
+    
+    fn collect_fees<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        cp_amm::collect_fees(
+            accounts.amm_program,
+            Self::decrease_accounts(accounts, position),
+            signer_seeds,
+        )
+    }
+    
+    fn withdraw_all<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u128> {
+        let liquidity = cp_amm::read_position_liquidity(&accounts.venue[PERSONAL_POSITION])?;
+        cp_amm::decrease_liquidity(
+            accounts.amm_program,
+            Self::decrease_accounts(accounts, position),
+            liquidity,
+            signer_seeds,
+        )?;
+        
+        Ok(liquidity)
+    }
+    
+    fn close_position<'a, 'info>(
+        accounts: &AmmAccounts<'a, 'info>,
+        close: &CloseAccounts<'a, 'info>,
+        _position: &PositionState,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let venue = accounts.venue;
+        
+        cp_amm::close_position(
+            accounts.amm_program,
+            cp_amm::ClosePosition {
+                nft_owner: accounts.position_owner,
+                position_nft_mint: close.position_nft_mint,
+                position_nft_account: &venue[POSITION_NFT_ACCOUNT],
+                personal_position: &venue[PERSONAL_POSITION],
+                system_program: close.system_program,
+                token_program: accounts.token_program,
+            },
+            signer_seeds,
+        )?;
+        
+        // CP-AMM returns the position rent to the NFT owner - forward it
+        let owner_lamports = accounts.position_owner.lamports();
+        if owner_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    close.system_program.clone(),
+                    system_program::Transfer {
+                        from: accounts.position_owner.clone(),
+                        to: close.rent_receiver.clone(),
+                    },
+                    signer_seeds,
+                ),
+                owner_lamports,
+            )?;
+        }
+        
+        Ok(())
+    }
+}
\ No newline at end of file
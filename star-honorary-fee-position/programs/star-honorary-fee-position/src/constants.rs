@@ -4,15 +4,55 @@ pub const POSITION_OWNER_SEED: &[u8] = b"investor_fee_pos_owner";
 pub const POLICY_SEED: &[u8] = b"policy";
 pub const PROGRESS_SEED: &[u8] = b"progress";
 pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const BASE_TREASURY_SEED: &[u8] = b"base_treasury";
 pub const POSITION_STATE_SEED: &[u8] = b"position_state";
+pub const REGISTRY_SEED: &[u8] = b"registry";
+pub const CREATOR_SPLIT_SEED: &[u8] = b"creator_split";
 
 // Time constraints
 pub const SECONDS_PER_DAY: i64 = 86400;
+pub const MIN_DISTRIBUTION_INTERVAL_SECS: i64 = 3600;
+pub const MAX_DISTRIBUTION_INTERVAL_SECS: i64 = 30 * SECONDS_PER_DAY;
+pub const MIN_DAY_DURATION_SECS: i64 = 3600;
 pub const MAX_INVESTORS_PER_PAGE: usize = 20;
+pub const MAX_REGISTERED_INVESTORS: usize = 128;
+pub const MAX_SPLIT_RECIPIENTS: usize = 5;
+pub const MAX_HONORARY_POSITIONS: usize = 4;
+
+// Leading accounts of every position group passed to the first crank page of a day:
+// [position_state, amm_program, pool, program_base_treasury, base_mint],
+// followed by the AMM adapter's own position accounts (see amm::position_account_count)
+pub const POSITION_GROUP_HEADER_ACCOUNTS: usize = 5;
+
+// CP-AMM global tick bounds
+pub const MIN_TICK: i32 = -443636;
+pub const MAX_TICK: i32 = 443636;
 
 // Basis points max
 pub const MAX_BPS: u16 = 10000;
 
+// Crank reward max (5% of the day's claim)
+pub const MAX_CRANK_REWARD_BPS: u16 = 500;
+
 // Raydium CP-AMM Program ID (using placeholder - update with actual)
 // For localnet testing, this would be the deployed CP-AMM program
-pub const CP_AMM_PROGRAM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
\ No newline at end of file
+pub const CP_AMM_PROGRAM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+
+// Meteora DAMM v2 Program ID
+pub const DAMM_V2_PROGRAM_ID: &str = "cpamdpZCGKUy5JxQXB4dcpGPiikHproWAjRHxdE1UvG";
+
+// Orca Whirlpool Program ID
+pub const WHIRLPOOL_PROGRAM_ID: &str = "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc";
+
+// SPL memo program ID (passed to CP-AMM's Token-2022 aware instructions)
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+// Streamflow program ID (stream / vesting contracts)
+pub const STREAMFLOW_PROGRAM_ID: &str = "strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m";
+
+// Oldest Streamflow contract version whose layout we parse
+pub const STREAMFLOW_MIN_VERSION: u8 = 1;
+
+// Linear-vesting escrow programs sharing the `VestingEscrow` layout (Jupiter Lock
+// first; in-house escrow deployments are appended here)
+pub const LINEAR_ESCROW_PROGRAM_IDS: [&str; 1] = ["LocpQgucEQHbqNABEYvBvwoxCPsSbG91A1QaQhQQqjn"];
\ No newline at end of file
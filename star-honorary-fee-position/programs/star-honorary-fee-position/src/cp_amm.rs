@@ -0,0 +1,444 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use std::str::FromStr;
+use crate::constants::{CP_AMM_PROGRAM_ID, MAX_TICK, MEMO_PROGRAM_ID, MIN_TICK};
+use crate::errors::ErrorCode;
+
+// PoolState layout (after 8-byte discriminator):
+// bump (1) | amm_config (32) | owner (32) | token_mint_0 (32) | token_mint_1 (32) |
+// token_vault_0 (32) | token_vault_1 (32) | observation_key (32) | mint_decimals_0 (1) |
+// mint_decimals_1 (1) | tick_spacing (2) | liquidity (16) | sqrt_price_x64 (16) | tick_current (4)
+const POOL_TOKEN_MINT_0_OFFSET: usize = 8 + 1 + 32 + 32;
+const POOL_TOKEN_VAULT_0_OFFSET: usize = POOL_TOKEN_MINT_0_OFFSET + 64;
+const POOL_TICK_SPACING_OFFSET: usize = POOL_TOKEN_VAULT_0_OFFSET + 64 + 32 + 2;
+const POOL_SQRT_PRICE_OFFSET: usize = POOL_TICK_SPACING_OFFSET + 2 + 16;
+const POOL_TICK_CURRENT_OFFSET: usize = POOL_SQRT_PRICE_OFFSET + 16;
+
+// PersonalPositionState layout (after 8-byte discriminator):
+// bump (1) | nft_mint (32) | pool_id (32) | tick_lower (4) | tick_upper (4) | liquidity (16) |
+// fee_growth_inside_0_last_x64 (16) | fee_growth_inside_1_last_x64 (16) |
+// token_fees_owed_0 (8) | token_fees_owed_1 (8)
+const PERSONAL_POSITION_LIQUIDITY_OFFSET: usize = 8 + 1 + 32 + 32 + 4 + 4;
+const PERSONAL_POSITION_FEES_OWED_OFFSET: usize = PERSONAL_POSITION_LIQUIDITY_OFFSET + 16 + 16 + 16;
+
+/// Parsed CP-AMM program ID
+pub fn program_id() -> Pubkey {
+    Pubkey::from_str(CP_AMM_PROGRAM_ID).unwrap()
+}
+
+/// Parsed SPL memo program ID (required by CP-AMM's Token-2022 aware instructions)
+pub fn memo_program_id() -> Pubkey {
+    Pubkey::from_str(MEMO_PROGRAM_ID).unwrap()
+}
+
+/// Fields of a CP-AMM pool relevant to the honorary position
+#[derive(Debug, Clone)]
+pub struct PoolSnapshot {
+    pub token_mint_0: Pubkey,
+    pub token_mint_1: Pubkey,
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+    pub tick_spacing: u16,
+    pub sqrt_price_x64: u128,
+    pub tick_current: i32,
+}
+
+/// Anchor instruction discriminator: sha256("global:<name>")[..8]
+pub(crate) fn instruction_discriminator(name: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", name);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// Anchor account discriminator: sha256("account:<name>")[..8]
+pub(crate) fn account_discriminator(name: &str) -> [u8; 8] {
+    let preimage = format!("account:{}", name);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// Arguments of CP-AMM's `open_position_v2` instruction
+#[derive(AnchorSerialize)]
+pub struct OpenPositionArgs {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+    pub with_metadata: bool,
+    pub base_flag: Option<bool>,
+}
+
+/// Accounts required by CP-AMM's `open_position_v2` instruction, in CPI order
+pub struct OpenPosition<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub position_nft_owner: &'a AccountInfo<'info>,
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub metadata_account: &'a AccountInfo<'info>,
+    pub pool_state: &'a AccountInfo<'info>,
+    pub protocol_position: &'a AccountInfo<'info>,
+    pub tick_array_lower: &'a AccountInfo<'info>,
+    pub tick_array_upper: &'a AccountInfo<'info>,
+    pub personal_position: &'a AccountInfo<'info>,
+    pub token_account_0: &'a AccountInfo<'info>,
+    pub token_account_1: &'a AccountInfo<'info>,
+    pub token_vault_0: &'a AccountInfo<'info>,
+    pub token_vault_1: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub metadata_program: &'a AccountInfo<'info>,
+    pub token_program_2022: &'a AccountInfo<'info>,
+    pub vault_0_mint: &'a AccountInfo<'info>,
+    pub vault_1_mint: &'a AccountInfo<'info>,
+}
+
+/// CPI into CP-AMM `open_position_v2` (supports Token-2022 pool mints)
+///
+/// The position NFT is minted to `position_nft_owner`, which signs via
+/// `signer_seeds` so the program-derived owner is recorded as the holder.
+pub fn open_position<'a, 'info>(
+    cp_amm_program: &'a AccountInfo<'info>,
+    accounts: OpenPosition<'a, 'info>,
+    args: OpenPositionArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = instruction_discriminator("open_position_v2").to_vec();
+    args.serialize(&mut data)?;
+    
+    let ix = Instruction {
+        program_id: cp_amm_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.position_nft_owner.key(), true),
+            AccountMeta::new(accounts.position_nft_mint.key(), true),
+            AccountMeta::new(accounts.position_nft_account.key(), false),
+            AccountMeta::new(accounts.metadata_account.key(), false),
+            AccountMeta::new(accounts.pool_state.key(), false),
+            AccountMeta::new(accounts.protocol_position.key(), false),
+            AccountMeta::new(accounts.tick_array_lower.key(), false),
+            AccountMeta::new(accounts.tick_array_upper.key(), false),
+            AccountMeta::new(accounts.personal_position.key(), false),
+            AccountMeta::new(accounts.token_account_0.key(), false),
+            AccountMeta::new(accounts.token_account_1.key(), false),
+            AccountMeta::new(accounts.token_vault_0.key(), false),
+            AccountMeta::new(accounts.token_vault_1.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.associated_token_program.key(), false),
+            AccountMeta::new_readonly(accounts.metadata_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_2022.key(), false),
+            AccountMeta::new_readonly(accounts.vault_0_mint.key(), false),
+            AccountMeta::new_readonly(accounts.vault_1_mint.key(), false),
+        ],
+        data,
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.payer.clone(),
+            accounts.position_nft_owner.clone(),
+            accounts.position_nft_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.metadata_account.clone(),
+            accounts.pool_state.clone(),
+            accounts.protocol_position.clone(),
+            accounts.tick_array_lower.clone(),
+            accounts.tick_array_upper.clone(),
+            accounts.personal_position.clone(),
+            accounts.token_account_0.clone(),
+            accounts.token_account_1.clone(),
+            accounts.token_vault_0.clone(),
+            accounts.token_vault_1.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+            accounts.token_program.clone(),
+            accounts.associated_token_program.clone(),
+            accounts.metadata_program.clone(),
+            accounts.token_program_2022.clone(),
+            accounts.vault_0_mint.clone(),
+            accounts.vault_1_mint.clone(),
+            cp_amm_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+#[derive(AnchorSerialize)]
+struct DecreaseLiquidityArgs {
+    liquidity: u128,
+    amount_0_min: u64,
+    amount_1_min: u64,
+}
+
+/// Accounts required by CP-AMM's `decrease_liquidity_v2` instruction, in CPI order
+pub struct DecreaseLiquidity<'a, 'info> {
+    pub nft_owner: &'a AccountInfo<'info>,
+    pub nft_account: &'a AccountInfo<'info>,
+    pub personal_position: &'a AccountInfo<'info>,
+    pub pool_state: &'a AccountInfo<'info>,
+    pub protocol_position: &'a AccountInfo<'info>,
+    pub token_vault_0: &'a AccountInfo<'info>,
+    pub token_vault_1: &'a AccountInfo<'info>,
+    pub tick_array_lower: &'a AccountInfo<'info>,
+    pub tick_array_upper: &'a AccountInfo<'info>,
+    pub recipient_token_account_0: &'a AccountInfo<'info>,
+    pub recipient_token_account_1: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_program_2022: &'a AccountInfo<'info>,
+    pub memo_program: &'a AccountInfo<'info>,
+    pub vault_0_mint: &'a AccountInfo<'info>,
+    pub vault_1_mint: &'a AccountInfo<'info>,
+}
+
+/// CPI into CP-AMM `decrease_liquidity_v2` (supports Token-2022 pool mints)
+///
+/// Withdraws `liquidity` from the position and settles its owed fees to the
+/// recipient token accounts, signed by the NFT owner PDA.
+pub fn decrease_liquidity<'a, 'info>(
+    cp_amm_program: &'a AccountInfo<'info>,
+    accounts: DecreaseLiquidity<'a, 'info>,
+    liquidity: u128,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = DecreaseLiquidityArgs {
+        liquidity,
+        amount_0_min: 0,
+        amount_1_min: 0,
+    };
+    
+    let mut data = instruction_discriminator("decrease_liquidity_v2").to_vec();
+    args.serialize(&mut data)?;
+    
+    let ix = Instruction {
+        program_id: cp_amm_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.nft_owner.key(), true),
+            AccountMeta::new_readonly(accounts.nft_account.key(), false),
+            AccountMeta::new(accounts.personal_position.key(), false),
+            AccountMeta::new(accounts.pool_state.key(), false),
+            AccountMeta::new(accounts.protocol_position.key(), false),
+            AccountMeta::new(accounts.token_vault_0.key(), false),
+            AccountMeta::new(accounts.token_vault_1.key(), false),
+            AccountMeta::new(accounts.tick_array_lower.key(), false),
+            AccountMeta::new(accounts.tick_array_upper.key(), false),
+            AccountMeta::new(accounts.recipient_token_account_0.key(), false),
+            AccountMeta::new(accounts.recipient_token_account_1.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_2022.key(), false),
+            AccountMeta::new_readonly(accounts.memo_program.key(), false),
+            AccountMeta::new_readonly(accounts.vault_0_mint.key(), false),
+            AccountMeta::new_readonly(accounts.vault_1_mint.key(), false),
+        ],
+        data,
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.nft_owner.clone(),
+            accounts.nft_account.clone(),
+            accounts.personal_position.clone(),
+            accounts.pool_state.clone(),
+            accounts.protocol_position.clone(),
+            accounts.token_vault_0.clone(),
+            accounts.token_vault_1.clone(),
+            accounts.tick_array_lower.clone(),
+            accounts.tick_array_upper.clone(),
+            accounts.recipient_token_account_0.clone(),
+            accounts.recipient_token_account_1.clone(),
+            accounts.token_program.clone(),
+            accounts.token_program_2022.clone(),
+            accounts.memo_program.clone(),
+            accounts.vault_0_mint.clone(),
+            accounts.vault_1_mint.clone(),
+            cp_amm_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// CPI into CP-AMM to collect the fees owed to a position
+///
+/// CP-AMM settles owed fees on `decrease_liquidity_v2`, so collecting is a
+/// zero-liquidity decrease signed by the NFT owner PDA.
+pub fn collect_fees<'a, 'info>(
+    cp_amm_program: &'a AccountInfo<'info>,
+    accounts: DecreaseLiquidity<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    decrease_liquidity(cp_amm_program, accounts, 0, signer_seeds)
+}
+
+/// Accounts required by CP-AMM's `close_position` instruction, in CPI order
+pub struct ClosePosition<'a, 'info> {
+    pub nft_owner: &'a AccountInfo<'info>,
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub personal_position: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+/// CPI into CP-AMM `close_position`
+///
+/// Burns the position NFT and closes the personal position. The position must
+/// hold no liquidity and no owed fees; its rent is returned to `nft_owner`.
+pub fn close_position<'a, 'info>(
+    cp_amm_program: &'a AccountInfo<'info>,
+    accounts: ClosePosition<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: cp_amm_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.nft_owner.key(), true),
+            AccountMeta::new(accounts.position_nft_mint.key(), false),
+            AccountMeta::new(accounts.position_nft_account.key(), false),
+            AccountMeta::new(accounts.personal_position.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data: instruction_discriminator("close_position").to_vec(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.nft_owner.clone(),
+            accounts.position_nft_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.personal_position.clone(),
+            accounts.system_program.clone(),
+            accounts.token_program.clone(),
+            cp_amm_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// Read the liquidity recorded in a CP-AMM personal position account
+pub fn read_position_liquidity(personal_position: &AccountInfo) -> Result<u128> {
+    require_keys_eq!(
+        *personal_position.owner,
+        program_id(),
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let data = personal_position.try_borrow_data()?;
+    let end = PERSONAL_POSITION_LIQUIDITY_OFFSET + 16;
+    require!(data.len() >= end, ErrorCode::InvalidCpAmmAccount);
+    
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&data[PERSONAL_POSITION_LIQUIDITY_OFFSET..end]);
+    Ok(u128::from_le_bytes(bytes))
+}
+
+/// Read the token0 / token1 fees owed to a CP-AMM personal position
+///
+/// CP-AMM only settles fee growth into these fields when the position is
+/// touched, so they can lag the fees actually accrued.
+pub fn read_position_fees_owed(personal_position: &AccountInfo) -> Result<(u64, u64)> {
+    require_keys_eq!(
+        *personal_position.owner,
+        program_id(),
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let data = personal_position.try_borrow_data()?;
+    let end = PERSONAL_POSITION_FEES_OWED_OFFSET + 16;
+    require!(data.len() >= end, ErrorCode::InvalidCpAmmAccount);
+    
+    let mut owed_0 = [0u8; 8];
+    let mut owed_1 = [0u8; 8];
+    owed_0.copy_from_slice(&data[PERSONAL_POSITION_FEES_OWED_OFFSET..PERSONAL_POSITION_FEES_OWED_OFFSET + 8]);
+    owed_1.copy_from_slice(&data[PERSONAL_POSITION_FEES_OWED_OFFSET + 8..end]);
+    Ok((u64::from_le_bytes(owed_0), u64::from_le_bytes(owed_1)))
+}
+
+/// Deserialize the fields we need from a CP-AMM pool state account
+pub fn read_pool_state(pool_state: &AccountInfo) -> Result<PoolSnapshot> {
+    require_keys_eq!(
+        *pool_state.owner,
+        program_id(),
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let data = pool_state.try_borrow_data()?;
+    require!(
+        data.len() >= POOL_TICK_CURRENT_OFFSET + 4 && data[..8] == account_discriminator("PoolState"),
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+    
+    let mut tick_spacing = [0u8; 2];
+    tick_spacing.copy_from_slice(&data[POOL_TICK_SPACING_OFFSET..POOL_TICK_SPACING_OFFSET + 2]);
+    
+    let mut sqrt_price_x64 = [0u8; 16];
+    sqrt_price_x64.copy_from_slice(&data[POOL_SQRT_PRICE_OFFSET..POOL_SQRT_PRICE_OFFSET + 16]);
+    
+    let mut tick_current = [0u8; 4];
+    tick_current.copy_from_slice(&data[POOL_TICK_CURRENT_OFFSET..POOL_TICK_CURRENT_OFFSET + 4]);
+    
+    Ok(PoolSnapshot {
+        token_mint_0: read_pubkey(POOL_TOKEN_MINT_0_OFFSET),
+        token_mint_1: read_pubkey(POOL_TOKEN_MINT_0_OFFSET + 32),
+        token_vault_0: read_pubkey(POOL_TOKEN_VAULT_0_OFFSET),
+        token_vault_1: read_pubkey(POOL_TOKEN_VAULT_0_OFFSET + 32),
+        tick_spacing: u16::from_le_bytes(tick_spacing),
+        sqrt_price_x64: u128::from_le_bytes(sqrt_price_x64),
+        tick_current: i32::from_le_bytes(tick_current),
+    })
+}
+
+/// Validate tick bounds and alignment to the pool's tick spacing
+pub fn validate_tick_range(tick_lower: i32, tick_upper: i32, tick_spacing: u16) -> Result<()> {
+    require!(tick_lower < tick_upper, ErrorCode::InvalidTickRangeForQuoteOnly);
+    require!(
+        tick_lower >= MIN_TICK && tick_upper <= MAX_TICK,
+        ErrorCode::TickOutOfBounds
+    );
+    
+    let spacing = tick_spacing as i32;
+    require!(spacing > 0, ErrorCode::InvalidCpAmmAccount);
+    require!(
+        tick_lower % spacing == 0 && tick_upper % spacing == 0,
+        ErrorCode::TickNotAlignedToSpacing
+    );
+    
+    Ok(())
+}
+
+/// Validate quote-only position ticks
+///
+/// - Quote is token0: position must sit entirely above the current price
+/// - Quote is token1: position must sit entirely below the current price
+pub fn validate_quote_only_ticks(
+    current_tick: i32,
+    tick_lower: i32,
+    tick_upper: i32,
+    is_quote_token_0: bool,
+) -> bool {
+    if is_quote_token_0 {
+        tick_lower > current_tick
+    } else {
+        tick_upper < current_tick
+    }
+}
\ No newline at end of file
@@ -0,0 +1,421 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use std::str::FromStr;
+use crate::constants::DAMM_V2_PROGRAM_ID;
+use crate::cp_amm::{account_discriminator, instruction_discriminator};
+use crate::errors::ErrorCode;
+
+// DAMM v2 PDA seeds
+const POOL_AUTHORITY_SEED: &[u8] = b"pool_authority";
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+const POSITION_SEED: &[u8] = b"position";
+const POSITION_NFT_ACCOUNT_SEED: &[u8] = b"position_nft_account";
+
+// Pool layout (after 8-byte discriminator):
+// pool_fees (160) | token_a_mint (32) | token_b_mint (32) | token_a_vault (32) |
+// token_b_vault (32) | whitelisted_vault (32) | partner (32) | liquidity (16) | padding (16) |
+// protocol_a_fee (8) | protocol_b_fee (8) | partner_a_fee (8) | partner_b_fee (8) |
+// sqrt_min_price (16) | sqrt_max_price (16) | sqrt_price (16) | activation_point (8) |
+// activation_type (1) | pool_status (1) | token_a_flag (1) | token_b_flag (1) | collect_fee_mode (1)
+const POOL_TOKEN_A_MINT_OFFSET: usize = 8 + 160;
+const POOL_TOKEN_A_VAULT_OFFSET: usize = POOL_TOKEN_A_MINT_OFFSET + 64;
+const POOL_SQRT_PRICE_OFFSET: usize = POOL_TOKEN_A_VAULT_OFFSET + 64 + 64 + 32 + 32 + 32;
+const POOL_COLLECT_FEE_MODE_OFFSET: usize = POOL_SQRT_PRICE_OFFSET + 16 + 8 + 4;
+
+// Position layout (after 8-byte discriminator):
+// pool (32) | nft_mint (32) | fee_a_per_token_checkpoint (32) | fee_b_per_token_checkpoint (32) |
+// fee_a_pending (8) | fee_b_pending (8) | unlocked_liquidity (16)
+const POSITION_POOL_OFFSET: usize = 8;
+const POSITION_FEE_A_PENDING_OFFSET: usize = POSITION_POOL_OFFSET + 32 + 32 + 32 + 32;
+const POSITION_UNLOCKED_LIQUIDITY_OFFSET: usize = POSITION_FEE_A_PENDING_OFFSET + 8 + 8;
+
+/// Pool collect-fee mode that charges every swap fee in token B
+pub const COLLECT_FEE_MODE_ONLY_B: u8 = 1;
+
+/// Parsed DAMM v2 program ID
+pub fn program_id() -> Pubkey {
+    Pubkey::from_str(DAMM_V2_PROGRAM_ID).unwrap()
+}
+
+/// DAMM v2 pool authority PDA (owns every pool vault)
+pub fn pool_authority() -> Pubkey {
+    Pubkey::find_program_address(&[POOL_AUTHORITY_SEED], &program_id()).0
+}
+
+/// DAMM v2 event authority PDA (required by its `emit_cpi!` instructions)
+pub fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &program_id()).0
+}
+
+/// DAMM v2 position account for a position NFT mint
+pub fn position_address(position_nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POSITION_SEED, position_nft_mint.as_ref()], &program_id()).0
+}
+
+/// DAMM v2 position NFT token account for a position NFT mint
+pub fn position_nft_account_address(position_nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[POSITION_NFT_ACCOUNT_SEED, position_nft_mint.as_ref()],
+        &program_id(),
+    )
+    .0
+}
+
+/// Fields of a DAMM v2 pool relevant to the honorary position
+#[derive(Debug, Clone)]
+pub struct PoolSnapshot {
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub sqrt_price: u128,
+    pub collect_fee_mode: u8,
+}
+
+/// Accounts shared by DAMM v2's fee claim and liquidity instructions, in CPI order
+pub struct PositionAccounts<'a, 'info> {
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub token_a_account: &'a AccountInfo<'info>,
+    pub token_b_account: &'a AccountInfo<'info>,
+    pub token_a_vault: &'a AccountInfo<'info>,
+    pub token_b_vault: &'a AccountInfo<'info>,
+    pub token_a_mint: &'a AccountInfo<'info>,
+    pub token_b_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub token_a_program: &'a AccountInfo<'info>,
+    pub token_b_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> PositionAccounts<'a, 'info> {
+    fn metas_and_infos(&self, program: &AccountInfo<'info>) -> (Vec<AccountMeta>, Vec<AccountInfo<'info>>) {
+        let metas = vec![
+            AccountMeta::new_readonly(self.pool_authority.key(), false),
+            AccountMeta::new(self.pool.key(), false),
+            AccountMeta::new(self.position.key(), false),
+            AccountMeta::new(self.token_a_account.key(), false),
+            AccountMeta::new(self.token_b_account.key(), false),
+            AccountMeta::new(self.token_a_vault.key(), false),
+            AccountMeta::new(self.token_b_vault.key(), false),
+            AccountMeta::new_readonly(self.token_a_mint.key(), false),
+            AccountMeta::new_readonly(self.token_b_mint.key(), false),
+            AccountMeta::new_readonly(self.position_nft_account.key(), false),
+            AccountMeta::new_readonly(self.owner.key(), true),
+            AccountMeta::new_readonly(self.token_a_program.key(), false),
+            AccountMeta::new_readonly(self.token_b_program.key(), false),
+            AccountMeta::new_readonly(self.event_authority.key(), false),
+            AccountMeta::new_readonly(program.key(), false),
+        ];
+        
+        let infos = vec![
+            self.pool_authority.clone(),
+            self.pool.clone(),
+            self.position.clone(),
+            self.token_a_account.clone(),
+            self.token_b_account.clone(),
+            self.token_a_vault.clone(),
+            self.token_b_vault.clone(),
+            self.token_a_mint.clone(),
+            self.token_b_mint.clone(),
+            self.position_nft_account.clone(),
+            self.owner.clone(),
+            self.token_a_program.clone(),
+            self.token_b_program.clone(),
+            self.event_authority.clone(),
+            program.clone(),
+        ];
+        
+        (metas, infos)
+    }
+}
+
+/// Accounts required by DAMM v2's `create_position` instruction, in CPI order
+pub struct CreatePosition<'a, 'info> {
+    pub owner: &'a AccountInfo<'info>,
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+}
+
+/// CPI into DAMM v2 `create_position`
+///
+/// Mints the Token-2022 position NFT to `owner`; the position starts empty.
+pub fn create_position<'a, 'info>(
+    damm_v2_program: &'a AccountInfo<'info>,
+    accounts: CreatePosition<'a, 'info>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: damm_v2_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.owner.key(), false),
+            AccountMeta::new(accounts.position_nft_mint.key(), true),
+            AccountMeta::new(accounts.position_nft_account.key(), false),
+            AccountMeta::new(accounts.pool.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.pool_authority.key(), false),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.event_authority.key(), false),
+            AccountMeta::new_readonly(damm_v2_program.key(), false),
+        ],
+        data: instruction_discriminator("create_position").to_vec(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.owner.clone(),
+            accounts.position_nft_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.pool.clone(),
+            accounts.position.clone(),
+            accounts.pool_authority.clone(),
+            accounts.payer.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.event_authority.clone(),
+            damm_v2_program.clone(),
+        ],
+        &[],
+    )?;
+    
+    Ok(())
+}
+
+#[derive(AnchorSerialize)]
+struct AddLiquidityArgs {
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+}
+
+/// CPI into DAMM v2 `add_liquidity`, signed by the position NFT owner
+///
+/// DAMM v2 has no `pool_authority` on deposits, so `accounts.pool_authority`
+/// is ignored. The source token accounts must let `owner` transfer up to the
+/// thresholds (owner or approved delegate).
+pub fn add_liquidity<'a, 'info>(
+    damm_v2_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = AddLiquidityArgs {
+        liquidity_delta,
+        token_a_amount_threshold,
+        token_b_amount_threshold,
+    };
+    
+    let mut data = instruction_discriminator("add_liquidity").to_vec();
+    args.serialize(&mut data)?;
+    
+    let (mut metas, mut infos) = accounts.metas_and_infos(damm_v2_program);
+    metas.remove(0);
+    infos.remove(0);
+    
+    invoke_signed(
+        &Instruction {
+            program_id: damm_v2_program.key(),
+            accounts: metas,
+            data,
+        },
+        &infos,
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// CPI into DAMM v2 `claim_position_fee`, signed by the position NFT owner
+///
+/// Transfers the position's pending token A and token B fees to the token
+/// accounts; a quote-only pool (collect-fee mode `OnlyB`) never pays token A.
+pub fn claim_position_fee<'a, 'info>(
+    damm_v2_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (metas, infos) = accounts.metas_and_infos(damm_v2_program);
+    
+    invoke_signed(
+        &Instruction {
+            program_id: damm_v2_program.key(),
+            accounts: metas,
+            data: instruction_discriminator("claim_position_fee").to_vec(),
+        },
+        &infos,
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+#[derive(AnchorSerialize)]
+struct RemoveAllLiquidityArgs {
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+}
+
+/// CPI into DAMM v2 `remove_all_liquidity`, signed by the position NFT owner
+///
+/// Withdraws the position's unlocked liquidity to the token accounts.
+pub fn remove_all_liquidity<'a, 'info>(
+    damm_v2_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = RemoveAllLiquidityArgs {
+        token_a_amount_threshold: 0,
+        token_b_amount_threshold: 0,
+    };
+    
+    let mut data = instruction_discriminator("remove_all_liquidity").to_vec();
+    args.serialize(&mut data)?;
+    
+    let (metas, infos) = accounts.metas_and_infos(damm_v2_program);
+    
+    invoke_signed(
+        &Instruction {
+            program_id: damm_v2_program.key(),
+            accounts: metas,
+            data,
+        },
+        &infos,
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// Accounts required by DAMM v2's `close_position` instruction, in CPI order
+pub struct ClosePosition<'a, 'info> {
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub rent_receiver: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+}
+
+/// CPI into DAMM v2 `close_position`
+///
+/// Burns the position NFT and closes the position account, which must hold
+/// no liquidity and no pending fees. Rent goes to `rent_receiver`.
+pub fn close_position<'a, 'info>(
+    damm_v2_program: &'a AccountInfo<'info>,
+    accounts: ClosePosition<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: damm_v2_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.position_nft_mint.key(), false),
+            AccountMeta::new(accounts.position_nft_account.key(), false),
+            AccountMeta::new(accounts.pool.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.pool_authority.key(), false),
+            AccountMeta::new(accounts.rent_receiver.key(), false),
+            AccountMeta::new_readonly(accounts.owner.key(), true),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.event_authority.key(), false),
+            AccountMeta::new_readonly(damm_v2_program.key(), false),
+        ],
+        data: instruction_discriminator("close_position").to_vec(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.position_nft_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.pool.clone(),
+            accounts.position.clone(),
+            accounts.pool_authority.clone(),
+            accounts.rent_receiver.clone(),
+            accounts.owner.clone(),
+            accounts.token_program.clone(),
+            accounts.event_authority.clone(),
+            damm_v2_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// Read the unlocked liquidity recorded in a DAMM v2 position account
+pub fn read_position_liquidity(position: &AccountInfo) -> Result<u128> {
+    require_keys_eq!(*position.owner, program_id(), ErrorCode::InvalidCpAmmAccount);
+    
+    let data = position.try_borrow_data()?;
+    let end = POSITION_UNLOCKED_LIQUIDITY_OFFSET + 16;
+    require!(data.len() >= end, ErrorCode::InvalidCpAmmAccount);
+    
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&data[POSITION_UNLOCKED_LIQUIDITY_OFFSET..end]);
+    Ok(u128::from_le_bytes(bytes))
+}
+
+/// Read the token A / token B fees pending on a DAMM v2 position account
+pub fn read_position_pending_fees(position: &AccountInfo) -> Result<(u64, u64)> {
+    require_keys_eq!(*position.owner, program_id(), ErrorCode::InvalidCpAmmAccount);
+    
+    let data = position.try_borrow_data()?;
+    let end = POSITION_FEE_A_PENDING_OFFSET + 16;
+    require!(data.len() >= end, ErrorCode::InvalidCpAmmAccount);
+    
+    let mut fee_a = [0u8; 8];
+    let mut fee_b = [0u8; 8];
+    fee_a.copy_from_slice(&data[POSITION_FEE_A_PENDING_OFFSET..POSITION_FEE_A_PENDING_OFFSET + 8]);
+    fee_b.copy_from_slice(&data[POSITION_FEE_A_PENDING_OFFSET + 8..end]);
+    Ok((u64::from_le_bytes(fee_a), u64::from_le_bytes(fee_b)))
+}
+
+/// Deserialize the fields we need from a DAMM v2 pool account
+pub fn read_pool(pool: &AccountInfo) -> Result<PoolSnapshot> {
+    require_keys_eq!(*pool.owner, program_id(), ErrorCode::InvalidCpAmmAccount);
+    
+    let data = pool.try_borrow_data()?;
+    require!(
+        data.len() > POOL_COLLECT_FEE_MODE_OFFSET && data[..8] == account_discriminator("Pool"),
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+    
+    let mut sqrt_price = [0u8; 16];
+    sqrt_price.copy_from_slice(&data[POOL_SQRT_PRICE_OFFSET..POOL_SQRT_PRICE_OFFSET + 16]);
+    
+    Ok(PoolSnapshot {
+        token_a_mint: read_pubkey(POOL_TOKEN_A_MINT_OFFSET),
+        token_b_mint: read_pubkey(POOL_TOKEN_A_MINT_OFFSET + 32),
+        token_a_vault: read_pubkey(POOL_TOKEN_A_VAULT_OFFSET),
+        token_b_vault: read_pubkey(POOL_TOKEN_A_VAULT_OFFSET + 32),
+        sqrt_price: u128::from_le_bytes(sqrt_price),
+        collect_fee_mode: data[POOL_COLLECT_FEE_MODE_OFFSET],
+    })
+}
+
+/// Validate a DAMM v2 pool only ever pays position fees in the quote token
+///
+/// In collect-fee mode `OnlyB` every swap fee is charged in token B, so the
+/// quote mint must be token B.
+pub fn is_quote_only_pool(pool: &PoolSnapshot, quote_mint: &Pubkey) -> bool {
+    pool.collect_fee_mode == COLLECT_FEE_MODE_ONLY_B && pool.token_b_mint == *quote_mint
+}
\ No newline at end of file
@@ -11,7 +11,7 @@ pub enum ErrorCode {
     #[msg("Base fees detected - refusing to distribute")]
     BaseFeesDetected,
     
-    #[msg("Too early for distribution - 24h not elapsed")]
+    #[msg("Too early for distribution - interval not elapsed")]
     TooEarlyForDistribution,
     
     #[msg("Math overflow detected")]
@@ -55,4 +55,130 @@ pub enum ErrorCode {
     
     #[msg("Tick out of bounds")]
     TickOutOfBounds,
+    
+    #[msg("Invalid CP-AMM program")]
+    InvalidCpAmmProgram,
+    
+    #[msg("Invalid CP-AMM account data")]
+    InvalidCpAmmAccount,
+    
+    #[msg("CP-AMM position was opened without liquidity")]
+    ZeroPositionLiquidity,
+    
+    #[msg("Token mints do not match pool state")]
+    PoolMintMismatch,
+    
+    #[msg("Tick not aligned to pool tick spacing")]
+    TickNotAlignedToSpacing,
+    
+    #[msg("Signer is not the policy creator")]
+    UnauthorizedCreator,
+    
+    #[msg("Investor registry is full")]
+    InvestorRegistryFull,
+    
+    #[msg("Investor already registered")]
+    InvestorAlreadyRegistered,
+    
+    #[msg("Investor not registered")]
+    InvestorNotRegistered,
+    
+    #[msg("Page accounts do not match investor registry")]
+    InvestorAccountsMismatch,
+    
+    #[msg("Investor ATA mint or owner does not match stream beneficiary")]
+    InvalidInvestorAta,
+    
+    #[msg("Economic policy changes require the timelocked propose/apply flow")]
+    PolicyChangeRequiresTimelock,
+    
+    #[msg("A policy change is already pending")]
+    PolicyChangeAlreadyPending,
+    
+    #[msg("No pending policy change")]
+    NoPendingPolicyChange,
+    
+    #[msg("Policy change effective time is before the timelock delay")]
+    PolicyChangeTooEarly,
+    
+    #[msg("Pending policy change has not matured")]
+    PolicyChangeNotMatured,
+    
+    #[msg("No creator nomination is pending")]
+    NoPendingCreator,
+    
+    #[msg("Signer is not the nominated creator")]
+    NotNominatedCreator,
+    
+    #[msg("Distributions are paused")]
+    DistributionsPaused,
+    
+    #[msg("Signer is neither the creator nor the guardian")]
+    UnauthorizedPauseAuthority,
+    
+    #[msg("Distributions are not paused")]
+    DistributionsNotPaused,
+    
+    #[msg("Investor streams are still locked")]
+    StreamsStillLocked,
+    
+    #[msg("Invalid token mint account")]
+    InvalidMintAccount,
+    
+    #[msg("Distribution interval out of range")]
+    InvalidDistributionInterval,
+    
+    #[msg("Maximum day duration out of range")]
+    InvalidMaxDayDuration,
+    
+    #[msg("No distribution day in progress")]
+    NoDayInProgress,
+    
+    #[msg("Day has not exceeded its maximum duration")]
+    DayNotStale,
+    
+    #[msg("Crank reward exceeds maximum basis points")]
+    InvalidCrankReward,
+    
+    #[msg("Creator split needs unique recipients with weights summing to 10000 bps")]
+    InvalidCreatorSplit,
+    
+    #[msg("Split recipient accounts do not match creator split")]
+    CreatorSplitAccountsMismatch,
+    
+    #[msg("Maximum number of honorary positions reached")]
+    TooManyPositions,
+    
+    #[msg("Position accounts do not match registered positions")]
+    PositionAccountsMismatch,
+    
+    #[msg("Honorary positions are still registered")]
+    PositionsStillRegistered,
+    
+    #[msg("DAMM v2 pool must collect fees only in the quote token (token B)")]
+    PoolNotQuoteOnly,
+    
+    #[msg("Position belongs to a different AMM")]
+    AmmKindMismatch,
+    
+    #[msg("Invalid linear-vesting escrow data")]
+    InvalidEscrowData,
+    
+    #[msg("Stream account is not owned by a supported lock provider")]
+    UnsupportedLockProvider,
+    
+    #[msg("Stream is not a lock of the vault's vesting mint from its allowed sender")]
+    StreamNotBoundToVault,
+    
+    #[msg("Position NFT account must hold the position NFT for the position owner")]
+    PositionNftNotHeld,
+    
+    #[msg("Token account is not owned by the expected authority")]
+    InvalidTokenAccountOwner,
+    
+    #[msg("Creator quote ATA does not match the policy")]
+    CreatorQuoteAtaMismatch,
+    
+    #[msg("Policy does not belong to this vault")]
+    PolicyVaultMismatch,
 }
\ No newline at end of file
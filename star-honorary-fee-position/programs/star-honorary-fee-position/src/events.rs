@@ -1,18 +1,104 @@
 use anchor_lang::prelude::*;
+use crate::state::{AmmKind, CapOverflowMode, SplitRecipient};
 
 #[event]
 pub struct PolicyInitialized {
     pub vault: Pubkey,
     pub creator_wallet: Pubkey,
     pub quote_mint: Pubkey,
+    pub vesting_mint: Pubkey,
+    pub allowed_stream_sender: Pubkey,
     pub investor_fee_share_bps: u16,
     pub daily_cap_lamports: Option<u64>,
+    pub cap_overflow_mode: CapOverflowMode,
+    pub crank_reward_bps: u16,
+    pub crank_reward_cap_lamports: Option<u64>,
     pub y0_total_allocation: u64,
+    pub distribution_interval_secs: i64,
+    pub align_to_epoch: bool,
+    pub max_day_duration_secs: i64,
+    pub policy_change_delay_days: u16,
+    pub guardian: Option<Pubkey>,
+}
+
+#[event]
+pub struct PolicyUpdated {
+    pub vault: Pubkey,
+    pub old_investor_fee_share_bps: u16,
+    pub new_investor_fee_share_bps: u16,
+    pub old_daily_cap_lamports: Option<u64>,
+    pub new_daily_cap_lamports: Option<u64>,
+    pub old_min_payout_lamports: u64,
+    pub new_min_payout_lamports: u64,
+    pub old_creator_quote_ata: Pubkey,
+    pub new_creator_quote_ata: Pubkey,
+}
+
+#[event]
+pub struct PolicyChangeProposed {
+    pub vault: Pubkey,
+    pub investor_fee_share_bps: Option<u16>,
+    pub daily_cap_lamports: Option<u64>,
+    pub clear_daily_cap: bool,
+    pub min_payout_lamports: Option<u64>,
+    pub proposed_ts: i64,
+    pub effective_ts: i64,
+}
+
+#[event]
+pub struct PolicyChangeApplied {
+    pub vault: Pubkey,
+    pub proposed_ts: i64,
+    pub effective_ts: i64,
+    pub applied_ts: i64,
+}
+
+#[event]
+pub struct PolicyChangeCancelled {
+    pub vault: Pubkey,
+    pub proposed_ts: i64,
+    pub effective_ts: i64,
+}
+
+#[event]
+pub struct CreatorNominated {
+    pub vault: Pubkey,
+    pub current_creator: Pubkey,
+    pub nominee: Pubkey,
+    pub nominee_quote_ata: Option<Pubkey>,
+}
+
+#[event]
+pub struct CreatorAccepted {
+    pub vault: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+    pub old_creator_quote_ata: Pubkey,
+    pub new_creator_quote_ata: Pubkey,
+}
+
+#[event]
+pub struct VaultPaused {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub pagination_cursor: u32,
+    pub day_complete: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUnpaused {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub pagination_cursor: u32,
+    pub day_complete: bool,
+    pub timestamp: i64,
 }
 
 #[event]
 pub struct HonoraryPositionInitialized {
     pub vault: Pubkey,
+    pub amm_kind: AmmKind,
     pub pool: Pubkey,
     pub position_id: Pubkey,
     pub tick_lower: i32,
@@ -26,15 +112,37 @@ pub struct QuoteFeesClaimed {
     pub vault: Pubkey,
     pub amount: u64,
     pub total_distributed: u64,
+    pub crank_reward: u64,
     pub page: u32,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct LockedTotalSnapshotted {
+    pub vault: Pubkey,
+    pub locked_total: u64,
+    pub f_locked_bps: u16,
+    pub investor_pool: u64,
+    pub pages: u32,
+}
+
+#[event]
+pub struct CapOverflow {
+    pub vault: Pubkey,
+    pub day_ts: i64,
+    pub uncapped_pool: u64,
+    pub investor_pool: u64,
+    pub overflow: u64,
+    pub mode: CapOverflowMode,
+    pub deferred_investor_balance: u64,
+}
+
 #[event]
 pub struct InvestorPayoutPage {
     pub vault: Pubkey,
     pub investor: Pubkey,
     pub amount: u64,
+    pub net_amount: u64,
     pub locked_amount: u64,
     pub page: u32,
 }
@@ -44,13 +152,86 @@ pub struct CreatorPayoutDayClosed {
     pub vault: Pubkey,
     pub creator: Pubkey,
     pub amount: u64,
+    pub net_amount: u64,
     pub day_ts: i64,
     pub total_distributed_to_investors: u64,
 }
 
+#[event]
+pub struct CreatorSplitPayout {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub weight_bps: u16,
+    pub amount: u64,
+    pub net_amount: u64,
+    pub day_ts: i64,
+}
+
+#[event]
+pub struct CreatorSplitRedirected {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub day_ts: i64,
+}
+
 #[event]
 pub struct DustCarriedOver {
     pub vault: Pubkey,
     pub dust_amount: u64,
     pub page: u32,
+}
+
+#[event]
+pub struct InvestorAdded {
+    pub vault: Pubkey,
+    pub stream: Pubkey,
+    pub investor_quote_ata: Pubkey,
+    pub index: u32,
+}
+
+#[event]
+pub struct InvestorRemoved {
+    pub vault: Pubkey,
+    pub stream: Pubkey,
+    pub investor_quote_ata: Pubkey,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault: Pubkey,
+    pub creator: Pubkey,
+    pub recipient: Pubkey,
+    pub quote_swept: u64,
+    pub creator_opt_out: bool,
+}
+
+#[event]
+pub struct PositionRemoved {
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub liquidity_withdrawn: u128,
+    pub fees_settled: u64,
+    pub quote_withdrawn: u64,
+    pub base_withdrawn: u64,
+    pub creator_opt_out: bool,
+}
+
+#[event]
+pub struct DayForceClosed {
+    pub vault: Pubkey,
+    pub closed_by: Pubkey,
+    pub day_ts: i64,
+    pub pages_completed: u32,
+    pub snapshot_complete: bool,
+    pub unpaid_investor_amount: u64,
+    pub rolled_over_quote: u64,
+    pub creator_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorSplitUpdated {
+    pub vault: Pubkey,
+    pub recipients: Vec<SplitRecipient>,
 }
\ No newline at end of file
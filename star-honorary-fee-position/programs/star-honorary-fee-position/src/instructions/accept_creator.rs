@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CreatorAccepted;
+use crate::state::PolicyState;
+
+#[derive(Accounts)]
+pub struct AcceptCreator<'info> {
+    /// Nominated creator
+    pub new_creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyState>,
+}
+
+pub fn handler(ctx: Context<AcceptCreator>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    
+    let nominee = policy.pending_creator.ok_or(ErrorCode::NoPendingCreator)?;
+    require_keys_eq!(
+        nominee,
+        ctx.accounts.new_creator.key(),
+        ErrorCode::NotNominatedCreator
+    );
+    
+    let old_creator = policy.creator_wallet;
+    let old_creator_quote_ata = policy.creator_quote_ata;
+    
+    policy.creator_wallet = nominee;
+    if let Some(new_creator_quote_ata) = policy.pending_creator_quote_ata {
+        policy.creator_quote_ata = new_creator_quote_ata;
+    }
+    
+    policy.pending_creator = None;
+    policy.pending_creator_quote_ata = None;
+    
+    emit!(CreatorAccepted {
+        vault: ctx.accounts.vault.key(),
+        old_creator,
+        new_creator: nominee,
+        old_creator_quote_ata,
+        new_creator_quote_ata: policy.creator_quote_ata,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::InvestorAdded;
+use crate::state::{InvestorEntry, InvestorRegistry, PolicyState, ProgressState};
+use crate::lock;
+
+#[derive(Accounts)]
+pub struct AddInvestor<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - registry may not change mid-day
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// Investor registry
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            REGISTRY_SEED,
+        ],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, InvestorRegistry>,
+    
+    /// Investor's stream (Streamflow or linear-vesting escrow)
+    /// CHECK: Parsed by the lock provider owning it in handler
+    pub stream: UncheckedAccount<'info>,
+    
+    /// Investor's quote token ATA
+    #[account(
+        constraint = investor_quote_ata.mint == policy.quote_mint @ ErrorCode::InvalidInvestorAta,
+    )]
+    pub investor_quote_ata: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<AddInvestor>) -> Result<()> {
+    // Stream must be a valid lock bound to the vault, paying out to the ATA owner
+    let now = Clock::get()?.unix_timestamp;
+    let stream = lock::read_vault_stream_lock(
+        &ctx.accounts.stream.to_account_info(),
+        &ctx.accounts.policy,
+        now,
+    )?;
+    require_keys_eq!(
+        ctx.accounts.investor_quote_ata.owner,
+        stream.recipient,
+        ErrorCode::InvalidInvestorAta
+    );
+    
+    let stream_key = ctx.accounts.stream.key();
+    let registry = &mut ctx.accounts.registry;
+    
+    require!(
+        registry.investors.len() < MAX_REGISTERED_INVESTORS,
+        ErrorCode::InvestorRegistryFull
+    );
+    
+    require!(
+        !registry.investors.iter().any(|entry| entry.stream == stream_key),
+        ErrorCode::InvestorAlreadyRegistered
+    );
+    
+    registry.investors.push(InvestorEntry {
+        stream: stream_key,
+        investor_quote_ata: ctx.accounts.investor_quote_ata.key(),
+    });
+    
+    emit!(InvestorAdded {
+        vault: ctx.accounts.vault.key(),
+        stream: stream_key,
+        investor_quote_ata: ctx.accounts.investor_quote_ata.key(),
+        index: (registry.investors.len() - 1) as u32,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::{PolicyChangeApplied, PolicyUpdated};
+use crate::state::{PolicyState, ProgressState};
+
+#[derive(Accounts)]
+pub struct ApplyPolicyChange<'info> {
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - policy may not change mid-day
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<ApplyPolicyChange>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let now = ctx.accounts.clock.unix_timestamp;
+    
+    let pending = policy
+        .pending_change
+        .take()
+        .ok_or(ErrorCode::NoPendingPolicyChange)?;
+    
+    require!(
+        now >= pending.effective_ts,
+        ErrorCode::PolicyChangeNotMatured
+    );
+    
+    let old_investor_fee_share_bps = policy.investor_fee_share_bps;
+    let old_daily_cap_lamports = policy.daily_cap_lamports;
+    let old_min_payout_lamports = policy.min_payout_lamports;
+    
+    policy.apply_economic_change(
+        pending.investor_fee_share_bps,
+        pending.daily_cap_lamports,
+        pending.clear_daily_cap,
+        pending.min_payout_lamports,
+    )?;
+    
+    emit!(PolicyChangeApplied {
+        vault: ctx.accounts.vault.key(),
+        proposed_ts: pending.proposed_ts,
+        effective_ts: pending.effective_ts,
+        applied_ts: now,
+    });
+    
+    emit!(PolicyUpdated {
+        vault: ctx.accounts.vault.key(),
+        old_investor_fee_share_bps,
+        new_investor_fee_share_bps: policy.investor_fee_share_bps,
+        old_daily_cap_lamports,
+        new_daily_cap_lamports: policy.daily_cap_lamports,
+        old_min_payout_lamports,
+        new_min_payout_lamports: policy.min_payout_lamports,
+        old_creator_quote_ata: policy.creator_quote_ata,
+        new_creator_quote_ata: policy.creator_quote_ata,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
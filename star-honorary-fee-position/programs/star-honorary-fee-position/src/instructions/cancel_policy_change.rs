@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PolicyChangeCancelled;
+use crate::state::PolicyState;
+
+#[derive(Accounts)]
+pub struct CancelPolicyChange<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+}
+
+pub fn handler(ctx: Context<CancelPolicyChange>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    
+    let pending = policy
+        .pending_change
+        .take()
+        .ok_or(ErrorCode::NoPendingPolicyChange)?;
+    
+    emit!(PolicyChangeCancelled {
+        vault: ctx.accounts.vault.key(),
+        proposed_ts: pending.proposed_ts,
+        effective_ts: pending.effective_ts,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
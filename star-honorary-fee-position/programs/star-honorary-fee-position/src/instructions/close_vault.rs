@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::VaultClosed;
+use crate::state::{CreatorSplit, InvestorRegistry, PolicyState, ProgressState};
+use crate::lock;
+use crate::transfer_fee;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CloseVaultParams {
+    /// Close even though investor streams are still locked (investors forgo future fees)
+    pub creator_opt_out: bool,
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Receives the reclaimed rent of every closed account
+    /// CHECK: Any account may receive lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state (closed)
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+        constraint = policy.position_pools.is_empty() @ ErrorCode::PositionsStillRegistered,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state (closed) - no day may be in progress
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// Investor registry (closed)
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            REGISTRY_SEED,
+        ],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, InvestorRegistry>,
+    
+    /// Creator split (closed)
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CREATOR_SPLIT_SEED,
+        ],
+        bump = creator_split.bump,
+    )]
+    pub creator_split: Account<'info, CreatorSplit>,
+    
+    /// Program quote treasury (swept and closed)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+        ],
+        bump,
+    )]
+    pub program_quote_treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Treasury authority PDA
+    /// CHECK: PDA used as authority for treasury
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+            b"authority",
+        ],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+    
+    /// Creator's quote token ATA (receives the quote sweep)
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ ErrorCode::CreatorQuoteAtaMismatch,
+    )]
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Quote token mint (mutable so withheld Token-2022 fees can be harvested)
+    #[account(
+        mut,
+        constraint = quote_mint.key() == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Token program owning the quote mint
+    #[account(
+        constraint = quote_token_program.key() == *quote_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    
+    // Remaining accounts (unless creator_opt_out):
+    // Every registry entry's stream account, in registry order
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>,
+    params: CloseVaultParams,
+) -> Result<()> {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let vault_key = ctx.accounts.vault.key();
+    
+    // 1. Investors must have nothing left locked, unless the creator opts out
+    if !params.creator_opt_out {
+        require_streams_unlocked(&ctx.accounts.registry, ctx.remaining_accounts, now)?;
+    }
+    
+    // 2. Sweep the quote treasury to the creator, then close it
+    // (positions and their base treasuries were closed by remove_position)
+    let quote_swept = ctx.accounts.program_quote_treasury.amount;
+    
+    let treasury_seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        TREASURY_SEED,
+        b"authority",
+        &[ctx.bumps.treasury_authority],
+    ];
+    let treasury_signer = &[&treasury_seeds[..]];
+    
+    let quote_treasury_info = ctx.accounts.program_quote_treasury.to_account_info();
+    let quote_mint_info = ctx.accounts.quote_mint.to_account_info();
+    let quote_token_program = ctx.accounts.quote_token_program.to_account_info();
+    
+    if quote_swept > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                quote_token_program.clone(),
+                TransferChecked {
+                    from: quote_treasury_info.clone(),
+                    mint: quote_mint_info.clone(),
+                    to: ctx.accounts.creator_quote_ata.to_account_info(),
+                    authority: ctx.accounts.treasury_authority.to_account_info(),
+                },
+                treasury_signer,
+            ),
+            quote_swept,
+            ctx.accounts.quote_mint.decimals,
+        )?;
+    }
+    
+    // Token-2022 refuses to close accounts that still hold withheld transfer fees
+    transfer_fee::harvest_withheld_to_mint(&quote_token_program, &quote_mint_info, &quote_treasury_info)?;
+    
+    token_interface::close_account(CpiContext::new_with_signer(
+        quote_token_program,
+        CloseAccount {
+            account: quote_treasury_info,
+            destination: ctx.accounts.recipient.to_account_info(),
+            authority: ctx.accounts.treasury_authority.to_account_info(),
+        },
+        treasury_signer,
+    ))?;
+    
+    // 3. State PDAs are closed to the recipient by their `close` constraints
+    emit!(VaultClosed {
+        vault: vault_key,
+        creator: ctx.accounts.creator.key(),
+        recipient: ctx.accounts.recipient.key(),
+        quote_swept,
+        creator_opt_out: params.creator_opt_out,
+    });
+    
+    Ok(())
+}
+
+/// Require every registered investor stream (passed in registry order) to be fully unlocked
+pub(crate) fn require_streams_unlocked(
+    registry: &InvestorRegistry,
+    stream_accounts: &[AccountInfo],
+    now: i64,
+) -> Result<()> {
+    require!(
+        stream_accounts.len() == registry.investors.len(),
+        ErrorCode::InvalidInvestorCount
+    );
+    
+    for (entry, stream_account) in registry.investors.iter().zip(stream_accounts.iter()) {
+        require_keys_eq!(
+            stream_account.key(),
+            entry.stream,
+            ErrorCode::InvestorAccountsMismatch
+        );
+        
+        let stream = lock::read_stream_lock(stream_account, now)?;
+        require!(stream.locked_amount == 0, ErrorCode::StreamsStillLocked);
+    }
+    
+    Ok(())
+}
\ No newline at end of file
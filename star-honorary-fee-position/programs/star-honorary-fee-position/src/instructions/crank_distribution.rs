@@ -1,21 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Token};
+use anchor_spl::token_2022::{self as token_2022, Token2022};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::amm::{self, AmmAccounts};
 use crate::constants::*;
+use crate::cp_amm;
 use crate::errors::ErrorCode;
 use crate::events::*;
-use crate::state::{PolicyState, PositionState, ProgressState};
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct CrankDistributionParams {
-    /// Number of investors in this page
-    pub investor_count: u8,
-    
-    /// Whether this is the last page of the day
-    pub is_last_page: bool,
-}
+use crate::math;
+use crate::state::{
+    CapOverflowMode, CreatorSplit, InvestorRegistry, PolicyState, PositionState,
+    ProgressState,
+};
+use crate::lock;
+use crate::transfer_fee;
 
 #[derive(Accounts)]
-#[instruction(params: CrankDistributionParams)]
 pub struct CrankDistribution<'info> {
     /// Cranker (can be anyone)
     pub cranker: Signer<'info>,
@@ -32,6 +32,7 @@ pub struct CrankDistribution<'info> {
             POLICY_SEED,
         ],
         bump = policy.bump,
+        constraint = !policy.paused @ ErrorCode::DistributionsPaused,
     )]
     pub policy: Account<'info, PolicyState>,
     
@@ -47,16 +48,27 @@ pub struct CrankDistribution<'info> {
     )]
     pub progress: Account<'info, ProgressState>,
     
-    /// Position state (readonly - position configuration)
+    /// Investor registry (defines page membership and order)
     #[account(
         seeds = [
             VAULT_SEED,
             vault.key().as_ref(),
-            POSITION_STATE_SEED,
+            REGISTRY_SEED,
         ],
-        bump = position_state.bump,
+        bump = registry.bump,
     )]
-    pub position_state: Account<'info, PositionState>,
+    pub registry: Account<'info, InvestorRegistry>,
+    
+    /// Creator split (recipients of the creator remainder)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CREATOR_SPLIT_SEED,
+        ],
+        bump = creator_split.bump,
+    )]
+    pub creator_split: Account<'info, CreatorSplit>,
     
     /// Position owner PDA
     /// CHECK: PDA that owns the position NFT
@@ -81,7 +93,7 @@ pub struct CrankDistribution<'info> {
         bump,
         constraint = program_quote_treasury.mint == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
     )]
-    pub program_quote_treasury: Account<'info, TokenAccount>,
+    pub program_quote_treasury: InterfaceAccount<'info, TokenAccount>,
     
     /// Treasury authority PDA
     /// CHECK: PDA used as authority for treasury
@@ -101,37 +113,50 @@ pub struct CrankDistribution<'info> {
         mut,
         constraint = creator_quote_ata.key() == policy.creator_quote_ata @ ErrorCode::InvalidPageParameters,
     )]
-    pub creator_quote_ata: Account<'info, TokenAccount>,
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
     
-    /// CP-AMM pool state
-    /// CHECK: Validated by CP-AMM program
-    #[account(mut)]
-    pub pool_state: UncheckedAccount<'info>,
+    /// Cranker's quote token account (optional - receives the crank reward)
+    #[account(
+        mut,
+        constraint = cranker_quote_ata.mint == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+        constraint = cranker_quote_ata.owner == cranker.key() @ ErrorCode::InvalidTokenAccountOwner,
+    )]
+    pub cranker_quote_ata: Option<InterfaceAccount<'info, TokenAccount>>,
     
-    /// CP-AMM protocol position
-    /// CHECK: Validated by CP-AMM program
-    #[account(mut)]
-    pub protocol_position: UncheckedAccount<'info>,
+    /// Quote token mint (decimals and transfer fee config)
+    #[account(
+        constraint = quote_mint.key() == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
     
-    /// Position NFT account
-    /// CHECK: Validated by CP-AMM program
-    pub position_nft_account: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub token_program_2022: Program<'info, Token2022>,
     
-    /// CP-AMM Program
-    /// CHECK: CP-AMM program ID
-    pub cp_amm_program: UncheckedAccount<'info>,
+    /// Token program owning the quote mint (used for payouts)
+    #[account(
+        constraint = quote_token_program.key() == *quote_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL memo program (required by Token-2022 aware AMM instructions)
+    /// CHECK: Must match the memo program ID
+    #[account(
+        constraint = memo_program.key() == cp_amm::memo_program_id() @ ErrorCode::InvalidCpAmmAccount,
+    )]
+    pub memo_program: UncheckedAccount<'info>,
     
-    pub token_program: Program<'info, Token>,
     pub clock: Sysvar<'info, Clock>,
     
     // Remaining accounts:
-    // For each investor: [stream_account, investor_quote_ata]
-    // Stream accounts are Streamflow stream data accounts
+    // On the first page of a day, one group per registered position, in policy.position_pools order:
+    // [position_state, amm_program, pool, program_base_treasury, base_mint, ...AMM position accounts]
+    // For each registry entry on the current page, in order: [stream_account, investor_quote_ata]
+    // Stream accounts may belong to any supported lock provider (see lock::LockProviderKind)
+    // On the last payout page, followed by each creator split recipient's quote ATA, in order
 }
 
 pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, CrankDistribution<'info>>,
-    params: CrankDistributionParams,
 ) -> Result<()> {
     let clock = &ctx.accounts.clock;
     let now = clock.unix_timestamp;
@@ -139,116 +164,175 @@ pub fn handler<'info>(
     let progress = &mut ctx.accounts.progress;
     let policy = &ctx.accounts.policy;
     
-    require!(
-        params.investor_count as usize <= MAX_INVESTORS_PER_PAGE,
-        ErrorCode::InvalidInvestorCount
-    );
-    
-    require!(
-        ctx.remaining_accounts.len() >= (params.investor_count as usize * 2),
-        ErrorCode::InvalidInvestorCount
-    );
-    
-    // 1. Check 24-hour gate and pagination state
-    if progress.pagination_cursor == 0 {
+    // 1. Check distribution interval gate and pagination state
+    let is_new_day = progress.day_complete;
+    if is_new_day {
         // First page of a new day
         require!(
-            progress.day_complete,
-            ErrorCode::DistributionNotComplete
+            progress.pagination_cursor == 0,
+            ErrorCode::PaginationStateMismatch
         );
         
         require!(
-            now >= progress.last_distribution_ts + SECONDS_PER_DAY,
+            policy.is_distribution_due(progress, now),
             ErrorCode::TooEarlyForDistribution
         );
         
         // Reset for new day
-        progress.current_day_ts = now;
-        progress.daily_distributed = 0;
-        progress.day_complete = false;
-    } else {
-        // Continuing pagination - ensure we're in the same day
-        require!(
-            !progress.day_complete,
-            ErrorCode::PaginationStateMismatch
-        );
+        progress.start_day(now);
     }
     
-    // 2. Claim fees from honorary position
-    // In a real implementation, this would call CP-AMM's collect_fees
-    // For this framework, we simulate by reading the treasury balance
-    let claimed_quote_amount = ctx.accounts.program_quote_treasury.amount;
-    
-    // CRITICAL: In real implementation, verify base_fees == 0
-    // This would come from the CP-AMM collect_fees return value
+    // Page membership comes from the registry - accounts must match it exactly, in order.
+    // This is also the replay guard: once a page is paid the cursor has moved past it,
+    // so re-sending its accounts no longer matches (a failed page rolls back entirely).
+    let registry = &ctx.accounts.registry;
+    let page_entries = registry.page(progress.pagination_cursor);
+    let investor_count = page_entries.len();
+    let is_last_page = progress.pagination_cursor + 1 >= registry.page_count();
+    
+    // The last payout page also carries the creator split recipients
+    let split_recipient_count = if is_last_page && progress.locked_snapshot_complete {
+        ctx.accounts.creator_split.recipients.len()
+    } else {
+        0
+    };
     
-    msg!("Claimed quote fees: {}", claimed_quote_amount);
+    // The first page of a day also carries every registered position
+    let vault_key = ctx.accounts.vault.key();
+    let (position_groups, page_accounts) = if is_new_day {
+        split_position_groups(ctx.remaining_accounts, &vault_key, &policy.position_pools)?
+    } else {
+        (Vec::new(), ctx.remaining_accounts)
+    };
     
-    // Add carry-over from previous pages
-    let total_available = claimed_quote_amount
-        .checked_add(progress.carry_over_dust)
-        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        page_accounts.len() == investor_count * 2 + split_recipient_count,
+        ErrorCode::InvalidInvestorCount
+    );
     
-    // 3. Calculate investor share based on locked amounts
-    let mut locked_total = 0u64;
-    let mut locked_amounts = Vec::new();
+    for (i, entry) in page_entries.iter().enumerate() {
+        require!(
+            page_accounts[i * 2].key() == entry.stream
+                && page_accounts[i * 2 + 1].key() == entry.investor_quote_ata,
+            ErrorCode::InvestorAccountsMismatch
+        );
+    }
     
-    // Read Streamflow locked amounts from remaining accounts
-    for i in 0..params.investor_count as usize {
-        let stream_account = &ctx.remaining_accounts[i * 2];
+    // 2. Claim fees from every registered position (first page of the day only)
+    let claimed_quote_amount = if is_new_day {
+        let quote_before = ctx.accounts.program_quote_treasury.amount;
+        
+        let owner_seeds = &[
+            VAULT_SEED,
+            vault_key.as_ref(),
+            POSITION_OWNER_SEED,
+            &[ctx.bumps.position_owner_pda],
+        ];
         
-        // Parse Streamflow stream data
-        // In a real implementation, this would deserialize the Streamflow account
-        // For this framework, we'll use a simplified mock approach
-        let locked_amount = parse_streamflow_locked_amount(stream_account, now)?;
+        let quote_treasury_info = ctx.accounts.program_quote_treasury.to_account_info();
+        let quote_mint_info = ctx.accounts.quote_mint.to_account_info();
+        let position_owner = ctx.accounts.position_owner_pda.to_account_info();
+        let quote_token_program = ctx.accounts.quote_token_program.to_account_info();
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let token_program_2022 = ctx.accounts.token_program_2022.to_account_info();
+        let memo_program = ctx.accounts.memo_program.to_account_info();
         
-        locked_amounts.push(locked_amount);
-        locked_total = locked_total
-            .checked_add(locked_amount)
+        for (position_state, group) in position_groups.iter() {
+            let (header, venue) = group.split_at(POSITION_GROUP_HEADER_ACCOUNTS);
+            
+            require!(
+                header[1].key() == amm::program_id(position_state.amm_kind),
+                ErrorCode::AmmKindMismatch
+            );
+            require!(
+                header[2].key() == position_state.pool_id
+                    && header[3].key() == position_state.base_treasury
+                    && header[4].key() == position_state.base_mint,
+                ErrorCode::PositionAccountsMismatch
+            );
+            amm::validate_position_accounts(
+                position_state.amm_kind,
+                venue,
+                position_state,
+                position_owner.key,
+            )?;
+            
+            // Refuse early if the venue already records base fees owed to the position
+            let unclaimed = amm::read_unclaimed_fees(position_state.amm_kind, venue, position_state)?;
+            require!(unclaimed.base == 0, ErrorCode::BaseFeesDetected);
+            
+            let base_treasury_info = &header[3];
+            let base_mint_info = &header[4];
+            let base_token_program = if *base_mint_info.owner == token_2022::ID {
+                &token_program_2022
+            } else {
+                &token_program
+            };
+            let base_before = token_account_amount(base_treasury_info)?;
+            
+            amm::collect_fees(
+                &AmmAccounts {
+                    amm_program: &header[1],
+                    pool: &header[2],
+                    position_owner: &position_owner,
+                    quote_mint: &quote_mint_info,
+                    base_mint: base_mint_info,
+                    quote_account: &quote_treasury_info,
+                    base_account: base_treasury_info,
+                    quote_token_program: &quote_token_program,
+                    base_token_program,
+                    token_program: &token_program,
+                    token_program_2022: &token_program_2022,
+                    memo_program: &memo_program,
+                    venue,
+                },
+                position_state,
+                &[&owner_seeds[..]],
+            )?;
+            
+            // CRITICAL: Quote-only guarantee - refuse to distribute if any base fees arrived
+            let base_received = token_account_amount(base_treasury_info)?
+                .checked_sub(base_before)
+                .ok_or(ErrorCode::MathOverflow)?;
+            require!(base_received == 0, ErrorCode::BaseFeesDetected);
+        }
+        
+        ctx.accounts.program_quote_treasury.reload()?;
+        
+        let quote_received = ctx.accounts.program_quote_treasury.amount
+            .checked_sub(quote_before)
             .ok_or(ErrorCode::MathOverflow)?;
-    }
-    
-    msg!("Total locked: {}", locked_total);
-    
-    // Calculate f_locked(t) = locked_total / Y0 in basis points
-    let f_locked_bps = if policy.y0_total_allocation > 0 {
-        let fraction = locked_total
-            .checked_mul(MAX_BPS as u64)
-            .ok_or(ErrorCode::MathOverflow)?
-            .checked_div(policy.y0_total_allocation)
-            .unwrap_or(0);
-        fraction.min(MAX_BPS as u64) as u16
+        
+        // Treasury deltas are net of any inbound Token-2022 transfer fee, so this is
+        // exactly what the treasury can pay out.
+        // Snapshot the day's claimed amount - every page splits this same amount.
+        // Claims from a force-closed day that never finished its snapshot join today's.
+        let claimed_total = quote_received
+            .checked_add(progress.rolled_over_claimed_quote)
+            .ok_or(ErrorCode::MathOverflow)?;
+        progress.rolled_over_claimed_quote = 0;
+        
+        // Crank reward budget comes off the top, before the investor/creator split
+        let crank_reward_budget = math::crank_reward_budget(
+            claimed_total,
+            policy.crank_reward_bps,
+            policy.crank_reward_cap_lamports,
+        )?;
+        progress.day_crank_reward_budget = crank_reward_budget;
+        progress.day_claimed_quote = claimed_total
+            .checked_sub(crank_reward_budget)
+            .ok_or(ErrorCode::MathOverflow)?;
+        progress.total_claimed_lifetime = progress.total_claimed_lifetime
+            .checked_add(quote_received)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        quote_received
     } else {
         0
     };
     
-    msg!("f_locked: {} bps", f_locked_bps);
-    
-    // Calculate eligible investor share
-    let eligible_investor_share_bps = policy.investor_fee_share_bps.min(f_locked_bps);
-    
-    // Calculate investor portion
-    let investor_fee_quote = total_available
-        .checked_mul(eligible_investor_share_bps as u64)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(MAX_BPS as u64)
-        .unwrap_or(0);
-    
-    msg!("Investor fee portion: {}", investor_fee_quote);
-    
-    // Apply daily cap if set
-    let investor_fee_quote = if let Some(cap) = policy.daily_cap_lamports {
-        let remaining_cap = cap.saturating_sub(progress.daily_distributed);
-        investor_fee_quote.min(remaining_cap)
-    } else {
-        investor_fee_quote
-    };
-    
-    // 4. Distribute to investors pro-rata
-    let mut total_distributed = 0u64;
-    let mut dust_accumulated = progress.carry_over_dust;
+    msg!("Claimed quote fees: {}", claimed_quote_amount);
     
-    let vault_key = ctx.accounts.vault.key();
     let treasury_seeds = &[
         VAULT_SEED,
         vault_key.as_ref(),
@@ -258,106 +342,244 @@ pub fn handler<'info>(
     ];
     let signer_seeds = &[&treasury_seeds[..]];
     
-    for i in 0..params.investor_count as usize {
-        let investor_ata_info = &ctx.remaining_accounts[i * 2 + 1];
-        let locked_amount = locked_amounts[i];
-        
-        if locked_total > 0 && locked_amount > 0 {
-            // Calculate pro-rata share
-            let investor_share = investor_fee_quote
-                .checked_mul(locked_amount)
-                .ok_or(ErrorCode::MathOverflow)?
-                .checked_div(locked_total)
-                .unwrap_or(0);
-            
-            msg!("Investor {} share: {}", i, investor_share);
+    // 3. Pay the cranker an even slice of the day's reward budget for this page
+    let crank_reward = match ctx.accounts.cranker_quote_ata.as_ref() {
+        Some(cranker_quote_ata) => {
+            let reward = math::crank_reward_per_page(
+                progress.day_crank_reward_budget,
+                registry.page_count(),
+            )
+            .min(progress.day_crank_reward_budget.saturating_sub(progress.day_crank_reward_paid));
             
-            // Apply minimum payout threshold
-            if investor_share >= policy.min_payout_lamports {
-                // Transfer to investor
-                let cpi_accounts = Transfer {
+            if reward > 0 {
+                let cpi_accounts = TransferChecked {
                     from: ctx.accounts.program_quote_treasury.to_account_info(),
-                    to: investor_ata_info.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: cranker_quote_ata.to_account_info(),
                     authority: ctx.accounts.treasury_authority.to_account_info(),
                 };
                 
                 let cpi_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.quote_token_program.to_account_info(),
                     cpi_accounts,
                     signer_seeds,
                 );
                 
-                token::transfer(cpi_ctx, investor_share)?;
+                token_interface::transfer_checked(cpi_ctx, reward, ctx.accounts.quote_mint.decimals)?;
                 
-                total_distributed = total_distributed
-                    .checked_add(investor_share)
+                progress.day_crank_reward_paid = progress.day_crank_reward_paid
+                    .checked_add(reward)
                     .ok_or(ErrorCode::MathOverflow)?;
-                
-                emit!(InvestorPayoutPage {
+            }
+            
+            reward
+        }
+        None => 0,
+    };
+    
+    msg!("Crank reward: {}", crank_reward);
+    
+    // 4. Read locked amounts from remaining accounts
+    let mut page_locked_total = 0u64;
+    let mut locked_amounts = Vec::new();
+    
+    for i in 0..investor_count {
+        let stream_account = &page_accounts[i * 2];
+        let investor_ata_info = &page_accounts[i * 2 + 1];
+        
+        // Parse the stream through the lock provider owning it; only locks of the
+        // vesting mint from the allowed sender count toward locked_total
+        let stream = lock::read_vault_stream_lock(stream_account, policy, now)?;
+        let locked_amount = stream.locked_amount;
+        
+        // Payouts may only go to the stream beneficiary's quote account
+        validate_investor_ata(investor_ata_info, &stream.recipient, &policy.quote_mint)?;
+        
+        locked_amounts.push(locked_amount);
+        page_locked_total = page_locked_total
+            .checked_add(locked_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    
+    msg!("Page locked: {}", page_locked_total);
+    
+    // 5. Snapshot pass - accumulate the global locked total before paying anyone
+    if !progress.locked_snapshot_complete {
+        progress.day_locked_total = progress.day_locked_total
+            .checked_add(page_locked_total)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        if is_last_page {
+            // Calculate f_locked(t) = locked_total / Y0 and the day's investor pool
+            let f_locked_bps = math::f_locked_bps(
+                progress.day_locked_total,
+                policy.y0_total_allocation,
+            )?;
+            
+            msg!("f_locked: {} bps", f_locked_bps);
+            
+            let uncapped_pool = math::investor_pool(
+                progress.day_claimed_quote,
+                policy.investor_fee_share_bps,
+                f_locked_bps,
+            )?;
+            
+            // Deferred overflow from earlier days competes for today's cap
+            let deferred_in = match policy.cap_overflow_mode {
+                CapOverflowMode::Deferred => progress.deferred_investor_balance,
+                _ => 0,
+            };
+            let available = uncapped_pool
+                .checked_add(deferred_in)
+                .ok_or(ErrorCode::MathOverflow)?;
+            let (investor_pool, cap_overflow) =
+                math::apply_daily_cap(available, policy.daily_cap_lamports);
+            
+            msg!("Investor pool: {}, cap overflow: {}", investor_pool, cap_overflow);
+            
+            progress.day_investor_pool_quote = investor_pool;
+            progress.day_deferred_in = deferred_in;
+            progress.day_retained_quote = match policy.cap_overflow_mode {
+                CapOverflowMode::Creator => 0,
+                CapOverflowMode::Deferred | CapOverflowMode::Treasury => cap_overflow,
+            };
+            if policy.cap_overflow_mode == CapOverflowMode::Deferred {
+                progress.deferred_investor_balance = cap_overflow;
+            }
+            
+            if cap_overflow > 0 {
+                emit!(CapOverflow {
                     vault: vault_key,
-                    investor: investor_ata_info.key(),
-                    amount: investor_share,
-                    locked_amount,
-                    page: progress.pagination_cursor,
+                    day_ts: progress.current_day_ts,
+                    uncapped_pool: available,
+                    investor_pool,
+                    overflow: cap_overflow,
+                    mode: policy.cap_overflow_mode,
+                    deferred_investor_balance: progress.deferred_investor_balance,
                 });
-            } else {
-                // Below threshold - accumulate as dust
-                dust_accumulated = dust_accumulated
-                    .checked_add(investor_share)
-                    .ok_or(ErrorCode::MathOverflow)?;
             }
+            progress.locked_snapshot_complete = true;
+            
+            emit!(LockedTotalSnapshotted {
+                vault: vault_key,
+                locked_total: progress.day_locked_total,
+                f_locked_bps,
+                investor_pool,
+                pages: progress.pagination_cursor + 1,
+            });
+            
+            // Payout pass starts again from the first page
+            progress.pagination_cursor = 0;
+        } else {
+            progress.pagination_cursor += 1;
         }
+        
+        emit!(QuoteFeesClaimed {
+            vault: vault_key,
+            amount: claimed_quote_amount,
+            total_distributed: 0,
+            crank_reward,
+            page: progress.pagination_cursor,
+            timestamp: now,
+        });
+        
+        return Ok(());
     }
     
-    msg!("Total distributed: {}, dust: {}", total_distributed, dust_accumulated);
-    
-    // Update progress
-    progress.daily_distributed = progress.daily_distributed
-        .checked_add(total_distributed)
-        .ok_or(ErrorCode::MathOverflow)?;
+    // 6. Payout pass - distribute the snapshotted pool pro-rata against the global total
+    let mut total_distributed = 0u64;
+    let mut dust_accumulated = progress.carry_over_dust;
     
-    // 5. Check if this is the last page
-    if params.is_last_page {
-        // Calculate creator portion (remainder)
-        let creator_amount = total_available
-            .checked_sub(total_distributed)
-            .ok_or(ErrorCode::MathOverflow)?;
+    for i in 0..investor_count {
+        let investor_ata_info = &page_accounts[i * 2 + 1];
+        let locked_amount = locked_amounts[i];
+        
+        if locked_amount == 0 {
+            continue;
+        }
+        
+        // Locked amounts only shrink after the snapshot, but never pay past the pool
+        let remaining_pool = progress.day_investor_pool_quote
+            .saturating_sub(progress.daily_distributed)
+            .saturating_sub(total_distributed);
+        let investor_share = math::pro_rata_share(
+            progress.day_investor_pool_quote,
+            locked_amount,
+            progress.day_locked_total,
+        )?
+        .min(remaining_pool);
         
-        msg!("Creator amount: {}", creator_amount);
+        msg!("Investor {} share: {}", i, investor_share);
         
-        // Transfer to creator (includes dust)
-        if creator_amount > 0 {
-            let cpi_accounts = Transfer {
+        // Apply minimum payout threshold to what the investor would actually receive
+        let net_share = transfer_fee::net_of_transfer_fee(
+            &ctx.accounts.quote_mint.to_account_info(),
+            investor_share,
+        )?;
+        
+        if net_share >= policy.min_payout_lamports && net_share > 0 {
+            // Transfer to investor
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.program_quote_treasury.to_account_info(),
-                to: ctx.accounts.creator_quote_ata.to_account_info(),
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                to: investor_ata_info.to_account_info(),
                 authority: ctx.accounts.treasury_authority.to_account_info(),
             };
             
             let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.quote_token_program.to_account_info(),
                 cpi_accounts,
                 signer_seeds,
             );
             
-            token::transfer(cpi_ctx, creator_amount)?;
+            token_interface::transfer_checked(cpi_ctx, investor_share, ctx.accounts.quote_mint.decimals)?;
+            
+            // Accounting tracks the gross amount debited from the treasury
+            total_distributed = total_distributed
+                .checked_add(investor_share)
+                .ok_or(ErrorCode::MathOverflow)?;
             
-            emit!(CreatorPayoutDayClosed {
+            emit!(InvestorPayoutPage {
                 vault: vault_key,
-                creator: policy.creator_wallet,
-                amount: creator_amount,
-                day_ts: progress.current_day_ts,
-                total_distributed_to_investors: total_distributed,
+                investor: investor_ata_info.key(),
+                amount: investor_share,
+                net_amount: net_share,
+                locked_amount,
+                page: progress.pagination_cursor,
             });
+        } else {
+            // Below threshold - accumulate as dust (stays in treasury for the creator)
+            dust_accumulated = dust_accumulated
+                .checked_add(investor_share)
+                .ok_or(ErrorCode::MathOverflow)?;
         }
-        
-        // Finalize day
-        progress.day_complete = true;
-        progress.last_distribution_ts = now;
-        progress.pagination_cursor = 0;
-        progress.carry_over_dust = 0; // Reset dust after giving to creator
-        progress.total_claimed_lifetime = progress.total_claimed_lifetime
-            .checked_add(claimed_quote_amount)
-            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    
+    msg!("Total distributed: {}, dust: {}", total_distributed, dust_accumulated);
+    
+    // Update progress
+    progress.daily_distributed = progress.daily_distributed
+        .checked_add(total_distributed)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    // 7. Check if this is the last page
+    if is_last_page {
+        close_day(
+            progress,
+            policy,
+            vault_key,
+            CreatorPayout {
+                program_quote_treasury: ctx.accounts.program_quote_treasury.to_account_info(),
+                quote_mint: &ctx.accounts.quote_mint,
+                creator_quote_ata: ctx.accounts.creator_quote_ata.to_account_info(),
+                treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+                quote_token_program: ctx.accounts.quote_token_program.to_account_info(),
+                creator_split: &ctx.accounts.creator_split,
+                split_recipient_atas: &page_accounts[investor_count * 2..],
+            },
+            signer_seeds,
+            now,
+        )?;
     } else {
         // Not last page - increment cursor and carry dust
         progress.pagination_cursor += 1;
@@ -374,6 +596,7 @@ pub fn handler<'info>(
         vault: vault_key,
         amount: claimed_quote_amount,
         total_distributed,
+        crank_reward,
         page: progress.pagination_cursor,
         timestamp: now,
     });
@@ -381,26 +604,375 @@ pub fn handler<'info>(
     Ok(())
 }
 
-/// Parse Streamflow locked amount from stream account
-/// This is a simplified mock - real implementation would deserialize Streamflow account
-fn parse_streamflow_locked_amount(
-    stream_account: &AccountInfo,
-    _current_time: i64,
+/// Accounts used to pay the creator remainder when a day closes
+pub(crate) struct CreatorPayout<'a, 'info> {
+    pub program_quote_treasury: AccountInfo<'info>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub creator_quote_ata: AccountInfo<'info>,
+    pub treasury_authority: AccountInfo<'info>,
+    pub quote_token_program: AccountInfo<'info>,
+    pub creator_split: &'a CreatorSplit,
+    /// Quote ATAs of the creator split recipients, in split order
+    pub split_recipient_atas: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> CreatorPayout<'a, 'info> {
+    /// Transfer `amount` from the treasury, returning what the recipient receives
+    fn pay(&self, to: AccountInfo<'info>, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<u64> {
+        let cpi_accounts = TransferChecked {
+            from: self.program_quote_treasury.clone(),
+            mint: self.quote_mint.to_account_info(),
+            to,
+            authority: self.treasury_authority.clone(),
+        };
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.quote_token_program.clone(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        
+        token_interface::transfer_checked(cpi_ctx, amount, self.quote_mint.decimals)?;
+        
+        transfer_fee::net_of_transfer_fee(&self.quote_mint.to_account_info(), amount)
+    }
+    
+    /// Whether a split recipient ATA can still receive the quote mint
+    ///
+    /// A closed, frozen or re-created account with another mint would fail the
+    /// transfer and leave the day (and the vault) unable to close.
+    fn can_receive(&self, recipient_ata: &AccountInfo) -> bool {
+        if recipient_ata.owner != self.quote_token_program.key {
+            return false;
+        }
+        
+        let Ok(data) = recipient_ata.try_borrow_data() else {
+            return false;
+        };
+        
+        match TokenAccount::try_deserialize(&mut &data[..]) {
+            Ok(token_account) => token_account.mint == self.quote_mint.key() && !token_account.is_frozen(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Pay the creator remainder and finalize the day
+///
+/// Creator receives everything available today that investors and crankers did not
+/// (includes dust), except cap overflow retained for later days or left in the treasury.
+/// With a creator split set, the remainder is fanned out to its recipients by weight;
+/// shares of recipients whose ATA can no longer receive go to the creator ATA instead.
+pub(crate) fn close_day(
+    progress: &mut ProgressState,
+    policy: &PolicyState,
+    vault_key: Pubkey,
+    accounts: CreatorPayout,
+    signer_seeds: &[&[&[u8]]],
+    now: i64,
 ) -> Result<u64> {
-    // In a real implementation, this would:
-    // 1. Deserialize the Streamflow Stream account
-    // 2. Calculate: locked = total - vested - withdrawn
-    // 3. Handle cliff and vesting schedule
+    let unpaid_crank_reward = progress.day_crank_reward_budget
+        .checked_sub(progress.day_crank_reward_paid)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let creator_amount = progress.day_claimed_quote
+        .checked_add(progress.day_deferred_in)
+        .and_then(|available| available.checked_add(unpaid_crank_reward))
+        .and_then(|available| available.checked_sub(progress.daily_distributed))
+        .and_then(|remaining| remaining.checked_sub(progress.day_retained_quote))
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Creator amount: {}", creator_amount);
+    
+    let recipients = &accounts.creator_split.recipients;
+    require!(
+        accounts.split_recipient_atas.len() == recipients.len(),
+        ErrorCode::CreatorSplitAccountsMismatch
+    );
+    
+    if creator_amount > 0 {
+        let net_amount = if recipients.is_empty() {
+            accounts.pay(accounts.creator_quote_ata.clone(), creator_amount, signer_seeds)?
+        } else {
+            let shares = accounts.creator_split.shares(creator_amount)?;
+            let mut net_total = 0u64;
+            let mut redirected = 0u64;
+            
+            for ((recipient, recipient_ata), share) in recipients
+                .iter()
+                .zip(accounts.split_recipient_atas.iter())
+                .zip(shares)
+            {
+                require_keys_eq!(
+                    recipient_ata.key(),
+                    recipient.quote_ata,
+                    ErrorCode::CreatorSplitAccountsMismatch
+                );
+                
+                if share == 0 {
+                    continue;
+                }
+                
+                if !accounts.can_receive(recipient_ata) {
+                    redirected = redirected.checked_add(share).ok_or(ErrorCode::MathOverflow)?;
+                    
+                    emit!(CreatorSplitRedirected {
+                        vault: vault_key,
+                        recipient: recipient.quote_ata,
+                        amount: share,
+                        day_ts: progress.current_day_ts,
+                    });
+                    continue;
+                }
+                
+                let net_share = accounts.pay(recipient_ata.clone(), share, signer_seeds)?;
+                net_total = net_total.checked_add(net_share).ok_or(ErrorCode::MathOverflow)?;
+                
+                emit!(CreatorSplitPayout {
+                    vault: vault_key,
+                    recipient: recipient.quote_ata,
+                    weight_bps: recipient.weight_bps,
+                    amount: share,
+                    net_amount: net_share,
+                    day_ts: progress.current_day_ts,
+                });
+            }
+            
+            if redirected > 0 {
+                let net_redirected = accounts.pay(accounts.creator_quote_ata.clone(), redirected, signer_seeds)?;
+                net_total = net_total.checked_add(net_redirected).ok_or(ErrorCode::MathOverflow)?;
+            }
+            
+            net_total
+        };
+        
+        emit!(CreatorPayoutDayClosed {
+            vault: vault_key,
+            creator: policy.creator_wallet,
+            amount: creator_amount,
+            net_amount,
+            day_ts: progress.current_day_ts,
+            total_distributed_to_investors: progress.daily_distributed,
+        });
+    }
+    
+    // Finalize day
+    progress.day_complete = true;
+    progress.last_distribution_ts = now;
+    progress.pagination_cursor = 0;
+    progress.carry_over_dust = 0; // Reset dust after giving to creator
     
-    // For this framework, we'll return a mock value based on account data length
-    // In production, replace with actual Streamflow parsing
+    Ok(creator_amount)
+}
+
+/// Deserialize a registered position's state passed in remaining accounts
+fn load_position_state(info: &AccountInfo, vault: &Pubkey, pool: &Pubkey) -> Result<PositionState> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::PositionAccountsMismatch);
+    
+    let data = info.try_borrow_data()?;
+    let position_state = PositionState::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(ErrorCode::PositionAccountsMismatch))?;
+    
+    require!(
+        position_state.vault == *vault && position_state.pool_id == *pool,
+        ErrorCode::PositionAccountsMismatch
+    );
     
-    if stream_account.data_len() == 0 {
-        return Ok(0);
+    Ok(position_state)
+}
+
+/// A registered position and its crank accounts (header followed by AMM accounts)
+type PositionGroup<'c, 'info> = (PositionState, &'c [AccountInfo<'info>]);
+
+/// Split the day's position groups off the front of the remaining accounts
+///
+/// Each group is sized by its position's AMM adapter, so the position state
+/// leading the group is loaded to find where the next one starts.
+fn split_position_groups<'c, 'info>(
+    accounts: &'c [AccountInfo<'info>],
+    vault: &Pubkey,
+    pools: &[Pubkey],
+) -> Result<(Vec<PositionGroup<'c, 'info>>, &'c [AccountInfo<'info>])> {
+    let mut groups = Vec::with_capacity(pools.len());
+    let mut rest = accounts;
+    
+    for pool in pools {
+        require!(!rest.is_empty(), ErrorCode::PositionAccountsMismatch);
+        let position_state = load_position_state(&rest[0], vault, pool)?;
+        
+        let group_len = POSITION_GROUP_HEADER_ACCOUNTS + amm::position_account_count(position_state.amm_kind);
+        require!(rest.len() >= group_len, ErrorCode::PositionAccountsMismatch);
+        
+        let (group, tail) = rest.split_at(group_len);
+        groups.push((position_state, group));
+        rest = tail;
     }
     
-    // Mock: return a value based on lamports (for testing)
-    // Real implementation: parse Streamflow data structure
-    let locked = stream_account.lamports() % 1000000;
-    Ok(locked)
+    Ok((groups, rest))
+}
+
+/// Current balance of a token account passed as a raw account
+fn token_account_amount(info: &AccountInfo) -> Result<u64> {
+    let data = info.try_borrow_data()?;
+    let token_account = TokenAccount::try_deserialize(&mut &data[..])?;
+    Ok(token_account.amount)
+}
+
+/// Validate an investor ATA is a quote token account owned by the stream beneficiary
+fn validate_investor_ata(
+    investor_ata_info: &AccountInfo,
+    beneficiary: &Pubkey,
+    quote_mint: &Pubkey,
+) -> Result<()> {
+    require!(
+        *investor_ata_info.owner == token::ID || *investor_ata_info.owner == token_2022::ID,
+        ErrorCode::InvalidInvestorAta
+    );
+    
+    let data = investor_ata_info.try_borrow_data()?;
+    let investor_ata = TokenAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(ErrorCode::InvalidInvestorAta))?;
+    
+    require_keys_eq!(investor_ata.mint, *quote_mint, ErrorCode::InvalidInvestorAta);
+    require_keys_eq!(investor_ata.owner, *beneficiary, ErrorCode::InvalidInvestorAta);
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AmmKind;
+    
+    /// Backing storage for an `AccountInfo` in the remaining accounts
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+    
+    impl TestAccount {
+        fn unchecked() -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                owner: Pubkey::new_unique(),
+                lamports: 0,
+                data: Vec::new(),
+            }
+        }
+        
+        fn position_state(vault: &Pubkey, pool: &Pubkey, amm_kind: AmmKind) -> Self {
+            let position_state = PositionState {
+                vault: *vault,
+                pool_id: *pool,
+                amm_kind,
+                ..Default::default()
+            };
+            let mut data = Vec::new();
+            position_state.try_serialize(&mut data).unwrap();
+            
+            Self {
+                owner: crate::ID,
+                data,
+                ..Self::unchecked()
+            }
+        }
+        
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(&self.key, false, true, &mut self.lamports, &mut self.data, &self.owner, false, 0)
+        }
+    }
+    
+    /// Header and venue accounts for one pool, led by its position state
+    fn position_group(vault: &Pubkey, pool: &Pubkey, amm_kind: AmmKind) -> Vec<TestAccount> {
+        let group_len = POSITION_GROUP_HEADER_ACCOUNTS + amm::position_account_count(amm_kind);
+        
+        std::iter::once(TestAccount::position_state(vault, pool, amm_kind))
+            .chain((1..group_len).map(|_| TestAccount::unchecked()))
+            .collect()
+    }
+    
+    fn page_accounts(count: usize) -> Vec<TestAccount> {
+        (0..count).map(|_| TestAccount::unchecked()).collect()
+    }
+    
+    fn split(accounts: &mut [TestAccount], vault: &Pubkey, pools: &[Pubkey]) -> Result<(Vec<usize>, usize)> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let (groups, rest) = split_position_groups(&infos, vault, pools)?;
+        
+        for ((position_state, group), pool) in groups.iter().zip(pools) {
+            assert_eq!(position_state.pool_id, *pool);
+            assert_eq!(*group[0].owner, crate::ID);
+        }
+        
+        Ok((groups.iter().map(|(_, group)| group.len()).collect(), rest.len()))
+    }
+    
+    #[test]
+    fn test_split_position_groups() {
+        let vault = Pubkey::new_unique();
+        let pools = [Pubkey::new_unique(), Pubkey::new_unique()];
+        
+        // Groups are sized by each position's AMM, in policy.position_pools order,
+        // ahead of the page's investor accounts
+        let mut accounts: Vec<TestAccount> = position_group(&vault, &pools[0], AmmKind::RaydiumClmm)
+            .into_iter()
+            .chain(position_group(&vault, &pools[1], AmmKind::MeteoraDammV2))
+            .chain(page_accounts(6))
+            .collect();
+        
+        let (group_lens, page_len) = split(&mut accounts, &vault, &pools).unwrap();
+        assert_eq!(group_lens, vec![12, 11]);
+        assert_eq!(page_len, 6);
+        
+        // No registered pools: everything belongs to the page
+        let (group_lens, page_len) = split(&mut accounts, &vault, &[]).unwrap();
+        assert!(group_lens.is_empty());
+        assert_eq!(page_len, accounts.len());
+    }
+    
+    #[test]
+    fn test_split_position_groups_rejects_mismatched_position() {
+        let vault = Pubkey::new_unique();
+        let pools = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mismatch = Err(error!(ErrorCode::PositionAccountsMismatch));
+        
+        let mut accounts: Vec<TestAccount> = position_group(&vault, &pools[0], AmmKind::RaydiumClmm)
+            .into_iter()
+            .chain(position_group(&vault, &pools[1], AmmKind::MeteoraDammV2))
+            .collect();
+        
+        // Position states of another vault
+        assert_eq!(split(&mut accounts, &Pubkey::new_unique(), &pools), mismatch);
+        
+        // Groups out of policy.position_pools order
+        assert_eq!(split(&mut accounts, &vault, &[pools[1], pools[0]]), mismatch);
+        
+        // Position state not owned by the program
+        accounts[0].owner = Pubkey::new_unique();
+        assert_eq!(split(&mut accounts, &vault, &pools), mismatch);
+    }
+    
+    #[test]
+    fn test_split_position_groups_rejects_missing_or_short_group() {
+        let vault = Pubkey::new_unique();
+        let pools = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mismatch = Err(error!(ErrorCode::PositionAccountsMismatch));
+        
+        // Second pool's group missing entirely
+        let mut accounts = position_group(&vault, &pools[0], AmmKind::RaydiumClmm);
+        assert_eq!(split(&mut accounts, &vault, &pools), mismatch);
+        
+        // ...or replaced by the page's investor accounts
+        accounts.extend(page_accounts(6));
+        assert_eq!(split(&mut accounts, &vault, &pools), mismatch);
+        
+        // Second group one venue account short
+        let mut accounts: Vec<TestAccount> = position_group(&vault, &pools[0], AmmKind::RaydiumClmm)
+            .into_iter()
+            .chain(position_group(&vault, &pools[1], AmmKind::MeteoraDammV2))
+            .collect();
+        accounts.pop();
+        assert_eq!(split(&mut accounts, &vault, &pools), mismatch);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::DayForceClosed;
+use crate::instructions::crank_distribution::{close_day, CreatorPayout};
+use crate::state::{CapOverflowMode, CreatorSplit, InvestorRegistry, PolicyState, ProgressState};
+
+#[derive(Accounts)]
+pub struct ForceCloseDay<'info> {
+    /// Caller (can be anyone)
+    pub caller: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = !policy.paused @ ErrorCode::DistributionsPaused,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - must hold a day that outlived max_day_duration_secs
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = !progress.day_complete @ ErrorCode::NoDayInProgress,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// Investor registry (page count for the event)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            REGISTRY_SEED,
+        ],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, InvestorRegistry>,
+    
+    /// Creator split (recipients of the creator remainder)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CREATOR_SPLIT_SEED,
+        ],
+        bump = creator_split.bump,
+    )]
+    pub creator_split: Account<'info, CreatorSplit>,
+    
+    /// Program quote treasury (holds collected fees)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+        ],
+        bump,
+        constraint = program_quote_treasury.mint == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub program_quote_treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Treasury authority PDA
+    /// CHECK: PDA used as authority for treasury
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+            b"authority",
+        ],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+    
+    /// Creator's quote token ATA (receives remainder)
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ ErrorCode::CreatorQuoteAtaMismatch,
+    )]
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Quote token mint
+    #[account(
+        constraint = quote_mint.key() == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Token program owning the quote mint
+    #[account(
+        constraint = quote_token_program.key() == *quote_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    
+    // Remaining accounts:
+    // Each creator split recipient's quote ATA, in order (only when the snapshot was complete)
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ForceCloseDay<'info>>) -> Result<()> {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let vault_key = ctx.accounts.vault.key();
+    let policy = &ctx.accounts.policy;
+    let progress = &mut ctx.accounts.progress;
+    
+    require!(policy.is_day_stale(progress, now), ErrorCode::DayNotStale);
+    
+    let day_ts = progress.current_day_ts;
+    let snapshot_complete = progress.locked_snapshot_complete;
+    
+    // Pages fully processed in this run (a payout page follows a full snapshot pass)
+    let pages_completed = if snapshot_complete {
+        ctx.accounts.registry.page_count() + progress.pagination_cursor
+    } else {
+        progress.pagination_cursor
+    };
+    
+    let mut unpaid_investor_amount = 0;
+    let mut rolled_over_quote = 0;
+    
+    let creator_amount = if snapshot_complete {
+        // Payout pass abandoned - investors not yet paid are handled like cap overflow
+        unpaid_investor_amount = progress.day_investor_pool_quote
+            .saturating_sub(progress.daily_distributed);
+        
+        match policy.cap_overflow_mode {
+            CapOverflowMode::Creator => {}
+            CapOverflowMode::Deferred => {
+                progress.deferred_investor_balance = progress.deferred_investor_balance
+                    .checked_add(unpaid_investor_amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                progress.day_retained_quote = progress.day_retained_quote
+                    .checked_add(unpaid_investor_amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            CapOverflowMode::Treasury => {
+                progress.day_retained_quote = progress.day_retained_quote
+                    .checked_add(unpaid_investor_amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+        }
+        
+        let treasury_seeds = &[
+            VAULT_SEED,
+            vault_key.as_ref(),
+            TREASURY_SEED,
+            b"authority",
+            &[ctx.bumps.treasury_authority],
+        ];
+        
+        close_day(
+            progress,
+            policy,
+            vault_key,
+            CreatorPayout {
+                program_quote_treasury: ctx.accounts.program_quote_treasury.to_account_info(),
+                quote_mint: &ctx.accounts.quote_mint,
+                creator_quote_ata: ctx.accounts.creator_quote_ata.to_account_info(),
+                treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+                quote_token_program: ctx.accounts.quote_token_program.to_account_info(),
+                creator_split: &ctx.accounts.creator_split,
+                split_recipient_atas: ctx.remaining_accounts,
+            },
+            &[&treasury_seeds[..]],
+            now,
+        )?
+    } else {
+        // Snapshot pass abandoned - the investor pool was never fixed, so the
+        // claim (with any unpaid crank reward) stays in the treasury and joins
+        // the next day's claim
+        rolled_over_quote = progress.day_claimed_quote
+            .checked_add(progress.day_crank_reward_budget)
+            .and_then(|claimed| claimed.checked_sub(progress.day_crank_reward_paid))
+            .ok_or(ErrorCode::MathOverflow)?;
+        progress.rolled_over_claimed_quote = progress.rolled_over_claimed_quote
+            .checked_add(rolled_over_quote)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        progress.day_complete = true;
+        progress.last_distribution_ts = now;
+        progress.pagination_cursor = 0;
+        progress.carry_over_dust = 0;
+        
+        0
+    };
+    
+    emit!(DayForceClosed {
+        vault: vault_key,
+        closed_by: ctx.accounts.caller.key(),
+        day_ts,
+        pages_completed,
+        snapshot_complete,
+        unpaid_investor_amount,
+        rolled_over_quote,
+        creator_amount,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
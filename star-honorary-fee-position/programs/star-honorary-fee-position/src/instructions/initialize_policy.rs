@@ -1,16 +1,26 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::PolicyInitialized;
-use crate::state::{PolicyState, ProgressState};
+use crate::state::{CapOverflowMode, CreatorSplit, InvestorRegistry, PolicyState, ProgressState};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitializePolicyParams {
     pub investor_fee_share_bps: u16,
     pub daily_cap_lamports: Option<u64>,
     pub min_payout_lamports: u64,
+    pub cap_overflow_mode: CapOverflowMode,
+    pub crank_reward_bps: u16,
+    pub crank_reward_cap_lamports: Option<u64>,
     pub y0_total_allocation: u64,
+    pub distribution_interval_secs: i64,
+    pub align_to_epoch: bool,
+    pub max_day_duration_secs: i64,
+    pub investors_per_page: u8,
+    pub policy_change_delay_days: u16,
+    pub guardian: Option<Pubkey>,
+    pub allowed_stream_sender: Pubkey,
 }
 
 #[derive(Accounts)]
@@ -22,15 +32,21 @@ pub struct InitializePolicy<'info> {
     /// CHECK: Used as seed for PDAs
     pub vault: UncheckedAccount<'info>,
     
-    /// Quote token mint
-    pub quote_mint: Account<'info, Mint>,
+    /// Quote token mint (SPL Token or Token-2022)
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Mint of the investors' locked tokens (base token)
+    #[account(
+        constraint = vesting_mint.key() != quote_mint.key() @ ErrorCode::InvalidMintAccount,
+    )]
+    pub vesting_mint: InterfaceAccount<'info, Mint>,
     
     /// Creator's quote token ATA
     #[account(
         constraint = creator_quote_ata.owner == creator.key() @ ErrorCode::InvalidPageParameters,
         constraint = creator_quote_ata.mint == quote_mint.key() @ ErrorCode::QuoteMintNotInPool,
     )]
-    pub creator_quote_ata: Account<'info, TokenAccount>,
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
     
     /// Policy state PDA
     #[account(
@@ -60,6 +76,34 @@ pub struct InitializePolicy<'info> {
     )]
     pub progress: Account<'info, ProgressState>,
     
+    /// Investor registry PDA (ordered investors processed by the crank)
+    #[account(
+        init,
+        payer = creator,
+        space = InvestorRegistry::LEN,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            REGISTRY_SEED,
+        ],
+        bump
+    )]
+    pub registry: Account<'info, InvestorRegistry>,
+    
+    /// Creator split PDA (empty - remainder goes to the creator ATA until set)
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorSplit::LEN,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CREATOR_SPLIT_SEED,
+        ],
+        bump
+    )]
+    pub creator_split: Account<'info, CreatorSplit>,
+    
     /// Program quote treasury PDA (owned by program, holds collected fees)
     #[account(
         init,
@@ -72,8 +116,9 @@ pub struct InitializePolicy<'info> {
         bump,
         token::mint = quote_mint,
         token::authority = treasury_authority,
+        token::token_program = token_program,
     )]
-    pub program_quote_treasury: Account<'info, TokenAccount>,
+    pub program_quote_treasury: InterfaceAccount<'info, TokenAccount>,
     
     /// Treasury authority PDA
     /// CHECK: PDA used as authority for treasury
@@ -88,7 +133,8 @@ pub struct InitializePolicy<'info> {
     )]
     pub treasury_authority: UncheckedAccount<'info>,
     
-    pub token_program: Program<'info, Token>,
+    /// Token program owning the quote mint
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -100,21 +146,59 @@ pub fn handler(ctx: Context<InitializePolicy>, params: InitializePolicyParams) -
         ErrorCode::InvalidFeeShareBps
     );
     
+    require!(
+        params.crank_reward_bps <= MAX_CRANK_REWARD_BPS,
+        ErrorCode::InvalidCrankReward
+    );
+    
     require!(
         params.y0_total_allocation > 0,
         ErrorCode::InvalidY0Allocation
     );
     
+    require!(
+        params.distribution_interval_secs >= MIN_DISTRIBUTION_INTERVAL_SECS
+            && params.distribution_interval_secs <= MAX_DISTRIBUTION_INTERVAL_SECS,
+        ErrorCode::InvalidDistributionInterval
+    );
+    
+    require!(
+        params.max_day_duration_secs >= MIN_DAY_DURATION_SECS,
+        ErrorCode::InvalidMaxDayDuration
+    );
+    
+    require!(
+        params.investors_per_page > 0
+            && params.investors_per_page as usize <= MAX_INVESTORS_PER_PAGE,
+        ErrorCode::InvalidPageParameters
+    );
+    
     // Initialize policy state
     let policy = &mut ctx.accounts.policy;
     policy.vault = ctx.accounts.vault.key();
     policy.creator_wallet = ctx.accounts.creator.key();
     policy.creator_quote_ata = ctx.accounts.creator_quote_ata.key();
     policy.quote_mint = ctx.accounts.quote_mint.key();
+    policy.vesting_mint = ctx.accounts.vesting_mint.key();
+    policy.allowed_stream_sender = params.allowed_stream_sender;
     policy.investor_fee_share_bps = params.investor_fee_share_bps;
     policy.daily_cap_lamports = params.daily_cap_lamports;
     policy.min_payout_lamports = params.min_payout_lamports;
+    policy.cap_overflow_mode = params.cap_overflow_mode;
+    policy.crank_reward_bps = params.crank_reward_bps;
+    policy.crank_reward_cap_lamports = params.crank_reward_cap_lamports;
     policy.y0_total_allocation = params.y0_total_allocation;
+    policy.distribution_interval_secs = params.distribution_interval_secs;
+    policy.align_to_epoch = params.align_to_epoch;
+    policy.max_day_duration_secs = params.max_day_duration_secs;
+    policy.policy_change_delay_days = params.policy_change_delay_days;
+    policy.pending_change = None;
+    policy.pending_creator = None;
+    policy.pending_creator_quote_ata = None;
+    policy.guardian = params.guardian;
+    policy.paused = false;
+    policy.paused_at = 0;
+    policy.position_pools = Vec::new();
     policy.bump = ctx.bumps.policy;
     
     // Initialize progress state
@@ -122,20 +206,54 @@ pub fn handler(ctx: Context<InitializePolicy>, params: InitializePolicyParams) -
     progress.vault = ctx.accounts.vault.key();
     progress.last_distribution_ts = 0; // No distributions yet
     progress.current_day_ts = 0;
+    progress.day_paused_secs = 0;
     progress.daily_distributed = 0;
+    progress.day_claimed_quote = 0;
+    progress.day_investor_pool_quote = 0;
+    progress.day_locked_total = 0;
+    progress.locked_snapshot_complete = false;
     progress.carry_over_dust = 0;
     progress.pagination_cursor = 0;
     progress.day_complete = true; // Ready for first distribution
     progress.total_claimed_lifetime = 0;
+    progress.deferred_investor_balance = 0;
+    progress.day_deferred_in = 0;
+    progress.day_retained_quote = 0;
+    progress.rolled_over_claimed_quote = 0;
+    progress.day_crank_reward_budget = 0;
+    progress.day_crank_reward_paid = 0;
     progress.bump = ctx.bumps.progress;
     
+    // Initialize investor registry
+    let registry = &mut ctx.accounts.registry;
+    registry.vault = ctx.accounts.vault.key();
+    registry.page_size = params.investors_per_page;
+    registry.investors = Vec::new();
+    registry.bump = ctx.bumps.registry;
+    
+    // Initialize creator split (no recipients)
+    let creator_split = &mut ctx.accounts.creator_split;
+    creator_split.vault = ctx.accounts.vault.key();
+    creator_split.recipients = Vec::new();
+    creator_split.bump = ctx.bumps.creator_split;
+    
     emit!(PolicyInitialized {
         vault: ctx.accounts.vault.key(),
         creator_wallet: policy.creator_wallet,
         quote_mint: policy.quote_mint,
+        vesting_mint: policy.vesting_mint,
+        allowed_stream_sender: policy.allowed_stream_sender,
         investor_fee_share_bps: params.investor_fee_share_bps,
         daily_cap_lamports: params.daily_cap_lamports,
+        cap_overflow_mode: params.cap_overflow_mode,
+        crank_reward_bps: params.crank_reward_bps,
+        crank_reward_cap_lamports: params.crank_reward_cap_lamports,
         y0_total_allocation: params.y0_total_allocation,
+        distribution_interval_secs: params.distribution_interval_secs,
+        align_to_epoch: params.align_to_epoch,
+        max_day_duration_secs: params.max_day_duration_secs,
+        policy_change_delay_days: params.policy_change_delay_days,
+        guardian: params.guardian,
     });
     
     Ok(())
@@ -1,20 +1,39 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::amm::{self, AmmAccounts, OpenAccounts};
 use crate::constants::*;
+use crate::cp_amm;
 use crate::errors::ErrorCode;
 use crate::events::HonoraryPositionInitialized;
-use crate::state::{PolicyState, PositionState};
+use crate::state::{AmmKind, PolicyState, PositionState};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitializePositionParams {
+    /// AMM the pool belongs to
+    pub amm_kind: AmmKind,
+    
+    /// Tick range and tick array starts (ignored on DAMM v2, which has no ticks)
     pub tick_lower: i32,
     pub tick_upper: i32,
     pub tick_array_lower_start_index: i32,
     pub tick_array_upper_start_index: i32,
+    
+    /// Liquidity to mint into the honorary position
+    pub liquidity: u128,
+    
+    /// Maximum token0 (token A) the payer is willing to deposit
+    pub amount_0_max: u64,
+    
+    /// Maximum token1 (token B) the payer is willing to deposit
+    pub amount_1_max: u64,
 }
 
 #[derive(Accounts)]
+#[instruction(params: InitializePositionParams)]
 pub struct InitializePosition<'info> {
+    /// Policy creator (funds the position and its accounts)
     #[account(mut)]
     pub payer: Signer<'info>,
     
@@ -22,19 +41,21 @@ pub struct InitializePosition<'info> {
     /// CHECK: Used as seed for PDAs
     pub vault: UncheckedAccount<'info>,
     
-    /// Policy state - must be initialized first
+    /// Policy state - must be initialized first (records the registered pool)
     #[account(
+        mut,
         seeds = [
             VAULT_SEED,
             vault.key().as_ref(),
             POLICY_SEED,
         ],
         bump = policy.bump,
-        constraint = policy.vault == vault.key() @ ErrorCode::InvalidPageParameters,
+        constraint = policy.vault == vault.key() @ ErrorCode::PolicyVaultMismatch,
+        constraint = policy.creator_wallet == payer.key() @ ErrorCode::UnauthorizedCreator,
     )]
     pub policy: Account<'info, PolicyState>,
     
-    /// Position state PDA
+    /// Position state PDA (one per pool)
     #[account(
         init,
         payer = payer,
@@ -43,6 +64,7 @@ pub struct InitializePosition<'info> {
             VAULT_SEED,
             vault.key().as_ref(),
             POSITION_STATE_SEED,
+            pool.key().as_ref(),
         ],
         bump
     )]
@@ -60,42 +82,95 @@ pub struct InitializePosition<'info> {
     )]
     pub position_owner_pda: UncheckedAccount<'info>,
     
-    /// CP-AMM pool state account
-    /// CHECK: Validated by CP-AMM program
+    /// AMM pool account
+    /// CHECK: Owner and layout checked by the AMM adapter
     #[account(mut)]
-    pub pool_state: UncheckedAccount<'info>,
+    pub pool: UncheckedAccount<'info>,
     
     /// Quote token mint (must match policy)
-    pub quote_mint: Account<'info, Mint>,
+    pub quote_mint: InterfaceAccount<'info, Mint>,
     
-    /// Token 0 mint of the pool
-    /// CHECK: Validated against pool
-    pub token_0_mint: UncheckedAccount<'info>,
+    /// Base token mint (the pool mint that is not the quote mint)
+    pub base_mint: InterfaceAccount<'info, Mint>,
     
-    /// Token 1 mint of the pool
-    /// CHECK: Validated against pool
-    pub token_1_mint: UncheckedAccount<'info>,
+    /// Program base treasury PDA for this pool - receives any base fees so the crank can detect them
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            BASE_TREASURY_SEED,
+            pool.key().as_ref(),
+        ],
+        bump,
+        token::mint = base_mint,
+        token::authority = treasury_authority,
+        token::token_program = base_token_program,
+    )]
+    pub program_base_treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Treasury authority PDA
+    /// CHECK: PDA used as authority for treasury
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+            b"authority",
+        ],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
     
     /// Position NFT mint (to be created)
-    /// CHECK: Will be created by CP-AMM
+    /// CHECK: Will be created by the AMM
     #[account(mut)]
     pub position_nft_mint: Signer<'info>,
     
-    /// Position NFT account (to be created)
-    /// CHECK: Will be created by CP-AMM
-    #[account(mut)]
-    pub position_nft_account: UncheckedAccount<'info>,
+    /// Payer's quote token account funding the deposit
+    #[account(
+        mut,
+        constraint = payer_quote_account.mint == quote_mint.key() @ ErrorCode::PoolMintMismatch,
+    )]
+    pub payer_quote_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// CP-AMM protocol position account
-    /// CHECK: Created by CP-AMM program
-    #[account(mut)]
-    pub protocol_position: UncheckedAccount<'info>,
+    /// Payer's base token account funding the deposit
+    #[account(
+        mut,
+        constraint = payer_base_account.mint == base_mint.key() @ ErrorCode::PoolMintMismatch,
+    )]
+    pub payer_base_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// CP-AMM Program
-    /// CHECK: CP-AMM program ID
-    pub cp_amm_program: UncheckedAccount<'info>,
+    /// AMM program
+    /// CHECK: Must match the program of `params.amm_kind`
+    #[account(
+        constraint = amm_program.key() == amm::program_id(params.amm_kind) @ ErrorCode::AmmKindMismatch,
+    )]
+    pub amm_program: UncheckedAccount<'info>,
     
     pub token_program: Program<'info, Token>,
+    pub token_program_2022: Program<'info, Token2022>,
+    
+    /// Token program owning the quote mint
+    #[account(
+        constraint = quote_token_program.key() == *quote_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program owning the base mint
+    #[account(
+        constraint = base_token_program.key() == *base_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub base_token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL memo program (required by Token-2022 aware AMM instructions)
+    /// CHECK: Must match the memo program ID
+    #[account(
+        constraint = memo_program.key() == cp_amm::memo_program_id() @ ErrorCode::InvalidCpAmmAccount,
+    )]
+    pub memo_program: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     
@@ -106,16 +181,35 @@ pub struct InitializePosition<'info> {
     /// Metadata program
     /// CHECK: Metaplex metadata program
     pub metadata_program: UncheckedAccount<'info>,
+    
+    // Remaining accounts:
+    // The AMM adapter's venue accounts for opening a position (see amm::open_account_count)
 }
 
-pub fn handler(ctx: Context<InitializePosition>, params: InitializePositionParams) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializePosition<'info>>,
+    params: InitializePositionParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.policy.position_pools.len() < MAX_HONORARY_POSITIONS,
+        ErrorCode::TooManyPositions
+    );
+    
+    let amm_kind = params.amm_kind;
+    require!(
+        ctx.remaining_accounts.len() == amm::open_account_count(amm_kind),
+        ErrorCode::PositionAccountsMismatch
+    );
+    
+    // Read live pool state - mints, vaults and current price
+    let pool_info = ctx.accounts.pool.to_account_info();
+    let pool = amm::read_pool(amm_kind, &pool_info)?;
+    
     // Validate quote mint is in the pool
     let quote_mint_key = ctx.accounts.quote_mint.key();
-    let token_0_key = ctx.accounts.token_0_mint.key();
-    let token_1_key = ctx.accounts.token_1_mint.key();
     
-    let is_quote_token_0 = quote_mint_key == token_0_key;
-    let is_quote_token_1 = quote_mint_key == token_1_key;
+    let is_quote_token_0 = quote_mint_key == pool.token_mint_0;
+    let is_quote_token_1 = quote_mint_key == pool.token_mint_1;
     
     require!(
         is_quote_token_0 || is_quote_token_1,
@@ -127,53 +221,96 @@ pub fn handler(ctx: Context<InitializePosition>, params: InitializePositionParam
         ErrorCode::QuoteMintNotInPool
     );
     
-    // Validate ticks
+    let base_mint_key = if is_quote_token_0 { pool.token_mint_1 } else { pool.token_mint_0 };
     require!(
-        params.tick_lower < params.tick_upper,
-        ErrorCode::InvalidTickRangeForQuoteOnly
+        ctx.accounts.base_mint.key() == base_mint_key,
+        ErrorCode::PoolMintMismatch
     );
     
-    // For a real implementation with CP-AMM, we would:
-    // 1. Read the current tick from pool_state
-    // 2. Validate tick range based on is_quote_token_0:
-    //    - If quote is token0: tick_lower > current_tick (position above price)
-    //    - If quote is token1: tick_upper < current_tick (position below price)
-    // 3. Call CP-AMM's open_position instruction with CPI
-    //
-    // Since we're creating a framework, we'll validate the logic but note
-    // that actual CP-AMM integration requires the CP-AMM SDK
-    
-    // CRITICAL VALIDATION: Quote-only position check
-    // This would need the actual pool data to validate properly
-    // For now, we validate that the tick range is provided correctly
-    
-    msg!("Initializing honorary position with ticks: {} to {}", params.tick_lower, params.tick_upper);
+    // CRITICAL VALIDATION: the position must only ever accrue quote fees
+    amm::validate_quote_only(amm_kind, &pool_info, &pool, is_quote_token_0, &params)?;
+    
+    msg!("Registering {:?} honorary position, sqrt price: {}", amm_kind, pool.sqrt_price);
     msg!("Quote token is token0: {}", is_quote_token_0);
     
+    // Open the position via the adapter - the NFT is owned by the position owner PDA
+    let vault_key = ctx.accounts.vault.key();
+    let owner_seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        POSITION_OWNER_SEED,
+        &[ctx.bumps.position_owner_pda],
+    ];
+    let signer_seeds = &[&owner_seeds[..]];
+    
+    let opened = amm::open_position(
+        amm_kind,
+        &AmmAccounts {
+            amm_program: &ctx.accounts.amm_program.to_account_info(),
+            pool: &pool_info,
+            position_owner: &ctx.accounts.position_owner_pda.to_account_info(),
+            quote_mint: &ctx.accounts.quote_mint.to_account_info(),
+            base_mint: &ctx.accounts.base_mint.to_account_info(),
+            quote_account: &ctx.accounts.payer_quote_account.to_account_info(),
+            base_account: &ctx.accounts.payer_base_account.to_account_info(),
+            quote_token_program: &ctx.accounts.quote_token_program.to_account_info(),
+            base_token_program: &ctx.accounts.base_token_program.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            token_program_2022: &ctx.accounts.token_program_2022.to_account_info(),
+            memo_program: &ctx.accounts.memo_program.to_account_info(),
+            venue: ctx.remaining_accounts,
+        },
+        &OpenAccounts {
+            payer: &ctx.accounts.payer.to_account_info(),
+            position_nft_mint: &ctx.accounts.position_nft_mint.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            metadata_program: &ctx.accounts.metadata_program.to_account_info(),
+        },
+        &pool,
+        is_quote_token_0,
+        &params,
+        signer_seeds,
+    )?;
+    
+    // Persist the liquidity the AMM actually minted
+    let liquidity = opened.liquidity;
+    require!(liquidity > 0, ErrorCode::ZeroPositionLiquidity);
+    
+    msg!("Position opened with liquidity: {}", liquidity);
+    
     // Save position state
     let position_state = &mut ctx.accounts.position_state;
-    position_state.vault = ctx.accounts.vault.key();
-    position_state.pool_id = ctx.accounts.pool_state.key();
+    position_state.vault = vault_key;
+    position_state.amm_kind = amm_kind;
+    position_state.pool_id = ctx.accounts.pool.key();
     position_state.position_nft_mint = ctx.accounts.position_nft_mint.key();
-    position_state.position_id = ctx.accounts.protocol_position.key();
-    position_state.tick_lower = params.tick_lower;
-    position_state.tick_upper = params.tick_upper;
-    position_state.liquidity = 1u128; // Minimal liquidity for quote-only fees
+    position_state.position_id = opened.position_id;
+    position_state.personal_position = opened.personal_position;
+    position_state.tick_array_lower = opened.tick_array_lower;
+    position_state.tick_array_upper = opened.tick_array_upper;
+    position_state.tick_lower = opened.tick_lower;
+    position_state.tick_upper = opened.tick_upper;
+    position_state.liquidity = liquidity;
+    position_state.base_mint = base_mint_key;
+    position_state.base_treasury = ctx.accounts.program_base_treasury.key();
     position_state.is_quote_token_0 = is_quote_token_0;
     position_state.bump = ctx.bumps.position_state;
     
+    // Register the pool so the crank claims from it on the first page of each day
+    ctx.accounts.policy.position_pools.push(ctx.accounts.pool.key());
+    
     emit!(HonoraryPositionInitialized {
-        vault: ctx.accounts.vault.key(),
-        pool: ctx.accounts.pool_state.key(),
-        position_id: ctx.accounts.protocol_position.key(),
-        tick_lower: params.tick_lower,
-        tick_upper: params.tick_upper,
+        vault: vault_key,
+        amm_kind,
+        pool: ctx.accounts.pool.key(),
+        position_id: opened.position_id,
+        tick_lower: opened.tick_lower,
+        tick_upper: opened.tick_upper,
         is_quote_token_0,
-        liquidity: 1u128,
+        liquidity,
     });
     
-    // NOTE: In a complete implementation with CP-AMM SDK, we would call:
-    // raydium_cp_swap::cpi::open_position(cpi_ctx, tick_lower, tick_upper, liquidity)
-    
     Ok(())
 }
\ No newline at end of file
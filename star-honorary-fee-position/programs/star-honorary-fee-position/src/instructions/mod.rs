@@ -1,7 +1,35 @@
 pub mod initialize_policy;
+pub mod update_policy;
+pub mod propose_policy_change;
+pub mod apply_policy_change;
+pub mod cancel_policy_change;
+pub mod nominate_creator;
+pub mod accept_creator;
+pub mod set_creator_split;
+pub mod pause;
+pub mod unpause;
+pub mod close_vault;
 pub mod initialize_position;
+pub mod remove_position;
 pub mod crank_distribution;
+pub mod force_close_day;
+pub mod add_investor;
+pub mod remove_investor;
 
 pub use initialize_policy::{InitializePolicy, InitializePolicyParams};
+pub use update_policy::{UpdatePolicy, UpdatePolicyParams};
+pub use propose_policy_change::{ProposePolicyChange, ProposePolicyChangeParams};
+pub use apply_policy_change::ApplyPolicyChange;
+pub use cancel_policy_change::CancelPolicyChange;
+pub use nominate_creator::{NominateCreator, NominateCreatorParams};
+pub use accept_creator::AcceptCreator;
+pub use set_creator_split::{SetCreatorSplit, SetCreatorSplitParams};
+pub use pause::Pause;
+pub use unpause::Unpause;
+pub use close_vault::{CloseVault, CloseVaultParams};
 pub use initialize_position::{InitializePosition, InitializePositionParams};
-pub use crank_distribution::{CrankDistribution, CrankDistributionParams};
\ No newline at end of file
+pub use remove_position::{RemovePosition, RemovePositionParams};
+pub use crank_distribution::CrankDistribution;
+pub use force_close_day::ForceCloseDay;
+pub use add_investor::AddInvestor;
+pub use remove_investor::{RemoveInvestor, RemoveInvestorParams};
\ No newline at end of file
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CreatorNominated;
+use crate::state::PolicyState;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NominateCreatorParams {
+    /// Key that will hold creator authority once it accepts
+    pub new_creator: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: NominateCreatorParams)]
+pub struct NominateCreator<'info> {
+    /// Current policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Nominee's quote token ATA to receive creator payouts after acceptance (optional)
+    #[account(
+        constraint = new_creator_quote_ata.owner == params.new_creator @ ErrorCode::InvalidTokenAccountOwner,
+        constraint = new_creator_quote_ata.mint == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub new_creator_quote_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<NominateCreator>, params: NominateCreatorParams) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    
+    // A new nomination replaces any previous one
+    policy.pending_creator = Some(params.new_creator);
+    policy.pending_creator_quote_ata = ctx
+        .accounts
+        .new_creator_quote_ata
+        .as_ref()
+        .map(|ata| ata.key());
+    
+    emit!(CreatorNominated {
+        vault: ctx.accounts.vault.key(),
+        current_creator: policy.creator_wallet,
+        nominee: params.new_creator,
+        nominee_quote_ata: policy.pending_creator_quote_ata,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::VaultPaused;
+use crate::state::{PolicyState, ProgressState};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// Creator or guardian
+    pub authority: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.is_pause_authority(&authority.key()) @ ErrorCode::UnauthorizedPauseAuthority,
+        constraint = !policy.paused @ ErrorCode::DistributionsPaused,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state (read only - a paused day resumes from the same cursor)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<Pause>) -> Result<()> {
+    ctx.accounts.policy.paused = true;
+    ctx.accounts.policy.paused_at = ctx.accounts.clock.unix_timestamp;
+    
+    emit!(VaultPaused {
+        vault: ctx.accounts.vault.key(),
+        authority: ctx.accounts.authority.key(),
+        pagination_cursor: ctx.accounts.progress.pagination_cursor,
+        day_complete: ctx.accounts.progress.day_complete,
+        timestamp: ctx.accounts.clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PolicyChangeProposed;
+use crate::state::{PendingPolicyChange, PolicyState};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposePolicyChangeParams {
+    /// New maximum investor fee share (None = unchanged)
+    pub investor_fee_share_bps: Option<u16>,
+    
+    /// New daily cap (None = unchanged)
+    pub daily_cap_lamports: Option<u64>,
+    
+    /// Remove the daily cap entirely (takes precedence over daily_cap_lamports)
+    pub clear_daily_cap: bool,
+    
+    /// New minimum payout threshold (None = unchanged)
+    pub min_payout_lamports: Option<u64>,
+    
+    /// Earliest timestamp the change can be applied
+    /// (must be at least policy_change_delay_days out)
+    pub effective_ts: i64,
+}
+
+#[derive(Accounts)]
+pub struct ProposePolicyChange<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<ProposePolicyChange>, params: ProposePolicyChangeParams) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let now = ctx.accounts.clock.unix_timestamp;
+    
+    // One change at a time - an existing proposal must be applied or cancelled first
+    require!(
+        policy.pending_change.is_none(),
+        ErrorCode::PolicyChangeAlreadyPending
+    );
+    
+    if let Some(investor_fee_share_bps) = params.investor_fee_share_bps {
+        require!(
+            investor_fee_share_bps <= MAX_BPS,
+            ErrorCode::InvalidFeeShareBps
+        );
+    }
+    
+    let min_delay = (policy.policy_change_delay_days as i64)
+        .checked_mul(SECONDS_PER_DAY)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let earliest_effective_ts = now
+        .checked_add(min_delay)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    require!(
+        params.effective_ts >= earliest_effective_ts,
+        ErrorCode::PolicyChangeTooEarly
+    );
+    
+    policy.pending_change = Some(PendingPolicyChange {
+        investor_fee_share_bps: params.investor_fee_share_bps,
+        daily_cap_lamports: params.daily_cap_lamports,
+        clear_daily_cap: params.clear_daily_cap,
+        min_payout_lamports: params.min_payout_lamports,
+        proposed_ts: now,
+        effective_ts: params.effective_ts,
+    });
+    
+    emit!(PolicyChangeProposed {
+        vault: ctx.accounts.vault.key(),
+        investor_fee_share_bps: params.investor_fee_share_bps,
+        daily_cap_lamports: params.daily_cap_lamports,
+        clear_daily_cap: params.clear_daily_cap,
+        min_payout_lamports: params.min_payout_lamports,
+        proposed_ts: now,
+        effective_ts: params.effective_ts,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::InvestorRemoved;
+use crate::state::{InvestorRegistry, PolicyState, ProgressState};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemoveInvestorParams {
+    /// Stream of the investor to remove
+    pub stream: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct RemoveInvestor<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - registry may not change mid-day
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// Investor registry
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            REGISTRY_SEED,
+        ],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, InvestorRegistry>,
+}
+
+pub fn handler(ctx: Context<RemoveInvestor>, params: RemoveInvestorParams) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    
+    let index = registry
+        .investors
+        .iter()
+        .position(|entry| entry.stream == params.stream)
+        .ok_or(ErrorCode::InvestorNotRegistered)?;
+    
+    // Preserve the order of the remaining investors
+    let removed = registry.investors.remove(index);
+    
+    emit!(InvestorRemoved {
+        vault: ctx.accounts.vault.key(),
+        stream: removed.stream,
+        investor_quote_ata: removed.investor_quote_ata,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,440 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::amm::{self, AmmAccounts, CloseAccounts};
+use crate::constants::*;
+use crate::cp_amm;
+use crate::errors::ErrorCode;
+use crate::events::PositionRemoved;
+use crate::instructions::close_vault::require_streams_unlocked;
+use crate::state::{InvestorRegistry, PolicyState, PositionState, ProgressState};
+use crate::transfer_fee;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemovePositionParams {
+    /// Remove even though investor streams are still locked (investors forgo this pool's fees)
+    pub creator_opt_out: bool,
+}
+
+#[derive(Accounts)]
+pub struct RemovePosition<'info> {
+    /// Policy creator (receives the reclaimed rent)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state (deregisters the pool)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - no day may be in progress (receives the settled fees)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// Investor registry (streams checked for remaining locks)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            REGISTRY_SEED,
+        ],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, InvestorRegistry>,
+    
+    /// Position state for the pool (closed)
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POSITION_STATE_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = position_state.bump,
+    )]
+    pub position_state: Account<'info, PositionState>,
+    
+    /// Position owner PDA - signs for the position NFT (and forwards any position rent it receives)
+    /// CHECK: PDA that owns the position NFT
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POSITION_OWNER_SEED,
+        ],
+        bump
+    )]
+    pub position_owner_pda: UncheckedAccount<'info>,
+    
+    /// Program quote treasury (receives the withdrawn quote liquidity)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+        ],
+        bump,
+    )]
+    pub program_quote_treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Program base treasury for the pool (swept and closed)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            BASE_TREASURY_SEED,
+            pool.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub program_base_treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Treasury authority PDA
+    /// CHECK: PDA used as authority for treasury
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            TREASURY_SEED,
+            b"authority",
+        ],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+    
+    /// Creator's quote token ATA (receives the withdrawn quote)
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy.creator_quote_ata @ ErrorCode::CreatorQuoteAtaMismatch,
+    )]
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Creator's base token account (receives any base tokens from withdrawn liquidity)
+    #[account(
+        mut,
+        constraint = creator_base_ata.owner == policy.creator_wallet @ ErrorCode::InvalidTokenAccountOwner,
+        constraint = creator_base_ata.mint == position_state.base_mint @ ErrorCode::PoolMintMismatch,
+    )]
+    pub creator_base_ata: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Quote token mint
+    #[account(
+        constraint = quote_mint.key() == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Base token mint (mutable so withheld Token-2022 fees can be harvested)
+    #[account(
+        mut,
+        constraint = base_mint.key() == position_state.base_mint @ ErrorCode::PoolMintMismatch,
+    )]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    /// AMM pool (selects the position)
+    /// CHECK: Must be a registered pool
+    #[account(
+        mut,
+        constraint = policy.position_pools.contains(&pool.key()) @ ErrorCode::PositionAccountsMismatch,
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    /// Position NFT mint (burned)
+    /// CHECK: Must match position state
+    #[account(
+        mut,
+        constraint = position_nft_mint.key() == position_state.position_nft_mint @ ErrorCode::InvalidCpAmmAccount,
+    )]
+    pub position_nft_mint: UncheckedAccount<'info>,
+    
+    /// AMM program
+    /// CHECK: Must match the program of the position's AMM
+    #[account(
+        constraint = amm_program.key() == amm::program_id(position_state.amm_kind) @ ErrorCode::AmmKindMismatch,
+    )]
+    pub amm_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub token_program_2022: Program<'info, Token2022>,
+    
+    /// Token program owning the quote mint
+    #[account(
+        constraint = quote_token_program.key() == *quote_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program owning the base mint
+    #[account(
+        constraint = base_token_program.key() == *base_mint.to_account_info().owner @ ErrorCode::InvalidMintAccount,
+    )]
+    pub base_token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL memo program (required by Token-2022 aware AMM instructions)
+    /// CHECK: Must match the memo program ID
+    #[account(
+        constraint = memo_program.key() == cp_amm::memo_program_id() @ ErrorCode::InvalidCpAmmAccount,
+    )]
+    pub memo_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    
+    // Remaining accounts:
+    // The AMM adapter's position accounts (see amm::position_account_count), then
+    // unless creator_opt_out, every registry entry's stream account, in registry order
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemovePosition<'info>>,
+    params: RemovePositionParams,
+) -> Result<()> {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let vault_key = ctx.accounts.vault.key();
+    let pool_key = ctx.accounts.pool.key();
+    let position_state = ctx.accounts.position_state.clone().into_inner();
+    
+    let venue_count = amm::position_account_count(position_state.amm_kind);
+    require!(
+        ctx.remaining_accounts.len() >= venue_count,
+        ErrorCode::PositionAccountsMismatch
+    );
+    let (venue, streams) = ctx.remaining_accounts.split_at(venue_count);
+    amm::validate_position_accounts(
+        position_state.amm_kind,
+        venue,
+        &position_state,
+        &ctx.accounts.position_owner_pda.key(),
+    )?;
+    
+    // 1. Investors must have nothing left locked, unless the creator opts out
+    if !params.creator_opt_out {
+        require_streams_unlocked(&ctx.accounts.registry, streams, now)?;
+    }
+    
+    let owner_seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        POSITION_OWNER_SEED,
+        &[ctx.bumps.position_owner_pda],
+    ];
+    let owner_signer = &[&owner_seeds[..]];
+    
+    let quote_treasury_info = ctx.accounts.program_quote_treasury.to_account_info();
+    let base_treasury_info = ctx.accounts.program_base_treasury.to_account_info();
+    let amm_program = ctx.accounts.amm_program.to_account_info();
+    let pool = ctx.accounts.pool.to_account_info();
+    let position_owner = ctx.accounts.position_owner_pda.to_account_info();
+    let quote_mint_info = ctx.accounts.quote_mint.to_account_info();
+    let base_mint_info = ctx.accounts.base_mint.to_account_info();
+    let quote_token_program = ctx.accounts.quote_token_program.to_account_info();
+    let base_token_program = ctx.accounts.base_token_program.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let token_program_2022 = ctx.accounts.token_program_2022.to_account_info();
+    let memo_program = ctx.accounts.memo_program.to_account_info();
+    // Fees and withdrawn liquidity land in the treasuries
+    let amm_accounts = AmmAccounts {
+        amm_program: &amm_program,
+        pool: &pool,
+        position_owner: &position_owner,
+        quote_mint: &quote_mint_info,
+        base_mint: &base_mint_info,
+        quote_account: &quote_treasury_info,
+        base_account: &base_treasury_info,
+        quote_token_program: &quote_token_program,
+        base_token_program: &base_token_program,
+        token_program: &token_program,
+        token_program_2022: &token_program_2022,
+        memo_program: &memo_program,
+        venue,
+    };
+    
+    // 2. Settle owed fees first - they belong to investors and join the next day's claim
+    let quote_before = ctx.accounts.program_quote_treasury.amount;
+    amm::collect_fees(&amm_accounts, &position_state, owner_signer)?;
+    ctx.accounts.program_quote_treasury.reload()?;
+    
+    let fees_settled = ctx.accounts.program_quote_treasury.amount
+        .checked_sub(quote_before)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let progress = &mut ctx.accounts.progress;
+    progress.rolled_over_claimed_quote = progress.rolled_over_claimed_quote
+        .checked_add(fees_settled)
+        .ok_or(ErrorCode::MathOverflow)?;
+    progress.total_claimed_lifetime = progress.total_claimed_lifetime
+        .checked_add(fees_settled)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    // Then withdraw all liquidity into the treasuries
+    let quote_before = ctx.accounts.program_quote_treasury.amount;
+    let liquidity = amm::withdraw_all(&amm_accounts, &position_state, owner_signer)?;
+    
+    // 3. Burn the position NFT and close the position; rent goes to the creator
+    amm::close_position(
+        &amm_accounts,
+        &CloseAccounts {
+            position_nft_mint: &ctx.accounts.position_nft_mint.to_account_info(),
+            rent_receiver: &ctx.accounts.creator.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        &position_state,
+        owner_signer,
+    )?;
+    
+    // 4. Withdrawn liquidity goes to the creator; the base treasury is swept and closed
+    ctx.accounts.program_quote_treasury.reload()?;
+    ctx.accounts.program_base_treasury.reload()?;
+    
+    let quote_withdrawn = ctx.accounts.program_quote_treasury.amount
+        .checked_sub(quote_before)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let base_withdrawn = ctx.accounts.program_base_treasury.amount;
+    
+    let treasury_seeds = &[
+        VAULT_SEED,
+        vault_key.as_ref(),
+        TREASURY_SEED,
+        b"authority",
+        &[ctx.bumps.treasury_authority],
+    ];
+    let treasury_signer = &[&treasury_seeds[..]];
+    
+    sweep_withdrawal(
+        WithdrawalSweep {
+            program_quote_treasury: quote_treasury_info,
+            quote_mint: &ctx.accounts.quote_mint,
+            creator_quote_ata: ctx.accounts.creator_quote_ata.to_account_info(),
+            quote_token_program,
+            program_base_treasury: base_treasury_info,
+            base_mint: &ctx.accounts.base_mint,
+            creator_base_ata: ctx.accounts.creator_base_ata.to_account_info(),
+            base_token_program,
+            treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+            rent_recipient: ctx.accounts.creator.to_account_info(),
+        },
+        quote_withdrawn,
+        base_withdrawn,
+        treasury_signer,
+    )?;
+    
+    // 5. Deregister the pool; the position state is closed by its `close` constraint
+    ctx.accounts.policy.position_pools.retain(|pool| *pool != pool_key);
+    
+    emit!(PositionRemoved {
+        vault: vault_key,
+        pool: pool_key,
+        liquidity_withdrawn: liquidity,
+        fees_settled,
+        quote_withdrawn,
+        base_withdrawn,
+        creator_opt_out: params.creator_opt_out,
+    });
+    
+    Ok(())
+}
+
+/// Accounts used to hand a removed position's liquidity to the creator
+pub(crate) struct WithdrawalSweep<'a, 'info> {
+    pub program_quote_treasury: AccountInfo<'info>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub creator_quote_ata: AccountInfo<'info>,
+    pub quote_token_program: AccountInfo<'info>,
+    pub program_base_treasury: AccountInfo<'info>,
+    pub base_mint: &'a InterfaceAccount<'info, Mint>,
+    pub creator_base_ata: AccountInfo<'info>,
+    pub base_token_program: AccountInfo<'info>,
+    pub treasury_authority: AccountInfo<'info>,
+    /// Receives the base treasury rent
+    pub rent_recipient: AccountInfo<'info>,
+}
+
+/// Send the withdrawn quote and base to the creator, then close the pool's base treasury
+pub(crate) fn sweep_withdrawal(
+    accounts: WithdrawalSweep,
+    quote_withdrawn: u64,
+    base_withdrawn: u64,
+    treasury_signer: &[&[&[u8]]],
+) -> Result<()> {
+    let transfers = [
+        (
+            accounts.program_quote_treasury.clone(),
+            accounts.creator_quote_ata.clone(),
+            accounts.quote_mint,
+            accounts.quote_token_program.clone(),
+            quote_withdrawn,
+        ),
+        (
+            accounts.program_base_treasury.clone(),
+            accounts.creator_base_ata.clone(),
+            accounts.base_mint,
+            accounts.base_token_program.clone(),
+            base_withdrawn,
+        ),
+    ];
+    
+    for (treasury, destination, mint, token_program, amount) in transfers {
+        if amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program,
+                    TransferChecked {
+                        from: treasury,
+                        mint: mint.to_account_info(),
+                        to: destination,
+                        authority: accounts.treasury_authority.clone(),
+                    },
+                    treasury_signer,
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+    }
+    
+    // Token-2022 refuses to close accounts that still hold withheld transfer fees
+    let base_mint_info = accounts.base_mint.to_account_info();
+    transfer_fee::harvest_withheld_to_mint(
+        &accounts.base_token_program,
+        &base_mint_info,
+        &accounts.program_base_treasury,
+    )?;
+    
+    token_interface::close_account(CpiContext::new_with_signer(
+        accounts.base_token_program,
+        CloseAccount {
+            account: accounts.program_base_treasury,
+            destination: accounts.rent_recipient,
+            authority: accounts.treasury_authority,
+        },
+        treasury_signer,
+    ))?;
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CreatorSplitUpdated;
+use crate::state::{CreatorSplit, PolicyState, ProgressState, SplitRecipient};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetCreatorSplitParams {
+    /// New recipients of the creator remainder (empty = all to creator_quote_ata)
+    pub recipients: Vec<SplitRecipient>,
+}
+
+#[derive(Accounts)]
+pub struct SetCreatorSplit<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - split may not change mid-day
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// Creator split
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            CREATOR_SPLIT_SEED,
+        ],
+        bump = creator_split.bump,
+    )]
+    pub creator_split: Account<'info, CreatorSplit>,
+    
+    // Remaining accounts:
+    // Each recipient's quote token account, in the same order as params.recipients
+}
+
+pub fn handler(ctx: Context<SetCreatorSplit>, params: SetCreatorSplitParams) -> Result<()> {
+    CreatorSplit::validate_recipients(&params.recipients)?;
+    
+    require!(
+        ctx.remaining_accounts.len() == params.recipients.len(),
+        ErrorCode::CreatorSplitAccountsMismatch
+    );
+    
+    // Every recipient must be a quote token account so the crank can pay it
+    for (recipient, account_info) in params.recipients.iter().zip(ctx.remaining_accounts.iter()) {
+        require_keys_eq!(
+            account_info.key(),
+            recipient.quote_ata,
+            ErrorCode::CreatorSplitAccountsMismatch
+        );
+        
+        require!(
+            *account_info.owner == token::ID || *account_info.owner == token_2022::ID,
+            ErrorCode::CreatorSplitAccountsMismatch
+        );
+        
+        let data = account_info.try_borrow_data()?;
+        let quote_ata = TokenAccount::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(ErrorCode::CreatorSplitAccountsMismatch))?;
+        require_keys_eq!(
+            quote_ata.mint,
+            ctx.accounts.policy.quote_mint,
+            ErrorCode::QuoteMintNotInPool
+        );
+    }
+    
+    let creator_split = &mut ctx.accounts.creator_split;
+    creator_split.recipients = params.recipients;
+    
+    emit!(CreatorSplitUpdated {
+        vault: ctx.accounts.vault.key(),
+        recipients: creator_split.recipients.clone(),
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::VaultUnpaused;
+use crate::state::{PolicyState, ProgressState};
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// Creator or guardian
+    pub authority: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.is_pause_authority(&authority.key()) @ ErrorCode::UnauthorizedPauseAuthority,
+        constraint = policy.paused @ ErrorCode::DistributionsNotPaused,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state (a paused day resumes from the same cursor; the paused
+    /// time is excluded from its stale-day timeout)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<Unpause>) -> Result<()> {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+    
+    ctx.accounts.progress.exclude_paused_time(policy.paused_at, now);
+    policy.paused = false;
+    policy.paused_at = 0;
+    
+    emit!(VaultUnpaused {
+        vault: ctx.accounts.vault.key(),
+        authority: ctx.accounts.authority.key(),
+        pagination_cursor: ctx.accounts.progress.pagination_cursor,
+        day_complete: ctx.accounts.progress.day_complete,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
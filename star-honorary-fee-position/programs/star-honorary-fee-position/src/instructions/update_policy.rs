@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::PolicyUpdated;
+use crate::state::{PolicyState, ProgressState};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdatePolicyParams {
+    /// New maximum investor fee share (None = unchanged)
+    pub investor_fee_share_bps: Option<u16>,
+    
+    /// New daily cap (None = unchanged)
+    pub daily_cap_lamports: Option<u64>,
+    
+    /// Remove the daily cap entirely (takes precedence over daily_cap_lamports)
+    pub clear_daily_cap: bool,
+    
+    /// New minimum payout threshold (None = unchanged)
+    pub min_payout_lamports: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePolicy<'info> {
+    /// Policy creator
+    pub creator: Signer<'info>,
+    
+    /// Vault account
+    /// CHECK: Used as seed for PDAs
+    pub vault: UncheckedAccount<'info>,
+    
+    /// Policy state
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            POLICY_SEED,
+        ],
+        bump = policy.bump,
+        constraint = policy.creator_wallet == creator.key() @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub policy: Account<'info, PolicyState>,
+    
+    /// Progress state - policy may not change mid-day
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            PROGRESS_SEED,
+        ],
+        bump = progress.bump,
+        constraint = progress.day_complete @ ErrorCode::DistributionNotComplete,
+    )]
+    pub progress: Account<'info, ProgressState>,
+    
+    /// New creator quote token ATA (optional)
+    #[account(
+        constraint = new_creator_quote_ata.owner == creator.key() @ ErrorCode::InvalidTokenAccountOwner,
+        constraint = new_creator_quote_ata.mint == policy.quote_mint @ ErrorCode::QuoteMintNotInPool,
+    )]
+    pub new_creator_quote_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<UpdatePolicy>, params: UpdatePolicyParams) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    
+    let old_investor_fee_share_bps = policy.investor_fee_share_bps;
+    let old_daily_cap_lamports = policy.daily_cap_lamports;
+    let old_min_payout_lamports = policy.min_payout_lamports;
+    let old_creator_quote_ata = policy.creator_quote_ata;
+    
+    let changes_economics = params.investor_fee_share_bps.is_some()
+        || params.daily_cap_lamports.is_some()
+        || params.clear_daily_cap
+        || params.min_payout_lamports.is_some();
+    
+    // With a timelock configured, economic changes must go through propose/apply
+    require!(
+        !changes_economics || policy.policy_change_delay_days == 0,
+        ErrorCode::PolicyChangeRequiresTimelock
+    );
+    
+    policy.apply_economic_change(
+        params.investor_fee_share_bps,
+        params.daily_cap_lamports,
+        params.clear_daily_cap,
+        params.min_payout_lamports,
+    )?;
+    
+    if let Some(new_creator_quote_ata) = &ctx.accounts.new_creator_quote_ata {
+        policy.creator_quote_ata = new_creator_quote_ata.key();
+    }
+    
+    emit!(PolicyUpdated {
+        vault: ctx.accounts.vault.key(),
+        old_investor_fee_share_bps,
+        new_investor_fee_share_bps: policy.investor_fee_share_bps,
+        old_daily_cap_lamports,
+        new_daily_cap_lamports: policy.daily_cap_lamports,
+        old_min_payout_lamports,
+        new_min_payout_lamports: policy.min_payout_lamports,
+        old_creator_quote_ata,
+        new_creator_quote_ata: policy.creator_quote_ata,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
@@ -1,10 +1,19 @@
 use anchor_lang::prelude::*;
 
+pub mod amm;
 pub mod constants;
+pub mod cp_amm;
+pub mod damm_v2;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod linear_escrow;
+pub mod lock;
+pub mod math;
 pub mod state;
+pub mod streamflow;
+pub mod transfer_fee;
+pub mod whirlpool;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
@@ -18,7 +27,12 @@ pub mod star_honorary_fee_position {
     /// - Maximum investor fee share (basis points)
     /// - Daily distribution cap (optional)
     /// - Minimum payout threshold
+    /// - Optional crank reward (basis points of each day's claim, with cap)
     /// - Y0 total allocation for f_locked calculation
+    /// - Distribution interval, optionally aligned to epoch boundaries
+    /// - Maximum day duration before a stalled day can be force-closed
+    /// - Timelock (days) for economic policy changes
+    /// - Optional guardian allowed to pause distributions
     pub fn initialize_policy(
         ctx: Context<instructions::InitializePolicy>,
         params: instructions::InitializePolicyParams,
@@ -26,34 +40,182 @@ pub mod star_honorary_fee_position {
         instructions::initialize_policy::handler(ctx, params)
     }
 
-    /// Initialize honorary position in CP-AMM pool
+    /// Update policy parameters (creator only)
     /// 
-    /// Creates a quote-only liquidity position that:
+    /// Can change the investor fee share, daily cap, minimum payout and
+    /// creator payout ATA. Rejected while a day's distribution is in progress.
+    /// When a policy timelock is configured, only the creator payout ATA can
+    /// change here; economic fields go through propose/apply.
+    pub fn update_policy(
+        ctx: Context<instructions::UpdatePolicy>,
+        params: instructions::UpdatePolicyParams,
+    ) -> Result<()> {
+        instructions::update_policy::handler(ctx, params)
+    }
+
+    /// Propose an economic policy change (creator only)
+    /// 
+    /// The change is stored as pending with an effective timestamp at least
+    /// the configured timelock delay in the future.
+    pub fn propose_policy_change(
+        ctx: Context<instructions::ProposePolicyChange>,
+        params: instructions::ProposePolicyChangeParams,
+    ) -> Result<()> {
+        instructions::propose_policy_change::handler(ctx, params)
+    }
+
+    /// Apply a matured pending policy change
+    /// 
+    /// Can be called by anyone once the effective timestamp has passed.
+    /// Rejected while a day's distribution is in progress.
+    pub fn apply_policy_change(ctx: Context<instructions::ApplyPolicyChange>) -> Result<()> {
+        instructions::apply_policy_change::handler(ctx)
+    }
+
+    /// Cancel the pending policy change (creator only)
+    pub fn cancel_policy_change(ctx: Context<instructions::CancelPolicyChange>) -> Result<()> {
+        instructions::cancel_policy_change::handler(ctx)
+    }
+
+    /// Nominate a new creator authority (creator only)
+    /// 
+    /// Optionally nominates a new payout ATA owned by the nominee. Nothing
+    /// changes until the nominee accepts; a new nomination replaces the old one.
+    pub fn nominate_creator(
+        ctx: Context<instructions::NominateCreator>,
+        params: instructions::NominateCreatorParams,
+    ) -> Result<()> {
+        instructions::nominate_creator::handler(ctx, params)
+    }
+
+    /// Accept a pending creator nomination (nominee only)
+    /// 
+    /// Moves creator authority, and the payout ATA if one was nominated.
+    pub fn accept_creator(ctx: Context<instructions::AcceptCreator>) -> Result<()> {
+        instructions::accept_creator::handler(ctx)
+    }
+
+    /// Set the recipients of the creator remainder (creator only)
+    /// 
+    /// Up to `MAX_SPLIT_RECIPIENTS` quote token accounts with weights summing
+    /// to 10_000 bps. An empty list sends the whole remainder to
+    /// `creator_quote_ata`. Rejected while a day is in progress.
+    pub fn set_creator_split(
+        ctx: Context<instructions::SetCreatorSplit>,
+        params: instructions::SetCreatorSplitParams,
+    ) -> Result<()> {
+        instructions::set_creator_split::handler(ctx, params)
+    }
+
+    /// Pause distributions (creator or guardian)
+    /// 
+    /// The crank is rejected while paused. Progress state is untouched, so a
+    /// day paused mid-way resumes from the same pagination cursor.
+    pub fn pause(ctx: Context<instructions::Pause>) -> Result<()> {
+        instructions::pause::handler(ctx)
+    }
+
+    /// Resume distributions (creator or guardian)
+    pub fn unpause(ctx: Context<instructions::Unpause>) -> Result<()> {
+        instructions::unpause::handler(ctx)
+    }
+
+    /// Close the vault and reclaim rent (creator only)
+    /// 
+    /// Requires every registered stream to be fully unlocked unless the
+    /// creator opts out, and every position to have been removed. Sweeps the
+    /// quote treasury to the creator and closes all program accounts,
+    /// returning their lamports to the recipient.
+    pub fn close_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, instructions::CloseVault<'info>>,
+        params: instructions::CloseVaultParams,
+    ) -> Result<()> {
+        instructions::close_vault::handler(ctx, params)
+    }
+
+    /// Initialize an honorary position (creator only)
+    /// 
+    /// Creates a quote-only liquidity position in a Raydium CLMM, Meteora
+    /// DAMM v2 or Orca Whirlpool pool (`params.amm_kind`) that:
     /// - Collects ONLY quote token fees (no base token fees)
-    /// - Uses minimal liquidity (1 unit)
-    /// - Validates tick range for quote-only strategy
-    pub fn initialize_position(
-        ctx: Context<instructions::InitializePosition>,
+    /// - Opens the position via the AMM adapter with the owner PDA as NFT holder
+    /// - Records the liquidity actually minted by the AMM
+    /// - Validates the tick range (or DAMM v2 collect-fee mode) for quote-only strategy
+    /// 
+    /// One position per pool, up to `MAX_HONORARY_POSITIONS`, all sharing the
+    /// vault's policy and quote mint.
+    pub fn initialize_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, instructions::InitializePosition<'info>>,
         params: instructions::InitializePositionParams,
     ) -> Result<()> {
         instructions::initialize_position::handler(ctx, params)
     }
 
+    /// Remove a pool's honorary position (creator only)
+    /// 
+    /// Requires every registered stream to be fully unlocked unless the
+    /// creator opts out. Owed fees are settled into the next day's claim;
+    /// the withdrawn liquidity goes to the creator and the pool's position
+    /// state and base treasury are closed.
+    pub fn remove_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, instructions::RemovePosition<'info>>,
+        params: instructions::RemovePositionParams,
+    ) -> Result<()> {
+        instructions::remove_position::handler(ctx, params)
+    }
+
     /// Crank distribution for a page of investors
     /// 
     /// Distributes collected quote fees:
-    /// 1. Enforces 24-hour gate (on first page)
-    /// 2. Claims fees from CP-AMM position (quote only)
-    /// 3. Calculates f_locked from Streamflow data
-    /// 4. Distributes pro-rata to investors (paginated)
-    /// 5. Sends remainder to creator (on last page)
+    /// 1. Enforces the distribution interval gate (on first page)
+    /// 2. Claims fees from every registered position through its AMM
+    ///    adapter (quote only)
+    ///    and reserves the crank reward before the investor/creator split
+    /// 3. Snapshot pass: sums locked amounts across all pages
+    ///    and fixes f_locked and the day's investor pool
+    /// 4. Payout pass: distributes pro-rata to investors (paginated)
+    /// 5. Sends remainder to creator, fanned out over the creator split
+    ///    if one is set (on last payout page)
     /// 
-    /// Can be called by anyone (permissionless cranking)
+    /// Pages follow the on-chain investor registry; the last page is
+    /// derived by the program.
+    /// 
+    /// Can be called by anyone (permissionless cranking); a cranker that
+    /// passes its quote ATA is paid a slice of the crank reward per page
     pub fn crank_distribution<'info>(
         ctx: Context<'_, '_, '_, 'info, instructions::CrankDistribution<'info>>,
-        params: instructions::CrankDistributionParams,
     ) -> Result<()> {
-        instructions::crank_distribution::handler(ctx, params)
+        instructions::crank_distribution::handler(ctx)
+    }
+
+    /// Force-close a day abandoned mid-pagination
+    /// 
+    /// Once `max_day_duration_secs` has passed since the day started, anyone
+    /// can finalize it. An abandoned payout pass treats unpaid investor
+    /// amounts per the cap overflow mode and pays the creator remainder; an
+    /// abandoned snapshot pass rolls the day's claim into the next day.
+    pub fn force_close_day<'info>(
+        ctx: Context<'_, '_, '_, 'info, instructions::ForceCloseDay<'info>>,
+    ) -> Result<()> {
+        instructions::force_close_day::handler(ctx)
+    }
+
+    /// Append an investor to the vault's registry (creator only)
+    /// 
+    /// Rejected while a day's distribution is in progress.
+    pub fn add_investor(ctx: Context<instructions::AddInvestor>) -> Result<()> {
+        instructions::add_investor::handler(ctx)
+    }
+
+    /// Remove an investor from the vault's registry (creator only)
+    /// 
+    /// Remaining investors keep their relative order. Rejected while a
+    /// day's distribution is in progress.
+    pub fn remove_investor(
+        ctx: Context<instructions::RemoveInvestor>,
+        params: instructions::RemoveInvestorParams,
+    ) -> Result<()> {
+        instructions::remove_investor::handler(ctx, params)
     }
 }
 
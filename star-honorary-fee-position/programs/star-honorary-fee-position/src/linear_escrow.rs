@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use crate::constants::LINEAR_ESCROW_PROGRAM_IDS;
+use crate::errors::ErrorCode;
+
+// Linear-vesting escrow `VestingEscrow` layout (Jupiter Lock; in-house escrows reuse it):
+// discriminator (8) | recipient (32) | token_mint (32) | creator (32) | base (32) |
+// escrow_bump (1) | update_recipient_mode (1) | cancel_mode (1) | token_program_flag (1) |
+// padding (4) | cliff_time (8) | frequency (8) | cliff_unlock_amount (8) |
+// amount_per_period (8) | number_of_period (8) | total_claimed_amount (8) |
+// vesting_start_time (8) | cancelled_at (8) | ...
+const RECIPIENT_OFFSET: usize = 8;
+const TOKEN_MINT_OFFSET: usize = 40;
+const CREATOR_OFFSET: usize = 72;
+const CLIFF_TIME_OFFSET: usize = 144;
+const FREQUENCY_OFFSET: usize = 152;
+const CLIFF_UNLOCK_AMOUNT_OFFSET: usize = 160;
+const AMOUNT_PER_PERIOD_OFFSET: usize = 168;
+const NUMBER_OF_PERIOD_OFFSET: usize = 176;
+const TOTAL_CLAIMED_AMOUNT_OFFSET: usize = 184;
+const CANCELLED_AT_OFFSET: usize = 200;
+const MIN_ESCROW_LEN: usize = CANCELLED_AT_OFFSET + 8;
+
+// Anchor account discriminator: sha256("account:VestingEscrow")[..8]
+const VESTING_ESCROW_DISCRIMINATOR: [u8; 8] = [244, 119, 183, 4, 73, 116, 135, 195];
+
+/// Whether `program` is a known linear-vesting escrow program
+pub fn is_escrow_program(program: &Pubkey) -> bool {
+    LINEAR_ESCROW_PROGRAM_IDS
+        .iter()
+        .any(|id| Pubkey::from_str(id).unwrap() == *program)
+}
+
+/// Fields of a linear-vesting escrow account needed to compute locked amounts
+#[derive(Debug, Clone, Default)]
+pub struct LinearVestingEscrow {
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub cliff_time: u64,
+    pub frequency: u64,
+    pub cliff_unlock_amount: u64,
+    pub amount_per_period: u64,
+    pub number_of_period: u64,
+    pub total_claimed_amount: u64,
+    pub cancelled_at: u64,
+}
+
+impl LinearVestingEscrow {
+    /// Deserialize raw escrow account data
+    pub fn unpack(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= MIN_ESCROW_LEN && data[..8] == VESTING_ESCROW_DISCRIMINATOR,
+            ErrorCode::InvalidEscrowData
+        );
+        
+        let read_u64 = |offset: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+        
+        Ok(Self {
+            recipient: read_pubkey(RECIPIENT_OFFSET),
+            token_mint: read_pubkey(TOKEN_MINT_OFFSET),
+            creator: read_pubkey(CREATOR_OFFSET),
+            cliff_time: read_u64(CLIFF_TIME_OFFSET),
+            frequency: read_u64(FREQUENCY_OFFSET),
+            cliff_unlock_amount: read_u64(CLIFF_UNLOCK_AMOUNT_OFFSET),
+            amount_per_period: read_u64(AMOUNT_PER_PERIOD_OFFSET),
+            number_of_period: read_u64(NUMBER_OF_PERIOD_OFFSET),
+            total_claimed_amount: read_u64(TOTAL_CLAIMED_AMOUNT_OFFSET),
+            cancelled_at: read_u64(CANCELLED_AT_OFFSET),
+        })
+    }
+    
+    /// Total amount escrowed: the cliff unlock plus every period's release
+    pub fn total_amount(&self) -> u64 {
+        self.amount_per_period
+            .saturating_mul(self.number_of_period)
+            .saturating_add(self.cliff_unlock_amount)
+    }
+    
+    /// Amount unlocked at `now`: the cliff amount, then one release per elapsed period
+    pub fn unlocked_amount(&self, now: i64) -> u64 {
+        let now = now.max(0) as u64;
+        
+        if now < self.cliff_time {
+            return 0;
+        }
+        
+        // A zero frequency releases every period at the cliff
+        let periods_elapsed = (now - self.cliff_time)
+            .checked_div(self.frequency)
+            .map_or(self.number_of_period, |periods| periods.min(self.number_of_period));
+        
+        self.cliff_unlock_amount
+            .saturating_add(periods_elapsed.saturating_mul(self.amount_per_period))
+            .min(self.total_amount())
+    }
+    
+    /// Amount still locked at `now`
+    ///
+    /// As with Streamflow, claims only reduce the locked amount when they exceed
+    /// the schedule, and a cancelled escrow has nothing locked.
+    pub fn locked_amount(&self, now: i64) -> u64 {
+        if self.cancelled_at > 0 {
+            return 0;
+        }
+        
+        let unlocked = self.unlocked_amount(now).max(self.total_claimed_amount);
+        self.total_amount().saturating_sub(unlocked)
+    }
+}
\ No newline at end of file
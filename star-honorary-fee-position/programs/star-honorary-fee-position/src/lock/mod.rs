@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::linear_escrow::{self, LinearVestingEscrow};
+use crate::state::PolicyState;
+use crate::streamflow::{self, StreamflowContract};
+
+/// Lock state of one investor stream, common to every lock provider
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamLock {
+    /// Beneficiary the locked tokens vest to
+    pub recipient: Pubkey,
+    /// Account that created and funded the lock
+    pub sender: Pubkey,
+    /// Mint of the locked tokens
+    pub mint: Pubkey,
+    /// Amount still locked at the time of reading
+    pub locked_amount: u64,
+}
+
+impl StreamLock {
+    /// Whether the lock holds `vesting_mint` and was funded by `sender`
+    pub fn is_bound_to(&self, vesting_mint: &Pubkey, sender: &Pubkey) -> bool {
+        self.mint == *vesting_mint && self.sender == *sender
+    }
+}
+
+/// Source of investor locks, selected by the stream account's owner program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockProviderKind {
+    /// Streamflow stream contracts
+    Streamflow,
+    /// Cliff + periodic release escrows in the `VestingEscrow` layout
+    LinearEscrow,
+}
+
+impl LockProviderKind {
+    /// Provider whose program owns stream accounts of `owner`, if supported
+    pub fn from_owner(owner: &Pubkey) -> Option<Self> {
+        if StreamflowProvider::owns(owner) {
+            Some(Self::Streamflow)
+        } else if LinearEscrowProvider::owns(owner) {
+            Some(Self::LinearEscrow)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parser for one lock provider's stream accounts
+pub trait LockProvider {
+    /// Whether accounts owned by `program` belong to this provider
+    fn owns(program: &Pubkey) -> bool;
+    
+    /// Parse raw stream account data into its lock state at `now`
+    fn read_lock(data: &[u8], now: i64) -> Result<StreamLock>;
+}
+
+/// Streamflow streams: cliff amount, then periodic release until `end_time`
+pub struct StreamflowProvider;
+
+impl LockProvider for StreamflowProvider {
+    fn owns(program: &Pubkey) -> bool {
+        *program == streamflow::program_id()
+    }
+    
+    fn read_lock(data: &[u8], now: i64) -> Result<StreamLock> {
+        let contract = StreamflowContract::unpack(data)?;
+        
+        Ok(StreamLock {
+            recipient: contract.recipient,
+            sender: contract.sender,
+            mint: contract.mint,
+            locked_amount: contract.locked_amount(now),
+        })
+    }
+}
+
+/// Linear-vesting escrows (Jupiter Lock and in-house escrows sharing its layout)
+pub struct LinearEscrowProvider;
+
+impl LockProvider for LinearEscrowProvider {
+    fn owns(program: &Pubkey) -> bool {
+        linear_escrow::is_escrow_program(program)
+    }
+    
+    fn read_lock(data: &[u8], now: i64) -> Result<StreamLock> {
+        let escrow = LinearVestingEscrow::unpack(data)?;
+        
+        Ok(StreamLock {
+            recipient: escrow.recipient,
+            sender: escrow.creator,
+            mint: escrow.token_mint,
+            locked_amount: escrow.locked_amount(now),
+        })
+    }
+}
+
+/// Read a stream account through the lock provider owning it
+///
+/// Providers can be mixed freely within one vault; accounts owned by any other
+/// program are rejected.
+pub fn read_stream_lock(stream_account: &AccountInfo, now: i64) -> Result<StreamLock> {
+    let kind = LockProviderKind::from_owner(stream_account.owner)
+        .ok_or(ErrorCode::UnsupportedLockProvider)?;
+    let data = stream_account.try_borrow_data()?;
+    
+    match kind {
+        LockProviderKind::Streamflow => StreamflowProvider::read_lock(&data, now),
+        LockProviderKind::LinearEscrow => LinearEscrowProvider::read_lock(&data, now),
+    }
+}
+
+/// Read a stream that must count toward the vault's locked total
+///
+/// Rejects streams held by an unsupported program or locking anything other
+/// than the policy's vesting mint from its allowed sender.
+pub fn read_vault_stream_lock(
+    stream_account: &AccountInfo,
+    policy: &PolicyState,
+    now: i64,
+) -> Result<StreamLock> {
+    require!(
+        LockProviderKind::from_owner(stream_account.owner).is_some(),
+        ErrorCode::StreamNotBoundToVault
+    );
+    
+    let stream = read_stream_lock(stream_account, now)?;
+    require!(
+        stream.is_bound_to(&policy.vesting_mint, &policy.allowed_stream_sender),
+        ErrorCode::StreamNotBoundToVault
+    );
+    
+    Ok(stream)
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_BPS;
+use crate::errors::ErrorCode;
+
+/// f_locked(t) = locked_total / Y0, in basis points (capped at 100%)
+pub fn f_locked_bps(locked_total: u64, y0_total_allocation: u64) -> Result<u16> {
+    if y0_total_allocation == 0 {
+        return Ok(0);
+    }
+    
+    let fraction = (locked_total as u128)
+        .checked_mul(MAX_BPS as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / y0_total_allocation as u128;
+    
+    Ok(fraction.min(MAX_BPS as u128) as u16)
+}
+
+/// Investor pool for the day before the daily cap: claimed * min(policy_share, f_locked)
+///
+/// The cap is applied separately by `apply_daily_cap`, after any deferred
+/// overflow from earlier days is added.
+pub fn investor_pool(
+    claimed_quote: u64,
+    investor_fee_share_bps: u16,
+    f_locked_bps: u16,
+) -> Result<u64> {
+    let eligible_share_bps = investor_fee_share_bps.min(f_locked_bps);
+    
+    let pool = (claimed_quote as u128)
+        .checked_mul(eligible_share_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / MAX_BPS as u128;
+    
+    Ok(pool as u64)
+}
+
+/// Clip the amount available to investors to the daily cap, returning (pool, overflow)
+pub fn apply_daily_cap(available: u64, daily_cap: Option<u64>) -> (u64, u64) {
+    match daily_cap {
+        Some(cap) if available > cap => (cap, available - cap),
+        _ => (available, 0),
+    }
+}
+
+/// Day's crank reward budget: claimed * crank_reward_bps, clipped to the optional cap
+pub fn crank_reward_budget(
+    claimed_quote: u64,
+    crank_reward_bps: u16,
+    crank_reward_cap: Option<u64>,
+) -> Result<u64> {
+    let budget = (claimed_quote as u128)
+        .checked_mul(crank_reward_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / MAX_BPS as u128;
+    let budget = budget as u64;
+    
+    Ok(match crank_reward_cap {
+        Some(cap) => budget.min(cap),
+        None => budget,
+    })
+}
+
+/// Reward for a single page: the budget spread evenly over both passes of the day
+pub fn crank_reward_per_page(budget: u64, page_count: u32) -> u64 {
+    budget / (2 * page_count.max(1) as u64)
+}
+
+/// Investor's pro-rata share of the pool: pool * locked / locked_total (floored)
+pub fn pro_rata_share(pool: u64, locked: u64, locked_total: u64) -> Result<u64> {
+    if locked_total == 0 {
+        return Ok(0);
+    }
+    
+    let share = (pool as u128)
+        .checked_mul(locked as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / locked_total as u128;
+    
+    u64::try_from(share).map_err(|_| error!(ErrorCode::MathOverflow))
+}
\ No newline at end of file
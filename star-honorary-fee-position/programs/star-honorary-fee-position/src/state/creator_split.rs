@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_BPS, MAX_SPLIT_RECIPIENTS};
+use crate::errors::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct SplitRecipient {
+    /// Quote token account receiving this recipient's cut
+    pub quote_ata: Pubkey,
+    
+    /// Share of the creator remainder in basis points
+    pub weight_bps: u16,
+}
+
+impl SplitRecipient {
+    pub const LEN: usize = 32 + // quote_ata
+        2; // weight_bps
+}
+
+#[account]
+#[derive(Default)]
+pub struct CreatorSplit {
+    /// Vault pubkey this split is associated with
+    pub vault: Pubkey,
+    
+    /// Recipients of the creator remainder (empty = all to creator_quote_ata)
+    pub recipients: Vec<SplitRecipient>,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorSplit {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        4 + SplitRecipient::LEN * MAX_SPLIT_RECIPIENTS + // recipients
+        1; // bump
+    
+    /// Recipients must be unique, non-zero and weigh exactly 10_000 bps in total
+    pub fn validate_recipients(recipients: &[SplitRecipient]) -> Result<()> {
+        if recipients.is_empty() {
+            return Ok(());
+        }
+        
+        require!(
+            recipients.len() <= MAX_SPLIT_RECIPIENTS,
+            ErrorCode::InvalidCreatorSplit
+        );
+        
+        let mut total_bps = 0u32;
+        for (i, recipient) in recipients.iter().enumerate() {
+            require!(recipient.weight_bps > 0, ErrorCode::InvalidCreatorSplit);
+            require!(
+                !recipients[..i].iter().any(|other| other.quote_ata == recipient.quote_ata),
+                ErrorCode::InvalidCreatorSplit
+            );
+            total_bps += recipient.weight_bps as u32;
+        }
+        
+        require!(total_bps == MAX_BPS as u32, ErrorCode::InvalidCreatorSplit);
+        
+        Ok(())
+    }
+    
+    /// Split `amount` by weight (floored); the last recipient takes the rounding remainder
+    pub fn shares(&self, amount: u64) -> Result<Vec<u64>> {
+        let mut shares = Vec::with_capacity(self.recipients.len());
+        let mut allocated = 0u64;
+        
+        for (i, recipient) in self.recipients.iter().enumerate() {
+            let share = if i + 1 == self.recipients.len() {
+                amount.checked_sub(allocated).ok_or(ErrorCode::MathOverflow)?
+            } else {
+                let share = (amount as u128)
+                    .checked_mul(recipient.weight_bps as u128)
+                    .ok_or(ErrorCode::MathOverflow)?
+                    / MAX_BPS as u128;
+                share as u64
+            };
+            allocated = allocated.checked_add(share).ok_or(ErrorCode::MathOverflow)?;
+            shares.push(share);
+        }
+        
+        Ok(shares)
+    }
+}
\ No newline at end of file
@@ -1,7 +1,11 @@
 pub mod policy;
 pub mod progress;
 pub mod position;
+pub mod registry;
+pub mod creator_split;
 
 pub use policy::*;
 pub use progress::*;
-pub use position::*;
\ No newline at end of file
+pub use position::*;
+pub use registry::*;
+pub use creator_split::*;
\ No newline at end of file
@@ -1,4 +1,51 @@
 use anchor_lang::prelude::*;
+use crate::constants::{MAX_BPS, MAX_HONORARY_POSITIONS};
+use crate::errors::ErrorCode;
+use crate::state::ProgressState;
+
+/// What happens to the part of the investor pool clipped by the daily cap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum CapOverflowMode {
+    /// Added to the creator's remainder on the last page
+    #[default]
+    Creator,
+    
+    /// Rolled into `deferred_investor_balance` and paid to investors on later days
+    Deferred,
+    
+    /// Left in the program treasury
+    Treasury,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct PendingPolicyChange {
+    /// New maximum investor fee share (None = unchanged)
+    pub investor_fee_share_bps: Option<u16>,
+    
+    /// New daily cap (None = unchanged)
+    pub daily_cap_lamports: Option<u64>,
+    
+    /// Remove the daily cap entirely
+    pub clear_daily_cap: bool,
+    
+    /// New minimum payout threshold (None = unchanged)
+    pub min_payout_lamports: Option<u64>,
+    
+    /// Timestamp the change was proposed
+    pub proposed_ts: i64,
+    
+    /// Earliest timestamp the change can be applied
+    pub effective_ts: i64,
+}
+
+impl PendingPolicyChange {
+    pub const LEN: usize = 1 + 2 + // Option<u16> investor_fee_share_bps
+        1 + 8 + // Option<u64> daily_cap_lamports
+        1 + // clear_daily_cap
+        1 + 8 + // Option<u64> min_payout_lamports
+        8 + // proposed_ts
+        8; // effective_ts
+}
 
 #[account]
 #[derive(Default)]
@@ -15,6 +62,12 @@ pub struct PolicyState {
     /// Quote token mint address
     pub quote_mint: Pubkey,
     
+    /// Mint of the investors' locked tokens (the base token)
+    pub vesting_mint: Pubkey,
+    
+    /// Only streams funded by this sender count toward locked_total
+    pub allowed_stream_sender: Pubkey,
+    
     /// Maximum investor fee share in basis points (0-10000)
     /// Actual share is min(investor_fee_share_bps, f_locked * 10000)
     pub investor_fee_share_bps: u16,
@@ -25,9 +78,53 @@ pub struct PolicyState {
     /// Minimum payout threshold to avoid dust transfers
     pub min_payout_lamports: u64,
     
+    /// Handling of investor pool amounts clipped by the daily cap
+    pub cap_overflow_mode: CapOverflowMode,
+    
+    /// Share of each day's claimed quote paid to crankers, in basis points
+    pub crank_reward_bps: u16,
+    
+    /// Optional cap on the day's total crank reward
+    pub crank_reward_cap_lamports: Option<u64>,
+    
     /// Total investor allocation at TGE (Y0) - used for f_locked calculation
     pub y0_total_allocation: u64,
     
+    /// Minimum time between distribution days
+    pub distribution_interval_secs: i64,
+    
+    /// Align distribution windows to multiples of the interval since the Unix
+    /// epoch (e.g. UTC midnight for daily) instead of "interval after last close"
+    pub align_to_epoch: bool,
+    
+    /// Time after a day starts beyond which anyone may force-close it
+    pub max_day_duration_secs: i64,
+    
+    /// Minimum delay (days) between proposing and applying economic policy changes
+    /// 0 = changes apply immediately via update_policy
+    pub policy_change_delay_days: u16,
+    
+    /// Proposed economic policy change waiting for its timelock
+    pub pending_change: Option<PendingPolicyChange>,
+    
+    /// Nominated creator waiting to accept authority
+    pub pending_creator: Option<Pubkey>,
+    
+    /// Payout ATA to switch to when the nominee accepts (None = keep current)
+    pub pending_creator_quote_ata: Option<Pubkey>,
+    
+    /// Optional key allowed to pause/unpause distributions alongside the creator
+    pub guardian: Option<Pubkey>,
+    
+    /// Distributions halted (crank rejected; progress is left untouched)
+    pub paused: bool,
+    
+    /// When distributions were last paused (paused time does not count toward a stale day)
+    pub paused_at: i64,
+    
+    /// Pools with a registered honorary position, in crank claim order
+    pub position_pools: Vec<Pubkey>,
+    
     /// PDA bump seed
     pub bump: u8,
 }
@@ -38,9 +135,86 @@ impl PolicyState {
         32 + // creator_wallet
         32 + // creator_quote_ata
         32 + // quote_mint
+        32 + // vesting_mint
+        32 + // allowed_stream_sender
         2 + // investor_fee_share_bps
         1 + 8 + // Option<u64> for daily_cap_lamports
         8 + // min_payout_lamports
+        1 + // cap_overflow_mode
+        2 + // crank_reward_bps
+        1 + 8 + // Option<u64> for crank_reward_cap_lamports
         8 + // y0_total_allocation
+        8 + // distribution_interval_secs
+        1 + // align_to_epoch
+        8 + // max_day_duration_secs
+        2 + // policy_change_delay_days
+        1 + PendingPolicyChange::LEN + // Option<PendingPolicyChange>
+        1 + 32 + // Option<Pubkey> pending_creator
+        1 + 32 + // Option<Pubkey> pending_creator_quote_ata
+        1 + 32 + // Option<Pubkey> guardian
+        1 + // paused
+        8 + // paused_at
+        4 + 32 * MAX_HONORARY_POSITIONS + // position_pools
         1; // bump
+    
+    /// Whether a new distribution day may start at `now`
+    ///
+    /// Unaligned: `distribution_interval_secs` after the last day closed.
+    /// Aligned: once per epoch-aligned window, based on when the last day started,
+    /// so start times do not drift later with each slow crank.
+    pub fn is_distribution_due(&self, progress: &ProgressState, now: i64) -> bool {
+        let interval = self.distribution_interval_secs.max(1);
+        
+        if self.align_to_epoch {
+            progress.current_day_ts == 0
+                || now.div_euclid(interval) > progress.current_day_ts.div_euclid(interval)
+        } else {
+            now >= progress.last_distribution_ts.saturating_add(interval)
+        }
+    }
+    
+    /// An unfinished day that outlived `max_day_duration_secs` may be force-closed
+    ///
+    /// Time the day spent paused is excluded, so a day paused past the maximum
+    /// duration still resumes from its cursor after unpause.
+    pub fn is_day_stale(&self, progress: &ProgressState, now: i64) -> bool {
+        !progress.day_complete
+            && now >= progress.current_day_ts
+                .saturating_add(progress.day_paused_secs)
+                .saturating_add(self.max_day_duration_secs)
+    }
+    
+    /// Creator or guardian may pause and unpause distributions
+    pub fn is_pause_authority(&self, key: &Pubkey) -> bool {
+        self.creator_wallet == *key || self.guardian == Some(*key)
+    }
+    
+    /// Apply changes to the economic fields, validating as initialize_policy does
+    pub fn apply_economic_change(
+        &mut self,
+        investor_fee_share_bps: Option<u16>,
+        daily_cap_lamports: Option<u64>,
+        clear_daily_cap: bool,
+        min_payout_lamports: Option<u64>,
+    ) -> Result<()> {
+        if let Some(investor_fee_share_bps) = investor_fee_share_bps {
+            require!(
+                investor_fee_share_bps <= MAX_BPS,
+                ErrorCode::InvalidFeeShareBps
+            );
+            self.investor_fee_share_bps = investor_fee_share_bps;
+        }
+        
+        if clear_daily_cap {
+            self.daily_cap_lamports = None;
+        } else if daily_cap_lamports.is_some() {
+            self.daily_cap_lamports = daily_cap_lamports;
+        }
+        
+        if let Some(min_payout_lamports) = min_payout_lamports {
+            self.min_payout_lamports = min_payout_lamports;
+        }
+        
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -1,32 +1,66 @@
 use anchor_lang::prelude::*;
 
+/// AMM the honorary position lives in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum AmmKind {
+    /// Raydium concentrated liquidity - quote-only by tick range
+    #[default]
+    RaydiumClmm,
+    
+    /// Meteora DAMM v2 - quote-only by the pool's `OnlyB` collect-fee mode
+    MeteoraDammV2,
+    
+    /// Orca Whirlpool - quote-only by tick range
+    OrcaWhirlpool,
+}
+
 #[account]
 #[derive(Default)]
 pub struct PositionState {
     /// Vault pubkey this position is associated with
     pub vault: Pubkey,
     
+    /// AMM the position was opened in
+    pub amm_kind: AmmKind,
+    
     /// CP-AMM pool ID
     pub pool_id: Pubkey,
     
     /// Position NFT mint address
     pub position_nft_mint: Pubkey,
     
-    /// CP-AMM position account ID
+    /// CP-AMM position account ID (protocol position on Raydium CLMM,
+    /// position account on DAMM v2 and Whirlpool)
     pub position_id: Pubkey,
     
-    /// Lower tick boundary
+    /// CP-AMM personal position account (tracks liquidity and owed fees; Raydium CLMM only)
+    pub personal_position: Pubkey,
+    
+    /// Tick array containing tick_lower (Raydium CLMM and Whirlpool)
+    pub tick_array_lower: Pubkey,
+    
+    /// Tick array containing tick_upper (Raydium CLMM and Whirlpool)
+    pub tick_array_upper: Pubkey,
+    
+    /// Lower tick boundary (Raydium CLMM and Whirlpool)
     pub tick_lower: i32,
     
-    /// Upper tick boundary
+    /// Upper tick boundary (Raydium CLMM and Whirlpool)
     pub tick_upper: i32,
     
     /// Position liquidity amount
     pub liquidity: u128,
     
+    /// Base token mint (the pool mint that is not the quote mint)
+    pub base_mint: Pubkey,
+    
+    /// Program base treasury for this pool (any base fee aborts the crank)
+    pub base_treasury: Pubkey,
+    
     /// Whether quote token is token0 in the pool
     /// If true: quote = token0, position must be ABOVE current price
     /// If false: quote = token1, position must be BELOW current price
+    /// Always false on DAMM v2, where the quote mint is token B
     pub is_quote_token_0: bool,
     
     /// PDA bump seed
@@ -36,12 +70,18 @@ pub struct PositionState {
 impl PositionState {
     pub const LEN: usize = 8 + // discriminator
         32 + // vault
+        1 + // amm_kind
         32 + // pool_id
         32 + // position_nft_mint
         32 + // position_id
+        32 + // personal_position
+        32 + // tick_array_lower
+        32 + // tick_array_upper
         4 + // tick_lower
         4 + // tick_upper
         16 + // liquidity (u128)
+        32 + // base_mint
+        32 + // base_treasury
         1 + // is_quote_token_0
         1; // bump
 }
\ No newline at end of file
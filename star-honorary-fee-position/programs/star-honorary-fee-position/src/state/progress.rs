@@ -12,13 +12,28 @@ pub struct ProgressState {
     /// Timestamp when current day's distribution started
     pub current_day_ts: i64,
     
+    /// Time the current day spent paused (excluded from the stale-day timeout)
+    pub day_paused_secs: i64,
+    
     /// Amount distributed to investors today (for daily cap tracking)
     pub daily_distributed: u64,
     
-    /// Dust amount carried over from previous pages/distributions
+    /// Quote fees claimed from the position on the first page of the current day
+    pub day_claimed_quote: u64,
+    
+    /// Investor share of the day's claimed quote, fixed when the locked snapshot completes
+    pub day_investor_pool_quote: u64,
+    
+    /// Global locked total across all investors, accumulated during the snapshot pass
+    pub day_locked_total: u64,
+    
+    /// Whether the snapshot pass finished and pages are now paying out
+    pub locked_snapshot_complete: bool,
+    
+    /// Dust amount carried over from previous pages of the current day
     pub carry_over_dust: u64,
     
-    /// Current pagination cursor (0 = new day, increments per page)
+    /// Current pagination cursor (0 = first page of a pass, increments per page)
     pub pagination_cursor: u32,
     
     /// Whether current day's distribution is complete
@@ -27,6 +42,25 @@ pub struct ProgressState {
     /// Total fees claimed from position over lifetime
     pub total_claimed_lifetime: u64,
     
+    /// Cap overflow owed to investors and paid on future days (Deferred mode)
+    pub deferred_investor_balance: u64,
+    
+    /// Deferred balance brought into the current day's investor pool
+    pub day_deferred_in: u64,
+    
+    /// Cap overflow withheld from the creator remainder for the current day
+    pub day_retained_quote: u64,
+    
+    /// Quote claimed outside a finished day (a force-closed snapshot pass or
+    /// fees settled when a position is removed), added to the next day's claim
+    pub rolled_over_claimed_quote: u64,
+    
+    /// Crank reward reserved from the current day's claim before the split
+    pub day_crank_reward_budget: u64,
+    
+    /// Crank reward paid out so far today (unpaid budget goes to the creator)
+    pub day_crank_reward_paid: u64,
+    
     /// PDA bump seed
     pub bump: u8,
 }
@@ -36,10 +70,50 @@ impl ProgressState {
         32 + // vault
         8 + // last_distribution_ts
         8 + // current_day_ts
+        8 + // day_paused_secs
         8 + // daily_distributed
+        8 + // day_claimed_quote
+        8 + // day_investor_pool_quote
+        8 + // day_locked_total
+        1 + // locked_snapshot_complete
         8 + // carry_over_dust
         4 + // pagination_cursor
         1 + // day_complete
         8 + // total_claimed_lifetime
+        8 + // deferred_investor_balance
+        8 + // day_deferred_in
+        8 + // day_retained_quote
+        8 + // rolled_over_claimed_quote
+        8 + // day_crank_reward_budget
+        8 + // day_crank_reward_paid
         1; // bump
+    
+    /// Exclude a pause lasting from `paused_at` to `now` from the current day
+    ///
+    /// Only an unfinished day is affected; a pause between days does not carry
+    /// over into the next one.
+    pub fn exclude_paused_time(&mut self, paused_at: i64, now: i64) {
+        if !self.day_complete {
+            self.day_paused_secs = self.day_paused_secs
+                .saturating_add(now.saturating_sub(paused_at).max(0));
+        }
+    }
+    
+    /// Reset the per-day fields for a day starting at `now`
+    ///
+    /// Lifetime totals and balances carried between days are kept.
+    pub fn start_day(&mut self, now: i64) {
+        self.current_day_ts = now;
+        self.day_paused_secs = 0;
+        self.daily_distributed = 0;
+        self.carry_over_dust = 0;
+        self.day_locked_total = 0;
+        self.day_investor_pool_quote = 0;
+        self.locked_snapshot_complete = false;
+        self.day_deferred_in = 0;
+        self.day_retained_quote = 0;
+        self.day_crank_reward_budget = 0;
+        self.day_crank_reward_paid = 0;
+        self.day_complete = false;
+    }
 }
\ No newline at end of file
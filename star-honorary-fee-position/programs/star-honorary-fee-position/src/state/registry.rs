@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_REGISTERED_INVESTORS;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct InvestorEntry {
+    /// Stream account holding the investor's locked tokens (any supported lock provider)
+    pub stream: Pubkey,
+    
+    /// Investor's quote token ATA receiving payouts
+    pub investor_quote_ata: Pubkey,
+}
+
+impl InvestorEntry {
+    pub const LEN: usize = 32 + // stream
+        32; // investor_quote_ata
+}
+
+#[account]
+#[derive(Default)]
+pub struct InvestorRegistry {
+    /// Vault pubkey this registry is associated with
+    pub vault: Pubkey,
+    
+    /// Number of investors processed per crank page
+    pub page_size: u8,
+    
+    /// Registered investors, in crank order
+    pub investors: Vec<InvestorEntry>,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InvestorRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        1 + // page_size
+        4 + InvestorEntry::LEN * MAX_REGISTERED_INVESTORS + // investors
+        1; // bump
+    
+    /// Number of pages in one pass over the registry (an empty registry still has one page)
+    pub fn page_count(&self) -> u32 {
+        let page_size = self.page_size.max(1) as usize;
+        self.investors.len().div_ceil(page_size).max(1) as u32
+    }
+    
+    /// Registry entries covered by page `cursor`
+    pub fn page(&self, cursor: u32) -> &[InvestorEntry] {
+        let page_size = self.page_size.max(1) as usize;
+        let start = (cursor as usize).saturating_mul(page_size).min(self.investors.len());
+        let end = start.saturating_add(page_size).min(self.investors.len());
+        &self.investors[start..end]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use crate::constants::{STREAMFLOW_MIN_VERSION, STREAMFLOW_PROGRAM_ID};
+use crate::errors::ErrorCode;
+
+// Streamflow `Contract` layout (no Anchor discriminator):
+// magic (8) | version (1) | created_at (8) | amount_withdrawn (8) | canceled_at (8) |
+// end_time (8) | last_withdrawn_at (8) | sender (32) | sender_tokens (32) | recipient (32) |
+// recipient_tokens (32) | mint (32) | escrow_tokens (32) | streamflow_treasury (32) |
+// streamflow_treasury_tokens (32) | streamflow_fee_total (8) | streamflow_fee_withdrawn (8) |
+// streamflow_fee_percent (4) | partner (32) | partner_tokens (32) | partner_fee_total (8) |
+// partner_fee_withdrawn (8) | partner_fee_percent (4) | ix: CreateParams {
+//   start_time (8) | net_amount_deposited (8) | period (8) | amount_per_period (8) |
+//   cliff (8) | cliff_amount (8) | ...
+// }
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 8;
+const AMOUNT_WITHDRAWN_OFFSET: usize = 17;
+const CANCELED_AT_OFFSET: usize = 25;
+const END_TIME_OFFSET: usize = 33;
+const SENDER_OFFSET: usize = 49;
+const RECIPIENT_OFFSET: usize = 113;
+const MINT_OFFSET: usize = 177;
+const START_TIME_OFFSET: usize = 409;
+const NET_AMOUNT_DEPOSITED_OFFSET: usize = 417;
+const PERIOD_OFFSET: usize = 425;
+const AMOUNT_PER_PERIOD_OFFSET: usize = 433;
+const CLIFF_OFFSET: usize = 441;
+const CLIFF_AMOUNT_OFFSET: usize = 449;
+const MIN_CONTRACT_LEN: usize = CLIFF_AMOUNT_OFFSET + 8;
+
+/// Parsed Streamflow program ID
+pub fn program_id() -> Pubkey {
+    Pubkey::from_str(STREAMFLOW_PROGRAM_ID).unwrap()
+}
+
+/// Fields of a Streamflow stream (contract) account needed to compute locked amounts
+#[derive(Debug, Clone, Default)]
+pub struct StreamflowContract {
+    pub magic: u64,
+    pub version: u8,
+    pub amount_withdrawn: u64,
+    pub canceled_at: u64,
+    pub end_time: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub start_time: u64,
+    pub net_amount_deposited: u64,
+    pub period: u64,
+    pub amount_per_period: u64,
+    pub cliff: u64,
+    pub cliff_amount: u64,
+}
+
+impl StreamflowContract {
+    /// Deserialize a stream account, checking owner program, layout and version
+    pub fn try_from_account(stream_account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *stream_account.owner,
+            program_id(),
+            ErrorCode::InvalidStreamflowData
+        );
+        
+        let data = stream_account.try_borrow_data()?;
+        Self::unpack(&data)
+    }
+    
+    /// Deserialize raw stream account data
+    pub fn unpack(data: &[u8]) -> Result<Self> {
+        require!(data.len() >= MIN_CONTRACT_LEN, ErrorCode::InvalidStreamflowData);
+        
+        let read_u64 = |offset: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+        
+        let contract = Self {
+            magic: read_u64(MAGIC_OFFSET),
+            version: data[VERSION_OFFSET],
+            amount_withdrawn: read_u64(AMOUNT_WITHDRAWN_OFFSET),
+            canceled_at: read_u64(CANCELED_AT_OFFSET),
+            end_time: read_u64(END_TIME_OFFSET),
+            sender: read_pubkey(SENDER_OFFSET),
+            recipient: read_pubkey(RECIPIENT_OFFSET),
+            mint: read_pubkey(MINT_OFFSET),
+            start_time: read_u64(START_TIME_OFFSET),
+            net_amount_deposited: read_u64(NET_AMOUNT_DEPOSITED_OFFSET),
+            period: read_u64(PERIOD_OFFSET),
+            amount_per_period: read_u64(AMOUNT_PER_PERIOD_OFFSET),
+            cliff: read_u64(CLIFF_OFFSET),
+            cliff_amount: read_u64(CLIFF_AMOUNT_OFFSET),
+        };
+        
+        // Uninitialized or unsupported contract versions are rejected
+        require!(
+            contract.magic != 0 && contract.version >= STREAMFLOW_MIN_VERSION,
+            ErrorCode::InvalidStreamflowData
+        );
+        
+        Ok(contract)
+    }
+    
+    /// Amount vested at `now` according to the cliff + periodic release schedule
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let now = now.max(0) as u64;
+        let cliff = if self.cliff > 0 { self.cliff } else { self.start_time };
+        
+        if now < cliff {
+            return 0;
+        }
+        
+        if now >= self.end_time || self.period == 0 {
+            return self.net_amount_deposited;
+        }
+        
+        let periods_elapsed = (now - cliff) / self.period;
+        let released = periods_elapsed.saturating_mul(self.amount_per_period);
+        
+        self.cliff_amount
+            .saturating_add(released)
+            .min(self.net_amount_deposited)
+    }
+    
+    /// Amount still locked at `now`
+    ///
+    /// locked = deposited - max(vested, withdrawn). Withdrawals can only come out
+    /// of vested tokens, so they reduce the locked amount only when they exceed
+    /// the schedule. A cancelled stream returns its unvested tokens to the sender and
+    /// therefore has nothing locked.
+    pub fn locked_amount(&self, now: i64) -> u64 {
+        if self.canceled_at > 0 {
+            return 0;
+        }
+        
+        let unlocked = self.vested_amount(now).max(self.amount_withdrawn);
+        self.net_amount_deposited.saturating_sub(unlocked)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+};
+use crate::errors::ErrorCode;
+
+/// Transfer fee config of a Token-2022 mint, if it has one
+fn transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+    
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)
+        .map_err(|_| error!(ErrorCode::InvalidMintAccount))?;
+    
+    Ok(mint_state.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Fee withheld when `amount` is transferred out of an account of `mint`
+///
+/// SPL Token mints and Token-2022 mints without the transfer fee extension
+/// charge nothing.
+pub fn calculate_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    match transfer_fee_config(mint)? {
+        Some(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow)),
+        None => Ok(0),
+    }
+}
+
+/// Amount the destination actually receives when `amount` is sent
+pub fn net_of_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let fee = calculate_transfer_fee(mint, amount)?;
+    amount.checked_sub(fee).ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+/// Move fees withheld in `account` to its mint so the account can be closed
+///
+/// No-op for mints without the transfer fee extension.
+pub fn harvest_withheld_to_mint<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+) -> Result<()> {
+    if transfer_fee_config(mint)?.is_none() {
+        return Ok(());
+    }
+    
+    let ix = spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        token_program.key,
+        mint.key,
+        &[account.key],
+    )?;
+    
+    invoke(&ix, &[mint.clone(), account.clone(), token_program.clone()])?;
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,467 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use std::str::FromStr;
+use crate::constants::WHIRLPOOL_PROGRAM_ID;
+use crate::cp_amm::{account_discriminator, instruction_discriminator};
+use crate::errors::ErrorCode;
+
+// Whirlpool PDA seeds
+const POSITION_SEED: &[u8] = b"position";
+
+// Whirlpool layout (after 8-byte discriminator):
+// whirlpools_config (32) | whirlpool_bump (1) | tick_spacing (2) | fee_tier_index_seed (2) |
+// fee_rate (2) | protocol_fee_rate (2) | liquidity (16) | sqrt_price (16) | tick_current_index (4) |
+// protocol_fee_owed_a (8) | protocol_fee_owed_b (8) | token_mint_a (32) | token_vault_a (32) |
+// fee_growth_global_a (16) | token_mint_b (32) | token_vault_b (32)
+const WHIRLPOOL_TICK_SPACING_OFFSET: usize = 8 + 32 + 1;
+const WHIRLPOOL_SQRT_PRICE_OFFSET: usize = WHIRLPOOL_TICK_SPACING_OFFSET + 2 + 2 + 2 + 2 + 16;
+const WHIRLPOOL_TICK_CURRENT_OFFSET: usize = WHIRLPOOL_SQRT_PRICE_OFFSET + 16;
+const WHIRLPOOL_TOKEN_MINT_A_OFFSET: usize = WHIRLPOOL_TICK_CURRENT_OFFSET + 4 + 8 + 8;
+const WHIRLPOOL_TOKEN_MINT_B_OFFSET: usize = WHIRLPOOL_TOKEN_MINT_A_OFFSET + 32 + 32 + 16;
+
+// Position layout (after 8-byte discriminator):
+// whirlpool (32) | position_mint (32) | liquidity (16) | tick_lower_index (4) |
+// tick_upper_index (4) | fee_growth_checkpoint_a (16) | fee_owed_a (8) |
+// fee_growth_checkpoint_b (16) | fee_owed_b (8)
+const POSITION_LIQUIDITY_OFFSET: usize = 8 + 32 + 32;
+const POSITION_FEE_OWED_A_OFFSET: usize = POSITION_LIQUIDITY_OFFSET + 16 + 4 + 4 + 16;
+const POSITION_FEE_OWED_B_OFFSET: usize = POSITION_FEE_OWED_A_OFFSET + 8 + 16;
+
+/// Parsed Whirlpool program ID
+pub fn program_id() -> Pubkey {
+    Pubkey::from_str(WHIRLPOOL_PROGRAM_ID).unwrap()
+}
+
+/// Whirlpool position account and bump for a position mint
+pub fn position_address(position_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POSITION_SEED, position_mint.as_ref()], &program_id())
+}
+
+/// Fields of a Whirlpool relevant to the honorary position
+#[derive(Debug, Clone)]
+pub struct PoolSnapshot {
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_spacing: u16,
+    pub sqrt_price: u128,
+    pub tick_current: i32,
+}
+
+/// Liquidity and owed fees recorded in a Whirlpool position
+#[derive(Debug, Clone, Default)]
+pub struct PositionSnapshot {
+    pub liquidity: u128,
+    pub fee_owed_a: u64,
+    pub fee_owed_b: u64,
+}
+
+#[derive(AnchorSerialize)]
+struct RemainingAccountsSlice {
+    accounts_type: u8,
+    length: u8,
+}
+
+#[derive(AnchorSerialize)]
+struct RemainingAccountsInfo {
+    slices: Vec<RemainingAccountsSlice>,
+}
+
+/// Accounts required by Whirlpool's `open_position` instruction, in CPI order
+pub struct OpenPosition<'a, 'info> {
+    pub funder: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_mint: &'a AccountInfo<'info>,
+    pub position_token_account: &'a AccountInfo<'info>,
+    pub whirlpool: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize)]
+struct OpenPositionArgs {
+    position_bump: u8,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+}
+
+/// CPI into Whirlpool `open_position`
+///
+/// Mints the position token into `owner`'s associated token account; the
+/// position starts without liquidity.
+pub fn open_position<'a, 'info>(
+    whirlpool_program: &'a AccountInfo<'info>,
+    accounts: OpenPosition<'a, 'info>,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Result<()> {
+    let (_, position_bump) = position_address(&accounts.position_mint.key());
+    let args = OpenPositionArgs {
+        position_bump,
+        tick_lower_index,
+        tick_upper_index,
+    };
+    
+    let mut data = instruction_discriminator("open_position").to_vec();
+    args.serialize(&mut data)?;
+    
+    let ix = Instruction {
+        program_id: whirlpool_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.funder.key(), true),
+            AccountMeta::new_readonly(accounts.owner.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new(accounts.position_mint.key(), true),
+            AccountMeta::new(accounts.position_token_account.key(), false),
+            AccountMeta::new_readonly(accounts.whirlpool.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+            AccountMeta::new_readonly(accounts.associated_token_program.key(), false),
+        ],
+        data,
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.funder.clone(),
+            accounts.owner.clone(),
+            accounts.position.clone(),
+            accounts.position_mint.clone(),
+            accounts.position_token_account.clone(),
+            accounts.whirlpool.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+            accounts.associated_token_program.clone(),
+            whirlpool_program.clone(),
+        ],
+        &[],
+    )?;
+    
+    Ok(())
+}
+
+/// Accounts shared by Whirlpool's `*_v2` liquidity and fee instructions, in CPI order
+pub struct PositionAccounts<'a, 'info> {
+    pub whirlpool: &'a AccountInfo<'info>,
+    pub token_program_a: &'a AccountInfo<'info>,
+    pub token_program_b: &'a AccountInfo<'info>,
+    pub memo_program: &'a AccountInfo<'info>,
+    pub position_authority: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_token_account: &'a AccountInfo<'info>,
+    pub token_mint_a: &'a AccountInfo<'info>,
+    pub token_mint_b: &'a AccountInfo<'info>,
+    pub token_owner_account_a: &'a AccountInfo<'info>,
+    pub token_vault_a: &'a AccountInfo<'info>,
+    pub token_owner_account_b: &'a AccountInfo<'info>,
+    pub token_vault_b: &'a AccountInfo<'info>,
+    pub tick_array_lower: &'a AccountInfo<'info>,
+    pub tick_array_upper: &'a AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize)]
+struct ModifyLiquidityArgs {
+    liquidity_amount: u128,
+    token_threshold_a: u64,
+    token_threshold_b: u64,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+/// Invoke `increase_liquidity_v2` / `decrease_liquidity_v2`, which share one account list
+fn modify_liquidity<'a, 'info>(
+    name: &str,
+    whirlpool_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    args: ModifyLiquidityArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = instruction_discriminator(name).to_vec();
+    args.serialize(&mut data)?;
+    
+    let ix = Instruction {
+        program_id: whirlpool_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.whirlpool.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_a.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_b.key(), false),
+            AccountMeta::new_readonly(accounts.memo_program.key(), false),
+            AccountMeta::new_readonly(accounts.position_authority.key(), true),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.position_token_account.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_a.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_b.key(), false),
+            AccountMeta::new(accounts.token_owner_account_a.key(), false),
+            AccountMeta::new(accounts.token_vault_a.key(), false),
+            AccountMeta::new(accounts.token_owner_account_b.key(), false),
+            AccountMeta::new(accounts.token_vault_b.key(), false),
+            AccountMeta::new(accounts.tick_array_lower.key(), false),
+            AccountMeta::new(accounts.tick_array_upper.key(), false),
+        ],
+        data,
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.whirlpool.clone(),
+            accounts.token_program_a.clone(),
+            accounts.token_program_b.clone(),
+            accounts.memo_program.clone(),
+            accounts.position_authority.clone(),
+            accounts.position.clone(),
+            accounts.position_token_account.clone(),
+            accounts.token_mint_a.clone(),
+            accounts.token_mint_b.clone(),
+            accounts.token_owner_account_a.clone(),
+            accounts.token_vault_a.clone(),
+            accounts.token_owner_account_b.clone(),
+            accounts.token_vault_b.clone(),
+            accounts.tick_array_lower.clone(),
+            accounts.tick_array_upper.clone(),
+            whirlpool_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// CPI into Whirlpool `increase_liquidity_v2`, signed by the position authority
+///
+/// The owner token accounts must let `position_authority` transfer up to the
+/// token maximums (owner or approved delegate).
+pub fn increase_liquidity<'a, 'info>(
+    whirlpool_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    liquidity: u128,
+    token_max_a: u64,
+    token_max_b: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    modify_liquidity(
+        "increase_liquidity_v2",
+        whirlpool_program,
+        accounts,
+        ModifyLiquidityArgs {
+            liquidity_amount: liquidity,
+            token_threshold_a: token_max_a,
+            token_threshold_b: token_max_b,
+            remaining_accounts_info: None,
+        },
+        signer_seeds,
+    )
+}
+
+/// CPI into Whirlpool `decrease_liquidity_v2`, signed by the position authority
+pub fn decrease_liquidity<'a, 'info>(
+    whirlpool_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    liquidity: u128,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    modify_liquidity(
+        "decrease_liquidity_v2",
+        whirlpool_program,
+        accounts,
+        ModifyLiquidityArgs {
+            liquidity_amount: liquidity,
+            token_threshold_a: 0,
+            token_threshold_b: 0,
+            remaining_accounts_info: None,
+        },
+        signer_seeds,
+    )
+}
+
+/// CPI into Whirlpool to collect the fees owed to a position
+///
+/// Whirlpool only accrues fees into a position when it is touched, so this
+/// runs `update_fees_and_rewards` before `collect_fees_v2`.
+pub fn collect_fees<'a, 'info>(
+    whirlpool_program: &'a AccountInfo<'info>,
+    accounts: PositionAccounts<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let update_ix = Instruction {
+        program_id: whirlpool_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.whirlpool.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.tick_array_lower.key(), false),
+            AccountMeta::new_readonly(accounts.tick_array_upper.key(), false),
+        ],
+        data: instruction_discriminator("update_fees_and_rewards").to_vec(),
+    };
+    
+    invoke_signed(
+        &update_ix,
+        &[
+            accounts.whirlpool.clone(),
+            accounts.position.clone(),
+            accounts.tick_array_lower.clone(),
+            accounts.tick_array_upper.clone(),
+            whirlpool_program.clone(),
+        ],
+        &[],
+    )?;
+    
+    let mut data = instruction_discriminator("collect_fees_v2").to_vec();
+    Option::<RemainingAccountsInfo>::None.serialize(&mut data)?;
+    
+    let collect_ix = Instruction {
+        program_id: whirlpool_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.whirlpool.key(), false),
+            AccountMeta::new_readonly(accounts.position_authority.key(), true),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.position_token_account.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_a.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_b.key(), false),
+            AccountMeta::new(accounts.token_owner_account_a.key(), false),
+            AccountMeta::new(accounts.token_vault_a.key(), false),
+            AccountMeta::new(accounts.token_owner_account_b.key(), false),
+            AccountMeta::new(accounts.token_vault_b.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_a.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_b.key(), false),
+            AccountMeta::new_readonly(accounts.memo_program.key(), false),
+        ],
+        data,
+    };
+    
+    invoke_signed(
+        &collect_ix,
+        &[
+            accounts.whirlpool.clone(),
+            accounts.position_authority.clone(),
+            accounts.position.clone(),
+            accounts.position_token_account.clone(),
+            accounts.token_mint_a.clone(),
+            accounts.token_mint_b.clone(),
+            accounts.token_owner_account_a.clone(),
+            accounts.token_vault_a.clone(),
+            accounts.token_owner_account_b.clone(),
+            accounts.token_vault_b.clone(),
+            accounts.token_program_a.clone(),
+            accounts.token_program_b.clone(),
+            accounts.memo_program.clone(),
+            whirlpool_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// Accounts required by Whirlpool's `close_position` instruction, in CPI order
+pub struct ClosePosition<'a, 'info> {
+    pub position_authority: &'a AccountInfo<'info>,
+    pub receiver: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_mint: &'a AccountInfo<'info>,
+    pub position_token_account: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+/// CPI into Whirlpool `close_position`
+///
+/// Burns the position token and closes the position, which must hold no
+/// liquidity and no owed fees. Rent goes to `receiver`.
+pub fn close_position<'a, 'info>(
+    whirlpool_program: &'a AccountInfo<'info>,
+    accounts: ClosePosition<'a, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: whirlpool_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.position_authority.key(), true),
+            AccountMeta::new(accounts.receiver.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new(accounts.position_mint.key(), false),
+            AccountMeta::new(accounts.position_token_account.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data: instruction_discriminator("close_position").to_vec(),
+    };
+    
+    invoke_signed(
+        &ix,
+        &[
+            accounts.position_authority.clone(),
+            accounts.receiver.clone(),
+            accounts.position.clone(),
+            accounts.position_mint.clone(),
+            accounts.position_token_account.clone(),
+            accounts.token_program.clone(),
+            whirlpool_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    
+    Ok(())
+}
+
+/// Read the liquidity and owed fees recorded in a Whirlpool position account
+pub fn read_position(position: &AccountInfo) -> Result<PositionSnapshot> {
+    require_keys_eq!(*position.owner, program_id(), ErrorCode::InvalidCpAmmAccount);
+    
+    let data = position.try_borrow_data()?;
+    require!(
+        data.len() >= POSITION_FEE_OWED_B_OFFSET + 8,
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let mut liquidity = [0u8; 16];
+    liquidity.copy_from_slice(&data[POSITION_LIQUIDITY_OFFSET..POSITION_LIQUIDITY_OFFSET + 16]);
+    let read_u64 = |offset: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&data[offset..offset + 8]);
+        u64::from_le_bytes(bytes)
+    };
+    
+    Ok(PositionSnapshot {
+        liquidity: u128::from_le_bytes(liquidity),
+        fee_owed_a: read_u64(POSITION_FEE_OWED_A_OFFSET),
+        fee_owed_b: read_u64(POSITION_FEE_OWED_B_OFFSET),
+    })
+}
+
+/// Deserialize the fields we need from a Whirlpool account
+pub fn read_pool(whirlpool: &AccountInfo) -> Result<PoolSnapshot> {
+    require_keys_eq!(*whirlpool.owner, program_id(), ErrorCode::InvalidCpAmmAccount);
+    
+    let data = whirlpool.try_borrow_data()?;
+    require!(
+        data.len() >= WHIRLPOOL_TOKEN_MINT_B_OFFSET + 64 && data[..8] == account_discriminator("Whirlpool"),
+        ErrorCode::InvalidCpAmmAccount
+    );
+    
+    let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+    
+    let mut tick_spacing = [0u8; 2];
+    tick_spacing.copy_from_slice(&data[WHIRLPOOL_TICK_SPACING_OFFSET..WHIRLPOOL_TICK_SPACING_OFFSET + 2]);
+    
+    let mut sqrt_price = [0u8; 16];
+    sqrt_price.copy_from_slice(&data[WHIRLPOOL_SQRT_PRICE_OFFSET..WHIRLPOOL_SQRT_PRICE_OFFSET + 16]);
+    
+    let mut tick_current = [0u8; 4];
+    tick_current.copy_from_slice(&data[WHIRLPOOL_TICK_CURRENT_OFFSET..WHIRLPOOL_TICK_CURRENT_OFFSET + 4]);
+    
+    Ok(PoolSnapshot {
+        token_mint_a: read_pubkey(WHIRLPOOL_TOKEN_MINT_A_OFFSET),
+        token_mint_b: read_pubkey(WHIRLPOOL_TOKEN_MINT_B_OFFSET),
+        token_vault_a: read_pubkey(WHIRLPOOL_TOKEN_MINT_A_OFFSET + 32),
+        token_vault_b: read_pubkey(WHIRLPOOL_TOKEN_MINT_B_OFFSET + 32),
+        tick_spacing: u16::from_le_bytes(tick_spacing),
+        sqrt_price: u128::from_le_bytes(sqrt_price),
+        tick_current: i32::from_le_bytes(tick_current),
+    })
+}
\ No newline at end of file
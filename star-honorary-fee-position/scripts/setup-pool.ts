@@ -14,6 +14,8 @@ import { StarHonoraryFeePosition } from "../target/types/star_honorary_fee_posit
 const VAULT_SEED = "vault";
 const POLICY_SEED = "policy";
 const PROGRESS_SEED = "progress";
+const REGISTRY_SEED = "registry";
+const CREATOR_SPLIT_SEED = "creator_split";
 const POSITION_STATE_SEED = "position_state";
 const POSITION_OWNER_SEED = "investor_fee_pos_owner";
 const TREASURY_SEED = "treasury";
@@ -86,6 +88,18 @@ async function main() {
   );
   console.log("  Progress PDA:", progressPda.toString());
 
+  const [registryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(VAULT_SEED), vault.publicKey.toBuffer(), Buffer.from(REGISTRY_SEED)],
+    program.programId
+  );
+  console.log("  Registry PDA:", registryPda.toString());
+
+  const [creatorSplitPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(VAULT_SEED), vault.publicKey.toBuffer(), Buffer.from(CREATOR_SPLIT_SEED)],
+    program.programId
+  );
+  console.log("  Creator Split PDA:", creatorSplitPda.toString());
+
   const [treasuryPda] = PublicKey.findProgramAddressSync(
     [Buffer.from(VAULT_SEED), vault.publicKey.toBuffer(), Buffer.from(TREASURY_SEED)],
     program.programId
@@ -104,11 +118,9 @@ async function main() {
   );
   console.log("  Position Owner PDA:", positionOwnerPda.toString());
 
-  const [positionStatePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from(VAULT_SEED), vault.publicKey.toBuffer(), Buffer.from(POSITION_STATE_SEED)],
-    program.programId
-  );
-  console.log("  Position State PDA:", positionStatePda.toString());
+  // Position state and base treasury PDAs are per pool:
+  // [VAULT_SEED, vault, POSITION_STATE_SEED, pool] / [VAULT_SEED, vault, BASE_TREASURY_SEED, pool]
+  console.log("  Position State PDAs: derived per registered pool");
   console.log("");
 
   // Step 5: Initialize policy
@@ -120,15 +132,28 @@ async function main() {
         investorFeeShareBps: 5000, // 50%
         dailyCapLamports: new anchor.BN(1_000_000_000),
         minPayoutLamports: new anchor.BN(10_000),
+        capOverflowMode: { creator: {} },
+        crankRewardBps: 0,
+        crankRewardCapLamports: null,
         y0TotalAllocation: new anchor.BN("10000000000"),
+        distributionIntervalSecs: new anchor.BN(86_400),
+        alignToEpoch: false,
+        maxDayDurationSecs: new anchor.BN(21_600),
+        investorsPerPage: 20,
+        policyChangeDelayDays: 7,
+        guardian: null,
+        allowedStreamSender: payer.publicKey, // funds the investor streams
       })
       .accounts({
         creator: payer.publicKey,
         vault: vault.publicKey,
         quoteMint: quoteMint,
+        vestingMint: baseMint,
         creatorQuoteAta: creatorQuoteAta,
         policy: policyPda,
         progress: progressPda,
+        registry: registryPda,
+        creatorSplit: creatorSplitPda,
         programQuoteTreasury: treasuryPda,
         treasuryAuthority: treasuryAuthority,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -173,7 +198,7 @@ async function main() {
   console.log("  Policy:", policyPda.toString());
   console.log("  Treasury:", treasuryPda.toString());
   console.log("");
-  console.log("Next: Initialize position with CP-AMM pool");
+  console.log("Next: Call initialize_position for each CP-AMM pool (up to 4 per vault)");
   console.log("");
 
   // Save config for other scripts
@@ -183,10 +208,11 @@ async function main() {
     baseMint: baseMint.toString(),
     policy: policyPda.toString(),
     progress: progressPda.toString(),
+    registry: registryPda.toString(),
+    creatorSplit: creatorSplitPda.toString(),
     treasury: treasuryPda.toString(),
     treasuryAuthority: treasuryAuthority.toString(),
     positionOwner: positionOwnerPda.toString(),
-    positionState: positionStatePda.toString(),
   };
 
   console.log("Config:", JSON.stringify(config, null, 2));
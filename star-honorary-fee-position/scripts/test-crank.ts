@@ -27,7 +27,6 @@ const CONFIG = {
   treasury: "",
   treasuryAuthority: "",
   positionOwner: "",
-  positionState: "",
 };
 
 async function main() {
@@ -51,36 +50,35 @@ async function main() {
   console.log("  - vault: The vault being distributed for");
   console.log("  - policy: Fee distribution policy");
   console.log("  - progress: Pagination state");
-  console.log("  - position_state: Honorary position info");
-  console.log("  - position_owner_pda: Owner of the position");
+  console.log("  - registry: Ordered investor registry");
+  console.log("  - creator_split: Creator remainder recipients");
+  console.log("  - position_owner_pda: Owner of every honorary position");
   console.log("  - program_quote_treasury: Treasury with fees");
   console.log("  - treasury_authority: Treasury signer");
   console.log("  - creator_quote_ata: Creator receives remainder");
-  console.log("  - pool_state: CP-AMM pool");
-  console.log("  - protocol_position: CP-AMM position");
-  console.log("  - position_nft_account: Position NFT");
-  console.log("  - cp_amm_program: Raydium CP-AMM program");
   console.log("  - token_program: SPL Token");
   console.log("  - clock: Clock sysvar");
   console.log("");
-  console.log("Remaining accounts (per investor):");
+  console.log("Remaining accounts, first page of a day only (one group per pool, in policy.position_pools order):");
+  console.log("  - position_state, amm_program, pool, program_base_treasury, base_mint");
+  console.log("  - the AMM adapter's position accounts (Raydium CLMM: 7, DAMM v2: 6, Whirlpool: 6)");
+  console.log("");
+  console.log("Remaining accounts (per registry entry on the current page, in order):");
   console.log("  - stream_account: Streamflow stream data");
   console.log("  - investor_quote_ata: Investor receives pro-rata share");
   console.log("");
 
-  console.log("Parameters:");
-  console.log("  - investor_count: Number of investors in this page (max 20)");
-  console.log("  - is_last_page: Whether this completes the day");
+  console.log("Parameters: none - page contents and last page come from the investor registry");
   console.log("");
 
   console.log("Distribution Logic:");
   console.log("  1. Check 24-hour gate (first page only)");
-  console.log("  2. Claim fees from CP-AMM position (quote only)");
-  console.log("  3. Read locked amounts from Streamflow");
+  console.log("  2. Claim fees from every registered position via its AMM adapter (quote only)");
+  console.log("  3. Snapshot pass: read locked amounts from Streamflow across all pages");
   console.log("  4. Calculate f_locked = locked_total / Y0");
-  console.log("  5. Investor share = min(policy_share, f_locked) * fees");
-  console.log("  6. Distribute pro-rata to investors");
-  console.log("  7. Send remainder to creator (last page)");
+  console.log("  5. Investor pool = min(policy_share, f_locked) * fees");
+  console.log("  6. Payout pass: distribute pro-rata against the global locked total");
+  console.log("  7. Send remainder to creator (last payout page)");
   console.log("");
 
   console.log("Example distribution calculation:");
@@ -111,7 +109,7 @@ async function main() {
   console.log("========================================");
   console.log("");
   console.log("To run actual crank:");
-  console.log("  1. Set up CP-AMM pool with position");
+  console.log("  1. Set up AMM pools and call initialize_position per pool");
   console.log("  2. Create Streamflow streams for investors");
   console.log("  3. Call crank_distribution with all accounts");
   console.log("");
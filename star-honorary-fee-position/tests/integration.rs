@@ -47,6 +47,9 @@ mod integration_tests {
         // Create quote mint
         let quote_mint = create_mint(&mut context, &creator, 6).await;
         
+        // Create vesting mint (the token locked in investor streams)
+        let vesting_mint = create_mint(&mut context, &creator, 6).await;
+        
         // Create creator's quote ATA
         let creator_quote_ata = create_token_account(
             &mut context,
@@ -76,6 +79,24 @@ mod integration_tests {
             &star_honorary_fee_position::ID,
         );
         
+        let (registry_pda, _) = Pubkey::find_program_address(
+            &[
+                VAULT_SEED,
+                vault.pubkey().as_ref(),
+                REGISTRY_SEED,
+            ],
+            &star_honorary_fee_position::ID,
+        );
+        
+        let (creator_split_pda, _) = Pubkey::find_program_address(
+            &[
+                VAULT_SEED,
+                vault.pubkey().as_ref(),
+                CREATOR_SPLIT_SEED,
+            ],
+            &star_honorary_fee_position::ID,
+        );
+        
         let (treasury_pda, _) = Pubkey::find_program_address(
             &[
                 VAULT_SEED,
@@ -100,16 +121,29 @@ mod integration_tests {
             investor_fee_share_bps: 5000, // 50%
             daily_cap_lamports: Some(1_000_000_000),
             min_payout_lamports: 10_000,
+            cap_overflow_mode: CapOverflowMode::Creator,
+            crank_reward_bps: 0,
+            crank_reward_cap_lamports: None,
             y0_total_allocation: 10_000_000_000,
+            distribution_interval_secs: SECONDS_PER_DAY,
+            align_to_epoch: false,
+            max_day_duration_secs: 6 * 3_600,
+            investors_per_page: 20,
+            policy_change_delay_days: 7,
+            guardian: None,
+            allowed_stream_sender: creator.pubkey(),
         };
         
         let accounts = vec![
             AccountMeta::new(creator.pubkey(), true),
             AccountMeta::new_readonly(vault.pubkey(), false),
             AccountMeta::new_readonly(quote_mint, false),
+            AccountMeta::new_readonly(vesting_mint, false),
             AccountMeta::new_readonly(creator_quote_ata, false),
             AccountMeta::new(policy_pda, false),
             AccountMeta::new(progress_pda, false),
+            AccountMeta::new(registry_pda, false),
+            AccountMeta::new(creator_split_pda, false),
             AccountMeta::new(treasury_pda, false),
             AccountMeta::new_readonly(treasury_authority, false),
             AccountMeta::new_readonly(token::ID, false),
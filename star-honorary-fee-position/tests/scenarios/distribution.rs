@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+    use star_honorary_fee_position::constants::SECONDS_PER_DAY;
+    use star_honorary_fee_position::errors::ErrorCode;
+    use star_honorary_fee_position::events::{CreatorPayoutDayClosed, InvestorPayoutPage};
+    use star_honorary_fee_position::state::CapOverflowMode;
+    use star_honorary_fee_position::streamflow::{self, StreamflowContract};
+    use anchor_lang::prelude::Pubkey;
+    
+    const HOUR: i64 = 3_600;
+    
+    #[test]
+    fn test_rolling_interval_gate() {
+        let mut harness = Harness::new();
+        let vault = TestVault::with_params(&mut harness, |params| {
+            params.distribution_interval_secs = 6 * HOUR;
+        });
+        let cranker = Pubkey::new_unique();
+        vault.add_investor(&mut harness, 100_000);
+        
+        // First distribution is due straight away
+        vault.crank_day(&mut harness, cranker).unwrap();
+        let closed_at = vault.progress_state(&harness).last_distribution_ts;
+        
+        harness.warp_to(closed_at + 6 * HOUR - 1);
+        let result = vault.crank_page(&mut harness, cranker);
+        assert_eq!(result, Err(program_error(ErrorCode::TooEarlyForDistribution)));
+        
+        harness.warp_to(closed_at + 6 * HOUR);
+        vault.crank_day(&mut harness, cranker).unwrap();
+        assert_eq!(vault.progress_state(&harness).current_day_ts, closed_at + 6 * HOUR);
+    }
+    
+    #[test]
+    fn test_epoch_aligned_gate_does_not_drift() {
+        let mut harness = Harness::new();
+        let vault = TestVault::with_params(&mut harness, |params| {
+            params.align_to_epoch = true;
+        });
+        let cranker = Pubkey::new_unique();
+        for _ in 0..3 {
+            vault.add_investor(&mut harness, 100_000);
+        }
+        
+        // Day starts at 23:00 UTC and its last page only lands at 00:30
+        let midnight = (harness.now() / SECONDS_PER_DAY + 1) * SECONDS_PER_DAY;
+        harness.warp_to(midnight - HOUR);
+        vault.crank_page(&mut harness, cranker).unwrap();
+        vault.crank_page(&mut harness, cranker).unwrap();
+        vault.crank_page(&mut harness, cranker).unwrap();
+        harness.warp_to(midnight + HOUR / 2);
+        vault.crank_page(&mut harness, cranker).unwrap();
+        assert!(vault.progress_state(&harness).day_complete);
+        
+        // The next UTC day already opened at midnight
+        vault.crank_page(&mut harness, cranker).unwrap();
+        assert_eq!(vault.progress_state(&harness).current_day_ts, midnight + HOUR / 2);
+        vault.crank_day(&mut harness, cranker).unwrap();
+        
+        // ...and the one after opens at the following midnight, not 24h after 00:30
+        harness.warp_to(midnight + SECONDS_PER_DAY - 1);
+        let result = vault.crank_page(&mut harness, cranker);
+        assert_eq!(result, Err(program_error(ErrorCode::TooEarlyForDistribution)));
+        harness.warp_to(midnight + SECONDS_PER_DAY);
+        vault.crank_page(&mut harness, cranker).unwrap();
+        assert_eq!(vault.progress_state(&harness).current_day_ts, midnight + SECONDS_PER_DAY);
+    }
+    
+    /// Vault with a 300k daily cap and one investor holding half of Y0 locked
+    fn capped_vault(harness: &mut Harness, mode: CapOverflowMode) -> (TestVault, TestInvestor) {
+        let vault = TestVault::with_params(harness, |params| {
+            params.daily_cap_lamports = Some(300_000);
+            params.cap_overflow_mode = mode;
+        });
+        let investor = vault.add_investor(harness, 500_000);
+        (vault, investor)
+    }
+    
+    /// Claim `amount` and crank a whole day, one interval after the last
+    fn distribute(harness: &mut Harness, vault: &TestVault, amount: u64) {
+        harness.warp_by(SECONDS_PER_DAY);
+        vault.seed_claim(harness, amount);
+        vault.crank_day(harness, Pubkey::new_unique()).unwrap();
+    }
+    
+    #[test]
+    fn test_cap_overflow_to_creator() {
+        let mut harness = Harness::new();
+        let (vault, investor) = capped_vault(&mut harness, CapOverflowMode::Creator);
+        
+        // 500k eligible, capped at 300k; the 200k overflow goes to the creator today
+        distribute(&mut harness, &vault, 1_000_000);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 700_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 0);
+    }
+    
+    #[test]
+    fn test_deferred_cap_overflow_paid_on_later_day() {
+        let mut harness = Harness::new();
+        let (vault, investor) = capped_vault(&mut harness, CapOverflowMode::Deferred);
+        
+        // The 200k overflow stays in the treasury, owed to investors
+        distribute(&mut harness, &vault, 1_000_000);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 500_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 200_000);
+        assert_eq!(vault.progress_state(&harness).deferred_investor_balance, 200_000);
+        
+        // Next day: 100k eligible plus the 200k deferred fills the cap exactly
+        distribute(&mut harness, &vault, 200_000);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 600_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 600_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 0);
+        assert_eq!(vault.progress_state(&harness).deferred_investor_balance, 0);
+    }
+    
+    #[test]
+    fn test_treasury_cap_overflow_is_retained() {
+        let mut harness = Harness::new();
+        let (vault, investor) = capped_vault(&mut harness, CapOverflowMode::Treasury);
+        
+        distribute(&mut harness, &vault, 1_000_000);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 500_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 200_000);
+        
+        // Retained overflow is not owed to anyone: later days ignore it
+        distribute(&mut harness, &vault, 200_000);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 400_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 600_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 200_000);
+        assert_eq!(vault.progress_state(&harness).deferred_investor_balance, 0);
+        
+        // ...until close_vault sweeps it to the creator
+        vault.close_vault(&mut harness, Pubkey::new_unique(), true, &[]).unwrap();
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 800_000);
+    }
+    
+    #[test]
+    fn test_token_2022_payouts_are_net_of_transfer_fee() {
+        let mut harness = Harness::new();
+        let quote_mint = Pubkey::new_unique();
+        harness.add_transfer_fee_mint(quote_mint, QUOTE_DECIMALS, 100, u64::MAX);
+        let vault = TestVault::with_quote_mint(&mut harness, quote_mint, |params| {
+            params.min_payout_lamports = 1_000;
+        });
+        assert_eq!(vault.quote_token_program, anchor_spl::token_2022::ID);
+        
+        // 101k locked of 1M -> 101k pool: shares of 100k and 1k before the 1% fee
+        let large = vault.add_investor(&mut harness, 100_000);
+        let small = vault.add_investor(&mut harness, 1_000);
+        vault.seed_claim(&mut harness, 1_000_000);
+        vault.crank_day(&mut harness, Pubkey::new_unique()).unwrap();
+        
+        // The small share clears min_payout gross (1_000) but not net (990), so it is dust
+        assert_eq!(harness.token_balance(&large.quote_ata), 99_000);
+        assert_eq!(harness.token_balance(&small.quote_ata), 0);
+        
+        let payouts = harness.events::<InvestorPayoutPage>();
+        assert_eq!(payouts.len(), 1);
+        assert_eq!((payouts[0].amount, payouts[0].net_amount), (100_000, 99_000));
+        
+        // The creator gets the rest, dust included, also net of the fee
+        let closed = harness.events::<CreatorPayoutDayClosed>();
+        assert_eq!((closed[0].amount, closed[0].net_amount), (900_000, 891_000));
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 891_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 0);
+        assert_eq!(vault.progress_state(&harness).daily_distributed, 100_000);
+    }
+    
+    #[test]
+    fn test_investor_ata_must_belong_to_stream_beneficiary() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let cranker = Pubkey::new_unique();
+        
+        // Registration checks the ATA owner against the stream recipient
+        let wallet = Pubkey::new_unique();
+        let contract = vault.locked_stream(&harness, wallet, 100_000);
+        let stream = Pubkey::new_unique();
+        let foreign_ata = Pubkey::new_unique();
+        harness.set_data(stream, streamflow::program_id(), stream_account_data(&contract));
+        vault.add_quote_account(&mut harness, foreign_ata, Pubkey::new_unique());
+        let result = vault.register_investor(&mut harness, stream, foreign_ata);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorAta)));
+        
+        let investor = vault.add_investor_with_stream(&mut harness, wallet, &contract);
+        vault.seed_claim(&mut harness, 1_000_000);
+        
+        // The stream is later transferred to someone else: the registered ATA no longer qualifies
+        let transferred = StreamflowContract { recipient: Pubkey::new_unique(), ..contract.clone() };
+        harness.set_data(investor.stream, streamflow::program_id(), stream_account_data(&transferred));
+        let result = vault.crank_page(&mut harness, cranker);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorAta)));
+        harness.set_data(investor.stream, streamflow::program_id(), stream_account_data(&contract));
+        
+        // The ATA is closed and re-created for another mint, or as a non-token account
+        let other_mint = Pubkey::new_unique();
+        harness.add_mint(other_mint, QUOTE_DECIMALS);
+        harness.add_token_account(investor.quote_ata, other_mint, wallet, 0);
+        let result = vault.crank_page(&mut harness, cranker);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorAta)));
+        
+        harness.add_wallet(investor.quote_ata, 1_000_000);
+        let result = vault.crank_page(&mut harness, cranker);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorAta)));
+        
+        // Back to the beneficiary's quote account, the day goes through
+        vault.add_quote_account(&mut harness, investor.quote_ata, wallet);
+        vault.crank_day(&mut harness, cranker).unwrap();
+        assert_eq!(harness.token_balance(&investor.quote_ata), 100_000);
+    }
+}
\ No newline at end of file
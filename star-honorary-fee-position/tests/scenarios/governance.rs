@@ -0,0 +1,484 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+    use star_honorary_fee_position::constants::SECONDS_PER_DAY;
+    use star_honorary_fee_position::errors::ErrorCode;
+    use star_honorary_fee_position::events::{
+        CreatorAccepted, PolicyChangeApplied, PolicyChangeCancelled, PolicyUpdated, VaultPaused,
+        VaultUnpaused,
+    };
+    use star_honorary_fee_position::instructions::{
+        NominateCreatorParams, ProposePolicyChangeParams, UpdatePolicyParams,
+    };
+    use star_honorary_fee_position::{accounts, instruction};
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::solana_program::program_error::ProgramError;
+    use anchor_lang::solana_program::sysvar;
+    
+    fn no_update() -> UpdatePolicyParams {
+        UpdatePolicyParams {
+            investor_fee_share_bps: None,
+            daily_cap_lamports: None,
+            clear_daily_cap: false,
+            min_payout_lamports: None,
+        }
+    }
+    
+    fn update_policy(
+        harness: &mut Harness,
+        vault: &TestVault,
+        signer: Pubkey,
+        params: UpdatePolicyParams,
+        new_creator_quote_ata: Option<Pubkey>,
+    ) -> Result<(), ProgramError> {
+        harness.process(
+            accounts::UpdatePolicy {
+                creator: signer,
+                vault: vault.vault,
+                policy: vault.policy,
+                progress: vault.progress,
+                new_creator_quote_ata,
+            },
+            vec![],
+            instruction::UpdatePolicy { params },
+        )
+    }
+    
+    /// Leave the vault part-way through a day (first of two snapshot pages sent)
+    fn start_day(harness: &mut Harness, vault: &TestVault) {
+        for _ in 0..3 {
+            vault.add_investor(harness, 100_000);
+        }
+        vault.seed_claim(harness, 1_000_000);
+        vault.crank_page(harness, Pubkey::new_unique()).unwrap();
+        assert!(!vault.progress_state(harness).day_complete);
+    }
+    
+    #[test]
+    fn test_update_policy_creator_only() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let params = || UpdatePolicyParams { investor_fee_share_bps: Some(2500), ..no_update() };
+        
+        let result = update_policy(&mut harness, &vault, Pubkey::new_unique(), params(), None);
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedCreator)));
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 5000);
+        
+        update_policy(&mut harness, &vault, vault.creator, params(), None).unwrap();
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 2500);
+    }
+    
+    #[test]
+    fn test_update_policy_rejected_mid_day() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        start_day(&mut harness, &vault);
+        
+        let params = UpdatePolicyParams { min_payout_lamports: Some(1_000), ..no_update() };
+        let result = update_policy(&mut harness, &vault, vault.creator, params, None);
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionNotComplete)));
+        
+        // Nor may the payout ATA move while the day's remainder is still owed
+        let new_ata = Pubkey::new_unique();
+        vault.add_quote_account(&mut harness, new_ata, vault.creator);
+        let result = update_policy(&mut harness, &vault, vault.creator, no_update(), Some(new_ata));
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionNotComplete)));
+        assert_eq!(vault.policy_state(&harness).creator_quote_ata, vault.creator_quote_ata);
+    }
+    
+    #[test]
+    fn test_update_policy_validates_fee_share() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        
+        let params = UpdatePolicyParams { investor_fee_share_bps: Some(10_001), ..no_update() };
+        let result = update_policy(&mut harness, &vault, vault.creator, params, None);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidFeeShareBps)));
+        
+        // MAX_BPS itself is allowed
+        let params = UpdatePolicyParams { investor_fee_share_bps: Some(10_000), ..no_update() };
+        update_policy(&mut harness, &vault, vault.creator, params, None).unwrap();
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 10_000);
+    }
+    
+    #[test]
+    fn test_update_policy_emits_old_and_new_values() {
+        let mut harness = Harness::new();
+        let vault = TestVault::with_params(&mut harness, |params| {
+            params.daily_cap_lamports = Some(500_000);
+            params.min_payout_lamports = 100;
+        });
+        let new_ata = Pubkey::new_unique();
+        vault.add_quote_account(&mut harness, new_ata, vault.creator);
+        
+        // Only the fields that are set change
+        let params = UpdatePolicyParams {
+            investor_fee_share_bps: Some(3000),
+            clear_daily_cap: true,
+            ..no_update()
+        };
+        update_policy(&mut harness, &vault, vault.creator, params, Some(new_ata)).unwrap();
+        
+        let updated = harness.events::<PolicyUpdated>();
+        assert_eq!(updated.len(), 1);
+        let event = &updated[0];
+        assert_eq!(event.vault, vault.vault);
+        assert_eq!((event.old_investor_fee_share_bps, event.new_investor_fee_share_bps), (5000, 3000));
+        assert_eq!((event.old_daily_cap_lamports, event.new_daily_cap_lamports), (Some(500_000), None));
+        assert_eq!((event.old_min_payout_lamports, event.new_min_payout_lamports), (100, 100));
+        assert_eq!((event.old_creator_quote_ata, event.new_creator_quote_ata), (vault.creator_quote_ata, new_ata));
+        
+        let policy = vault.policy_state(&harness);
+        assert_eq!(policy.investor_fee_share_bps, 3000);
+        assert_eq!(policy.daily_cap_lamports, None);
+        assert_eq!(policy.creator_quote_ata, new_ata);
+    }
+    
+    fn propose(
+        harness: &mut Harness,
+        vault: &TestVault,
+        signer: Pubkey,
+        investor_fee_share_bps: u16,
+        effective_ts: i64,
+    ) -> Result<(), ProgramError> {
+        harness.process(
+            accounts::ProposePolicyChange {
+                creator: signer,
+                vault: vault.vault,
+                policy: vault.policy,
+                clock: sysvar::clock::ID,
+            },
+            vec![],
+            instruction::ProposePolicyChange {
+                params: ProposePolicyChangeParams {
+                    investor_fee_share_bps: Some(investor_fee_share_bps),
+                    daily_cap_lamports: None,
+                    clear_daily_cap: false,
+                    min_payout_lamports: None,
+                    effective_ts,
+                },
+            },
+        )
+    }
+    
+    fn apply(harness: &mut Harness, vault: &TestVault) -> Result<(), ProgramError> {
+        harness.process(
+            accounts::ApplyPolicyChange {
+                vault: vault.vault,
+                policy: vault.policy,
+                progress: vault.progress,
+                clock: sysvar::clock::ID,
+            },
+            vec![],
+            instruction::ApplyPolicyChange {},
+        )
+    }
+    
+    fn cancel(harness: &mut Harness, vault: &TestVault, signer: Pubkey) -> Result<(), ProgramError> {
+        harness.process(
+            accounts::CancelPolicyChange {
+                creator: signer,
+                vault: vault.vault,
+                policy: vault.policy,
+            },
+            vec![],
+            instruction::CancelPolicyChange {},
+        )
+    }
+    
+    /// Vault whose economic changes wait two days
+    fn timelocked_vault(harness: &mut Harness) -> TestVault {
+        TestVault::with_params(harness, |params| params.policy_change_delay_days = 2)
+    }
+    
+    #[test]
+    fn test_propose_before_delay_is_rejected() {
+        let mut harness = Harness::new();
+        let vault = timelocked_vault(&mut harness);
+        let earliest = harness.now() + 2 * SECONDS_PER_DAY;
+        
+        let result = propose(&mut harness, &vault, vault.creator, 3000, earliest - 1);
+        assert_eq!(result, Err(program_error(ErrorCode::PolicyChangeTooEarly)));
+        assert!(vault.policy_state(&harness).pending_change.is_none());
+        
+        // Only the creator may propose
+        let result = propose(&mut harness, &vault, Pubkey::new_unique(), 3000, earliest);
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedCreator)));
+        
+        propose(&mut harness, &vault, vault.creator, 3000, earliest).unwrap();
+        let pending = vault.policy_state(&harness).pending_change.unwrap();
+        assert_eq!(pending.effective_ts, earliest);
+        assert_eq!(pending.investor_fee_share_bps, Some(3000));
+        
+        // One proposal at a time
+        let result = propose(&mut harness, &vault, vault.creator, 2000, earliest);
+        assert_eq!(result, Err(program_error(ErrorCode::PolicyChangeAlreadyPending)));
+    }
+    
+    #[test]
+    fn test_apply_waits_for_maturity() {
+        let mut harness = Harness::new();
+        let vault = timelocked_vault(&mut harness);
+        let effective_ts = harness.now() + 2 * SECONDS_PER_DAY;
+        propose(&mut harness, &vault, vault.creator, 3000, effective_ts).unwrap();
+        
+        harness.warp_to(effective_ts - 1);
+        assert_eq!(apply(&mut harness, &vault), Err(program_error(ErrorCode::PolicyChangeNotMatured)));
+        assert!(vault.policy_state(&harness).pending_change.is_some());
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 5000);
+        
+        // Anyone can apply a matured change
+        harness.warp_to(effective_ts);
+        apply(&mut harness, &vault).unwrap();
+        let policy = vault.policy_state(&harness);
+        assert!(policy.pending_change.is_none());
+        assert_eq!(policy.investor_fee_share_bps, 3000);
+        
+        let applied = harness.events::<PolicyChangeApplied>();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].applied_ts, effective_ts);
+        assert_eq!(harness.events::<PolicyUpdated>()[0].old_investor_fee_share_bps, 5000);
+    }
+    
+    #[test]
+    fn test_apply_waits_for_the_day_to_complete() {
+        let mut harness = Harness::new();
+        let vault = timelocked_vault(&mut harness);
+        let effective_ts = harness.now() + 2 * SECONDS_PER_DAY;
+        propose(&mut harness, &vault, vault.creator, 3000, effective_ts).unwrap();
+        
+        harness.warp_to(effective_ts);
+        start_day(&mut harness, &vault);
+        assert_eq!(apply(&mut harness, &vault), Err(program_error(ErrorCode::DistributionNotComplete)));
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 5000);
+        
+        // The day's rate is kept until its last page, then the change lands
+        while !vault.progress_state(&harness).day_complete {
+            vault.crank_page(&mut harness, Pubkey::new_unique()).unwrap();
+        }
+        apply(&mut harness, &vault).unwrap();
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 3000);
+    }
+    
+    #[test]
+    fn test_cancel_is_creator_only() {
+        let mut harness = Harness::new();
+        let vault = timelocked_vault(&mut harness);
+        let effective_ts = harness.now() + 2 * SECONDS_PER_DAY;
+        
+        assert_eq!(cancel(&mut harness, &vault, vault.creator), Err(program_error(ErrorCode::NoPendingPolicyChange)));
+        propose(&mut harness, &vault, vault.creator, 3000, effective_ts).unwrap();
+        
+        let result = cancel(&mut harness, &vault, Pubkey::new_unique());
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedCreator)));
+        assert!(vault.policy_state(&harness).pending_change.is_some());
+        
+        cancel(&mut harness, &vault, vault.creator).unwrap();
+        assert!(vault.policy_state(&harness).pending_change.is_none());
+        assert_eq!(harness.events::<PolicyChangeCancelled>()[0].effective_ts, effective_ts);
+        
+        // A cancelled change can no longer be applied
+        harness.warp_to(effective_ts);
+        assert_eq!(apply(&mut harness, &vault), Err(program_error(ErrorCode::NoPendingPolicyChange)));
+        assert_eq!(vault.policy_state(&harness).investor_fee_share_bps, 5000);
+    }
+    
+    #[test]
+    fn test_update_policy_refused_with_timelock() {
+        let mut harness = Harness::new();
+        let vault = timelocked_vault(&mut harness);
+        
+        let params = UpdatePolicyParams { investor_fee_share_bps: Some(3000), ..no_update() };
+        let result = update_policy(&mut harness, &vault, vault.creator, params, None);
+        assert_eq!(result, Err(program_error(ErrorCode::PolicyChangeRequiresTimelock)));
+        
+        let params = UpdatePolicyParams { clear_daily_cap: true, ..no_update() };
+        let result = update_policy(&mut harness, &vault, vault.creator, params, None);
+        assert_eq!(result, Err(program_error(ErrorCode::PolicyChangeRequiresTimelock)));
+        
+        // Moving the payout ATA is not an economic change
+        let new_ata = Pubkey::new_unique();
+        vault.add_quote_account(&mut harness, new_ata, vault.creator);
+        update_policy(&mut harness, &vault, vault.creator, no_update(), Some(new_ata)).unwrap();
+        assert_eq!(vault.policy_state(&harness).creator_quote_ata, new_ata);
+    }
+    
+    fn nominate(
+        harness: &mut Harness,
+        vault: &TestVault,
+        new_creator: Pubkey,
+        new_creator_quote_ata: Option<Pubkey>,
+    ) -> Result<(), ProgramError> {
+        harness.process(
+            accounts::NominateCreator {
+                creator: vault.creator,
+                vault: vault.vault,
+                policy: vault.policy,
+                new_creator_quote_ata,
+            },
+            vec![],
+            instruction::NominateCreator {
+                params: NominateCreatorParams { new_creator },
+            },
+        )
+    }
+    
+    fn accept(harness: &mut Harness, vault: &TestVault, signer: Pubkey) -> Result<(), ProgramError> {
+        harness.process(
+            accounts::AcceptCreator {
+                new_creator: signer,
+                vault: vault.vault,
+                policy: vault.policy,
+            },
+            vec![],
+            instruction::AcceptCreator {},
+        )
+    }
+    
+    #[test]
+    fn test_accept_by_non_nominee_is_rejected() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let nominee = Pubkey::new_unique();
+        
+        assert_eq!(accept(&mut harness, &vault, nominee), Err(program_error(ErrorCode::NoPendingCreator)));
+        nominate(&mut harness, &vault, nominee, None).unwrap();
+        
+        let result = accept(&mut harness, &vault, Pubkey::new_unique());
+        assert_eq!(result, Err(program_error(ErrorCode::NotNominatedCreator)));
+        let result = accept(&mut harness, &vault, vault.creator);
+        assert_eq!(result, Err(program_error(ErrorCode::NotNominatedCreator)));
+        
+        let policy = vault.policy_state(&harness);
+        assert_eq!(policy.creator_wallet, vault.creator);
+        assert_eq!(policy.pending_creator, Some(nominee));
+        
+        // Only the current creator nominates
+        let outsider = TestVault { creator: Pubkey::new_unique(), ..vault };
+        let result = nominate(&mut harness, &outsider, outsider.creator, None);
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedCreator)));
+    }
+    
+    #[test]
+    fn test_renomination_replaces_pending_nominee() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let first_ata = Pubkey::new_unique();
+        vault.add_quote_account(&mut harness, first_ata, first);
+        
+        nominate(&mut harness, &vault, first, Some(first_ata)).unwrap();
+        nominate(&mut harness, &vault, second, None).unwrap();
+        
+        // The first nominee and their ATA are gone
+        let policy = vault.policy_state(&harness);
+        assert_eq!(policy.pending_creator, Some(second));
+        assert_eq!(policy.pending_creator_quote_ata, None);
+        assert_eq!(accept(&mut harness, &vault, first), Err(program_error(ErrorCode::NotNominatedCreator)));
+        
+        accept(&mut harness, &vault, second).unwrap();
+        let policy = vault.policy_state(&harness);
+        assert_eq!(policy.creator_wallet, second);
+        assert_eq!(policy.pending_creator, None);
+        
+        // The old creator lost its rights
+        let result = update_policy(&mut harness, &vault, vault.creator, no_update(), None);
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedCreator)));
+    }
+    
+    #[test]
+    fn test_handover_carries_payout_ata_only_when_nominated() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        
+        // Without a nominated ATA the payout ATA stays where it was
+        let first = Pubkey::new_unique();
+        nominate(&mut harness, &vault, first, None).unwrap();
+        accept(&mut harness, &vault, first).unwrap();
+        let policy = vault.policy_state(&harness);
+        assert_eq!(policy.creator_wallet, first);
+        assert_eq!(policy.creator_quote_ata, vault.creator_quote_ata);
+        
+        // The nominee's ATA must belong to the nominee
+        let handed_over = TestVault { creator: first, ..vault };
+        let second = Pubkey::new_unique();
+        let second_ata = Pubkey::new_unique();
+        let foreign_ata = Pubkey::new_unique();
+        vault.add_quote_account(&mut harness, second_ata, second);
+        vault.add_quote_account(&mut harness, foreign_ata, first);
+        let result = nominate(&mut harness, &handed_over, second, Some(foreign_ata));
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidTokenAccountOwner)));
+        
+        nominate(&mut harness, &handed_over, second, Some(second_ata)).unwrap();
+        accept(&mut harness, &vault, second).unwrap();
+        let policy = vault.policy_state(&harness);
+        assert_eq!(policy.creator_wallet, second);
+        assert_eq!(policy.creator_quote_ata, second_ata);
+        assert_eq!(policy.pending_creator_quote_ata, None);
+        
+        let accepted = harness.events::<CreatorAccepted>();
+        assert_eq!((accepted[0].old_creator, accepted[0].new_creator), (first, second));
+        assert_eq!(
+            (accepted[0].old_creator_quote_ata, accepted[0].new_creator_quote_ata),
+            (vault.creator_quote_ata, second_ata)
+        );
+    }
+    
+    #[test]
+    fn test_guardian_pause_and_unpause() {
+        let mut harness = Harness::new();
+        let guardian = Pubkey::new_unique();
+        let vault = TestVault::with_params(&mut harness, |params| params.guardian = Some(guardian));
+        
+        let result = vault.pause(&mut harness, Pubkey::new_unique());
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedPauseAuthority)));
+        assert_eq!(vault.unpause(&mut harness, guardian), Err(program_error(ErrorCode::DistributionsNotPaused)));
+        
+        vault.pause(&mut harness, guardian).unwrap();
+        let policy = vault.policy_state(&harness);
+        assert!(policy.paused);
+        assert_eq!(policy.paused_at, harness.now());
+        assert_eq!(vault.pause(&mut harness, vault.creator), Err(program_error(ErrorCode::DistributionsPaused)));
+        
+        // Either authority may lift a pause set by the other
+        let result = vault.unpause(&mut harness, Pubkey::new_unique());
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedPauseAuthority)));
+        vault.unpause(&mut harness, vault.creator).unwrap();
+        assert!(!vault.policy_state(&harness).paused);
+        
+        let unpaused = harness.events::<VaultUnpaused>();
+        assert_eq!(unpaused[0].authority, vault.creator);
+    }
+    
+    #[test]
+    fn test_paused_day_resumes_at_its_cursor() {
+        let mut harness = Harness::new();
+        let guardian = Pubkey::new_unique();
+        let vault = TestVault::with_params(&mut harness, |params| params.guardian = Some(guardian));
+        let cranker = Pubkey::new_unique();
+        start_day(&mut harness, &vault);
+        
+        vault.pause(&mut harness, guardian).unwrap();
+        let paused = harness.events::<VaultPaused>();
+        assert_eq!((paused[0].pagination_cursor, paused[0].day_complete), (1, false));
+        
+        // Cranking is refused and the day's progress is left alone
+        let before = vault.progress_state(&harness);
+        let result = vault.crank_page(&mut harness, cranker);
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionsPaused)));
+        assert_eq!(vault.progress_state(&harness).pagination_cursor, before.pagination_cursor);
+        
+        harness.warp_by(3_600);
+        vault.unpause(&mut harness, guardian).unwrap();
+        assert_eq!(vault.progress_state(&harness).day_paused_secs, 3_600);
+        
+        // The day picks up at page 1 of the snapshot pass and completes
+        vault.crank_page(&mut harness, cranker).unwrap();
+        assert!(vault.progress_state(&harness).locked_snapshot_complete);
+        while !vault.progress_state(&harness).day_complete {
+            vault.crank_page(&mut harness, cranker).unwrap();
+        }
+        assert_eq!(vault.progress_state(&harness).daily_distributed, 300_000);
+    }
+}
\ No newline at end of file
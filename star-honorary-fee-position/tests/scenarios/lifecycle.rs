@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+    use star_honorary_fee_position::constants::SECONDS_PER_DAY;
+    use star_honorary_fee_position::errors::ErrorCode;
+    use star_honorary_fee_position::events::{DayForceClosed, VaultClosed};
+    use star_honorary_fee_position::state::{PolicyState, ProgressState};
+    use anchor_lang::prelude::Pubkey;
+    
+    /// Rent held by the vault's state PDAs and quote treasury
+    fn vault_rent(harness: &Harness, vault: &TestVault) -> u64 {
+        [vault.policy, vault.progress, vault.registry, vault.creator_split, vault.treasury]
+            .iter()
+            .map(|key| harness.account(key).unwrap().lamports)
+            .sum()
+    }
+    
+    fn assert_vault_closed(harness: &Harness, vault: &TestVault) {
+        for key in [vault.policy, vault.progress, vault.registry, vault.creator_split, vault.treasury] {
+            assert!(harness.is_closed(&key));
+        }
+    }
+    
+    #[test]
+    fn test_close_vault_once_all_streams_unlocked() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let recipient = Pubkey::new_unique();
+        
+        let investors: Vec<TestInvestor> = (0..3).map(|_| vault.add_investor(&mut harness, 100_000)).collect();
+        let streams: Vec<Pubkey> = investors.iter().map(|investor| investor.stream).collect();
+        vault.seed_claim(&mut harness, 250_000);
+        
+        // Streams are still locked
+        let result = vault.close_vault(&mut harness, recipient, false, &streams);
+        assert_eq!(result, Err(program_error(ErrorCode::StreamsStillLocked)));
+        
+        // Past the end of every stream, but one stream missing or out of order
+        harness.warp_by(SECONDS_PER_DAY * 366);
+        let result = vault.close_vault(&mut harness, recipient, false, &streams[..2]);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorCount)));
+        let result = vault.close_vault(&mut harness, recipient, false, &[streams[1], streams[0], streams[2]]);
+        assert_eq!(result, Err(program_error(ErrorCode::InvestorAccountsMismatch)));
+        
+        let rent = vault_rent(&harness, &vault);
+        vault.close_vault(&mut harness, recipient, false, &streams).unwrap();
+        
+        // Treasury swept to the creator, all rent to the recipient
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 250_000);
+        assert_eq!(harness.account(&recipient).unwrap().lamports, rent);
+        assert_vault_closed(&harness, &vault);
+        
+        let closed = harness.events::<VaultClosed>();
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].quote_swept, 250_000);
+        assert!(!closed[0].creator_opt_out);
+    }
+    
+    #[test]
+    fn test_close_vault_with_creator_opt_out() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let recipient = Pubkey::new_unique();
+        
+        vault.add_investor(&mut harness, 100_000);
+        vault.add_investor(&mut harness, 200_000);
+        vault.seed_claim(&mut harness, 40_000);
+        
+        // Positions must be removed first
+        let pool = Pubkey::new_unique();
+        harness.update_anchor_account::<PolicyState>(&vault.policy, |policy| {
+            policy.position_pools.push(pool);
+        });
+        let result = vault.close_vault(&mut harness, recipient, true, &[]);
+        assert_eq!(result, Err(program_error(ErrorCode::PositionsStillRegistered)));
+        harness.update_anchor_account::<PolicyState>(&vault.policy, |policy| {
+            policy.position_pools.clear();
+        });
+        
+        // Not while a day is in progress
+        harness.update_anchor_account::<ProgressState>(&vault.progress, |progress| {
+            progress.day_complete = false;
+        });
+        let result = vault.close_vault(&mut harness, recipient, true, &[]);
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionNotComplete)));
+        harness.update_anchor_account::<ProgressState>(&vault.progress, |progress| {
+            progress.day_complete = true;
+        });
+        
+        // Streams are still locked, but the creator opts out and passes none
+        let rent = vault_rent(&harness, &vault);
+        vault.close_vault(&mut harness, recipient, true, &[]).unwrap();
+        
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 40_000);
+        assert_eq!(harness.account(&recipient).unwrap().lamports, rent);
+        assert_vault_closed(&harness, &vault);
+        assert!(harness.events::<VaultClosed>()[0].creator_opt_out);
+    }
+    
+    /// Vault with three investors (two pages) and 1M claimed, after the first snapshot page
+    fn vault_mid_snapshot(harness: &mut Harness) -> (TestVault, Vec<TestInvestor>) {
+        let vault = TestVault::new(harness);
+        let investors = (0..3).map(|_| vault.add_investor(harness, 100_000)).collect();
+        vault.seed_claim(harness, 1_000_000);
+        vault.crank_page(harness, Pubkey::new_unique()).unwrap();
+        
+        let progress = vault.progress_state(harness);
+        assert!(!progress.day_complete && !progress.locked_snapshot_complete);
+        (vault, investors)
+    }
+    
+    #[test]
+    fn test_force_close_excludes_paused_time() {
+        let mut harness = Harness::new();
+        let caller = Pubkey::new_unique();
+        
+        let vault = TestVault::new(&mut harness);
+        let result = vault.force_close_day(&mut harness, caller);
+        assert_eq!(result, Err(program_error(ErrorCode::NoDayInProgress)));
+        
+        let (vault, _) = vault_mid_snapshot(&mut harness);
+        let day_ts = vault.progress_state(&harness).current_day_ts;
+        
+        // Paused for ten hours part-way through the day
+        harness.warp_by(3_600);
+        vault.pause(&mut harness, vault.creator).unwrap();
+        harness.warp_by(10 * 3_600);
+        let result = vault.force_close_day(&mut harness, caller);
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionsPaused)));
+        vault.unpause(&mut harness, vault.creator).unwrap();
+        assert_eq!(vault.progress_state(&harness).day_paused_secs, 10 * 3_600);
+        
+        // A full day has passed, but ten hours of it were paused
+        harness.warp_to(day_ts + SECONDS_PER_DAY);
+        let result = vault.force_close_day(&mut harness, caller);
+        assert_eq!(result, Err(program_error(ErrorCode::DayNotStale)));
+        
+        harness.warp_to(day_ts + SECONDS_PER_DAY + 10 * 3_600 - 1);
+        let result = vault.force_close_day(&mut harness, caller);
+        assert_eq!(result, Err(program_error(ErrorCode::DayNotStale)));
+        
+        harness.warp_to(day_ts + SECONDS_PER_DAY + 10 * 3_600);
+        vault.force_close_day(&mut harness, caller).unwrap();
+        assert!(vault.progress_state(&harness).day_complete);
+    }
+    
+    #[test]
+    fn test_force_close_mid_snapshot_rolls_claim_over() {
+        let mut harness = Harness::new();
+        let caller = Pubkey::new_unique();
+        let (vault, investors) = vault_mid_snapshot(&mut harness);
+        
+        let progress = vault.progress_state(&harness);
+        assert_eq!(progress.day_claimed_quote, 1_000_000);
+        assert_eq!(progress.rolled_over_claimed_quote, 0);
+        
+        harness.warp_by(SECONDS_PER_DAY);
+        vault.force_close_day(&mut harness, caller).unwrap();
+        
+        // Nothing was paid; the claim waits in the treasury for the next day
+        let progress = vault.progress_state(&harness);
+        assert!(progress.day_complete);
+        assert_eq!(progress.pagination_cursor, 0);
+        assert_eq!(progress.rolled_over_claimed_quote, 1_000_000);
+        assert_eq!(harness.token_balance(&vault.treasury), 1_000_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 0);
+        
+        let closed = harness.events::<DayForceClosed>();
+        assert_eq!(closed.len(), 1);
+        assert!(!closed[0].snapshot_complete);
+        assert_eq!(closed[0].pages_completed, 1);
+        assert_eq!(closed[0].rolled_over_quote, 1_000_000);
+        assert_eq!(closed[0].creator_amount, 0);
+        
+        // The next day counts the rolled-over claim: 30% locked -> 300k to investors
+        harness.warp_by(SECONDS_PER_DAY);
+        vault.crank_day(&mut harness, caller).unwrap();
+        for investor in &investors {
+            assert_eq!(harness.token_balance(&investor.quote_ata), 100_000);
+        }
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 700_000);
+        assert_eq!(vault.progress_state(&harness).rolled_over_claimed_quote, 0);
+    }
+}
\ No newline at end of file
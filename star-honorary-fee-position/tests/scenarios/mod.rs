@@ -1,7 +1,15 @@
 pub mod quote_only_fees;
 pub mod pagination;
 pub mod edge_cases;
+pub mod lifecycle;
+pub mod governance;
+pub mod distribution;
+pub mod positions;
 
 pub use quote_only_fees::*;
 pub use pagination::*;
-pub use edge_cases::*;
\ No newline at end of file
+pub use edge_cases::*;
+pub use lifecycle::*;
+pub use governance::*;
+pub use distribution::*;
+pub use positions::*;
\ No newline at end of file
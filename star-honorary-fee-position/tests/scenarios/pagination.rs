@@ -1,6 +1,18 @@
 #[cfg(test)]
 mod tests {
     use crate::utils::*;
+    use star_honorary_fee_position::math::{
+        apply_daily_cap, crank_reward_budget, crank_reward_per_page, f_locked_bps, investor_pool,
+        pro_rata_share,
+    };
+    use star_honorary_fee_position::constants::SECONDS_PER_DAY;
+    use star_honorary_fee_position::errors::ErrorCode;
+    use star_honorary_fee_position::events::CreatorSplitRedirected;
+    use star_honorary_fee_position::state::{
+        CreatorSplit, InvestorEntry, InvestorRegistry, ProgressState, SplitRecipient,
+    };
+    use anchor_lang::prelude::Pubkey;
+    use anchor_spl::token::spl_token::error::TokenError;
     
     #[test]
     fn test_investor_batching() {
@@ -36,4 +48,380 @@ mod tests {
             );
         }
     }
+    
+    #[test]
+    fn test_shares_consistent_across_pages() {
+        // Shares are computed against the day's snapshot, not the page
+        let claimed = 1_000_000u64;
+        let y0 = 10_000_000u64;
+        let locked_amounts: Vec<u64> = (0..45).map(|i| (i % 5 + 1) * 1_000_000).collect();
+        
+        // Snapshot pass: global locked total over every page
+        let locked_total: u64 = locked_amounts
+            .chunks(20)
+            .map(|page| page.iter().sum::<u64>())
+            .sum();
+        
+        let f_locked = f_locked_bps(locked_total, y0).unwrap();
+        let pool = investor_pool(claimed, 5000, f_locked).unwrap();
+        
+        // Payout pass: every page uses the same pool and global total
+        let mut paid = Vec::new();
+        for page in locked_amounts.chunks(20) {
+            for locked in page {
+                paid.push(pro_rata_share(pool, *locked, locked_total).unwrap());
+            }
+        }
+        
+        // Equal locked amounts receive equal shares regardless of page
+        assert_eq!(paid[0], paid[20]);
+        assert_eq!(paid[20], paid[40]);
+        
+        let total_paid: u64 = paid.iter().sum();
+        assert!(total_paid <= pool);
+        assert!(pool - total_paid < locked_amounts.len() as u64, "Only rounding dust remains");
+    }
+    
+    #[test]
+    fn test_registry_page_order() {
+        let investors: Vec<InvestorEntry> = (0..45)
+            .map(|_| InvestorEntry {
+                stream: Pubkey::new_unique(),
+                investor_quote_ata: Pubkey::new_unique(),
+            })
+            .collect();
+        
+        let registry = InvestorRegistry {
+            page_size: 20,
+            investors: investors.clone(),
+            ..Default::default()
+        };
+        
+        // Last page is derived from the registry, not the cranker
+        assert_eq!(registry.page_count(), 3);
+        assert_eq!(registry.page(0), &investors[0..20]);
+        assert_eq!(registry.page(1), &investors[20..40]);
+        assert_eq!(registry.page(2), &investors[40..45]);
+        assert!(registry.page(3).is_empty());
+        
+        // An empty registry still runs a single (creator-only) page
+        let empty = InvestorRegistry {
+            page_size: 20,
+            ..Default::default()
+        };
+        assert_eq!(empty.page_count(), 1);
+        assert!(empty.page(0).is_empty());
+    }
+    
+    #[test]
+    fn test_start_day_resets_day_fields() {
+        let mut progress = ProgressState {
+            current_day_ts: 1_000_000,
+            day_complete: true,
+            daily_distributed: 600,
+            carry_over_dust: 7,
+            locked_snapshot_complete: true,
+            total_claimed_lifetime: 5_000,
+            deferred_investor_balance: 200,
+            rolled_over_claimed_quote: 30,
+            ..Default::default()
+        };
+        
+        // What the crank runs on the first page of a day
+        let now = 1_000_000 + 86_400;
+        progress.start_day(now);
+        
+        assert_eq!(progress.current_day_ts, now);
+        assert!(!progress.day_complete);
+        assert!(!progress.locked_snapshot_complete);
+        assert_eq!((progress.daily_distributed, progress.carry_over_dust), (0, 0));
+        
+        // Balances carried between days survive the reset
+        assert_eq!(progress.total_claimed_lifetime, 5_000);
+        assert_eq!(progress.deferred_investor_balance, 200);
+        assert_eq!(progress.rolled_over_claimed_quote, 30);
+    }
+    
+    #[test]
+    fn test_deferred_cap_overflow_rolls_forward() {
+        let cap = Some(300_000u64);
+        
+        // Day 1: 500k eligible, 300k paid, 200k deferred
+        let day1_pool = investor_pool(1_000_000, 5000, 10_000).unwrap();
+        let (paid_day1, deferred) = apply_daily_cap(day1_pool, cap);
+        assert_eq!((paid_day1, deferred), (300_000, 200_000));
+        
+        // Day 2: 100k eligible plus 200k deferred fits under the cap
+        let day2_pool = investor_pool(200_000, 5000, 10_000).unwrap();
+        let (paid_day2, deferred) = apply_daily_cap(day2_pool + deferred, cap);
+        assert_eq!((paid_day2, deferred), (300_000, 0));
+        
+        // Investors end up with the full uncapped amount over both days
+        assert_eq!(paid_day1 + paid_day2, day1_pool + day2_pool);
+        
+        // No cap - nothing overflows
+        assert_eq!(apply_daily_cap(day1_pool, None), (day1_pool, 0));
+    }
+    
+    #[test]
+    fn test_crank_reward_comes_off_the_top() {
+        let claimed = 1_000_000u64;
+        
+        // 1% of the claim, cap not reached
+        let budget = crank_reward_budget(claimed, 100, Some(50_000)).unwrap();
+        assert_eq!(budget, 10_000);
+        
+        // Cap clips the budget
+        assert_eq!(crank_reward_budget(claimed, 100, Some(4_000)).unwrap(), 4_000);
+        
+        // Disabled reward leaves the claim untouched
+        assert_eq!(crank_reward_budget(claimed, 0, None).unwrap(), 0);
+        
+        // Split happens on what is left after the reward
+        let pool = investor_pool(claimed - budget, 5000, 10_000).unwrap();
+        assert_eq!(pool, 495_000);
+        
+        // 3 registry pages -> 6 pages per day (snapshot + payout)
+        let per_page = crank_reward_per_page(budget, 3);
+        assert_eq!(per_page, 1_666);
+        assert!(per_page * 6 <= budget);
+        
+        // Rounding remainder is left for the creator
+        assert_eq!(budget - per_page * 6, 4);
+    }
+    
+    #[test]
+    fn test_creator_split_fan_out() {
+        let recipient = |weight_bps| SplitRecipient {
+            quote_ata: Pubkey::new_unique(),
+            weight_bps,
+        };
+        let split = CreatorSplit {
+            recipients: vec![recipient(6000), recipient(2500), recipient(1500)],
+            ..Default::default()
+        };
+        assert!(CreatorSplit::validate_recipients(&split.recipients).is_ok());
+        
+        // Floored by weight, last recipient absorbs rounding
+        let shares = split.shares(1_001).unwrap();
+        assert_eq!(shares, vec![600, 250, 151]);
+        assert_eq!(shares.iter().sum::<u64>(), 1_001);
+        
+        // Weights must sum to 10_000 bps
+        assert!(CreatorSplit::validate_recipients(&[recipient(6000), recipient(3000)]).is_err());
+        
+        // Zero weights and duplicate recipients are rejected
+        assert!(CreatorSplit::validate_recipients(&[recipient(10_000), recipient(0)]).is_err());
+        let duplicate = recipient(5000);
+        assert!(CreatorSplit::validate_recipients(&[duplicate.clone(), duplicate]).is_err());
+        
+        // Empty split = whole remainder to the creator ATA
+        assert!(CreatorSplit::validate_recipients(&[]).is_ok());
+    }
+    
+    /// Crank the snapshot pass of a new day, leaving the payout pass at page 0
+    fn snapshot_day(harness: &mut Harness, vault: &TestVault, cranker: Pubkey) {
+        vault.crank_page(harness, cranker).unwrap();
+        while !vault.progress_state(harness).locked_snapshot_complete {
+            vault.crank_page(harness, cranker).unwrap();
+        }
+    }
+    
+    fn balances(harness: &Harness, investors: &[TestInvestor]) -> Vec<u64> {
+        investors.iter().map(|investor| harness.token_balance(&investor.quote_ata)).collect()
+    }
+    
+    #[test]
+    fn test_replayed_page_is_rejected_without_paying_twice() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let cranker = Pubkey::new_unique();
+        
+        // Two pages of two; 400k locked of 1M -> 40% of 1M claimed = 400k pool
+        let investors: Vec<TestInvestor> = (0..4).map(|_| vault.add_investor(&mut harness, 100_000)).collect();
+        vault.seed_claim(&mut harness, 1_000_000);
+        snapshot_day(&mut harness, &vault, cranker);
+        
+        let first_page = vault.page_accounts(&harness);
+        vault.crank_with(&mut harness, cranker, first_page.clone()).unwrap();
+        assert_eq!(balances(&harness, &investors), vec![100_000, 100_000, 0, 0]);
+        
+        // The cursor moved on, so the paid page no longer matches the registry
+        let replay = vault.crank_with(&mut harness, cranker, first_page.clone());
+        assert_eq!(replay, Err(program_error(ErrorCode::InvestorAccountsMismatch)));
+        assert_eq!(vault.progress_state(&harness).pagination_cursor, 1);
+        
+        vault.crank_page(&mut harness, cranker).unwrap();
+        assert!(vault.progress_state(&harness).day_complete);
+        assert_eq!(balances(&harness, &investors), vec![100_000; 4]);
+        
+        // After the day closes a replay would start a new day, which is not due yet
+        let replay = vault.crank_with(&mut harness, cranker, first_page);
+        assert_eq!(replay, Err(program_error(ErrorCode::TooEarlyForDistribution)));
+        assert_eq!(balances(&harness, &investors), vec![100_000; 4]);
+    }
+    
+    #[test]
+    fn test_page_failed_part_way_is_paid_once_on_retry() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let cranker = Pubkey::new_unique();
+        
+        let investors: Vec<TestInvestor> = (0..4).map(|_| vault.add_investor(&mut harness, 100_000)).collect();
+        vault.seed_claim(&mut harness, 1_000_000);
+        snapshot_day(&mut harness, &vault, cranker);
+        
+        // Second payout of the page fails after the first one went through
+        harness.set_frozen(&investors[1].quote_ata, true);
+        let first_page = vault.page_accounts(&harness);
+        let failed = vault.crank_with(&mut harness, cranker, first_page.clone());
+        assert_eq!(failed, Err(TokenError::AccountFrozen.into()));
+        
+        // The whole page rolled back: nobody was paid and the cursor did not move
+        assert_eq!(balances(&harness, &investors), vec![0; 4]);
+        assert_eq!(vault.progress_state(&harness).pagination_cursor, 0);
+        assert_eq!(vault.progress_state(&harness).daily_distributed, 0);
+        
+        // Retrying the same page pays each of its investors once
+        harness.set_frozen(&investors[1].quote_ata, false);
+        vault.crank_with(&mut harness, cranker, first_page.clone()).unwrap();
+        assert_eq!(balances(&harness, &investors), vec![100_000, 100_000, 0, 0]);
+        
+        // ...and sending it a third time is rejected
+        let replay = vault.crank_with(&mut harness, cranker, first_page);
+        assert_eq!(replay, Err(program_error(ErrorCode::InvestorAccountsMismatch)));
+        assert_eq!(balances(&harness, &investors), vec![100_000, 100_000, 0, 0]);
+        
+        vault.crank_page(&mut harness, cranker).unwrap();
+        let progress = vault.progress_state(&harness);
+        assert!(progress.day_complete);
+        assert_eq!(progress.daily_distributed, 400_000);
+        assert_eq!(balances(&harness, &investors), vec![100_000; 4]);
+    }
+    
+    #[test]
+    fn test_unusable_split_recipient_share_goes_to_creator() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let cranker = Pubkey::new_unique();
+        
+        // 10% locked -> investors get 100k of 1M, the creator remainder is 900k
+        let investor = vault.add_investor(&mut harness, 100_000);
+        let closed = Pubkey::new_unique();
+        let live = Pubkey::new_unique();
+        vault.add_quote_account(&mut harness, closed, Pubkey::new_unique());
+        vault.add_quote_account(&mut harness, live, Pubkey::new_unique());
+        vault.set_creator_split(&mut harness, vec![
+            SplitRecipient { quote_ata: closed, weight_bps: 5000 },
+            SplitRecipient { quote_ata: live, weight_bps: 5000 },
+        ]).unwrap();
+        
+        // First recipient closes its ATA before the day is paid out
+        harness.close_account(&closed);
+        vault.seed_claim(&mut harness, 1_000_000);
+        vault.crank_day(&mut harness, cranker).unwrap();
+        
+        assert!(vault.progress_state(&harness).day_complete);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 100_000);
+        assert_eq!(harness.token_balance(&live), 450_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 450_000);
+        assert!(harness.is_closed(&closed));
+        
+        let redirected = harness.events::<CreatorSplitRedirected>();
+        assert_eq!(redirected.len(), 1);
+        assert_eq!(redirected[0].recipient, closed);
+        assert_eq!(redirected[0].amount, 450_000);
+        
+        // A frozen recipient is skipped the same way on the next day
+        harness.set_frozen(&live, true);
+        harness.warp_by(SECONDS_PER_DAY);
+        vault.seed_claim(&mut harness, 1_000_000);
+        vault.crank_day(&mut harness, cranker).unwrap();
+        
+        assert_eq!(harness.token_balance(&live), 450_000);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 1_350_000);
+        
+        // With the day closed, the creator can replace the dead recipients
+        vault.set_creator_split(&mut harness, vec![]).unwrap();
+        assert!(vault.creator_split_state(&harness).recipients.is_empty());
+    }
+    
+    fn registry_streams(harness: &Harness, vault: &TestVault) -> Vec<Pubkey> {
+        vault.registry_state(harness).investors.iter().map(|entry| entry.stream).collect()
+    }
+    
+    #[test]
+    fn test_registry_maintained_through_handlers() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let investors: Vec<TestInvestor> = (0..3).map(|_| vault.add_investor(&mut harness, 100_000)).collect();
+        let streams: Vec<Pubkey> = investors.iter().map(|investor| investor.stream).collect();
+        assert_eq!(registry_streams(&harness, &vault), streams);
+        assert_eq!(vault.registry_state(&harness).page_count(), 2);
+        
+        // A stream is registered once
+        let result = vault.register_investor(&mut harness, investors[0].stream, investors[0].quote_ata);
+        assert_eq!(result, Err(program_error(ErrorCode::InvestorAlreadyRegistered)));
+        
+        // Only the creator edits the registry
+        let outsider = TestVault { creator: Pubkey::new_unique(), ..vault };
+        let result = outsider.remove_investor(&mut harness, investors[0].stream);
+        assert_eq!(result, Err(program_error(ErrorCode::UnauthorizedCreator)));
+        
+        // Removal keeps the order of the others, so pages shift up
+        vault.remove_investor(&mut harness, investors[0].stream).unwrap();
+        assert_eq!(registry_streams(&harness, &vault), streams[1..]);
+        assert_eq!(vault.registry_state(&harness).page_count(), 1);
+        let result = vault.remove_investor(&mut harness, investors[0].stream);
+        assert_eq!(result, Err(program_error(ErrorCode::InvestorNotRegistered)));
+        
+        // Not while a day is in progress
+        vault.add_investor(&mut harness, 100_000);
+        vault.crank_page(&mut harness, Pubkey::new_unique()).unwrap();
+        assert!(!vault.progress_state(&harness).day_complete);
+        let result = vault.remove_investor(&mut harness, investors[1].stream);
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionNotComplete)));
+        let contract = vault.locked_stream(&harness, Pubkey::new_unique(), 100_000);
+        let stream = Pubkey::new_unique();
+        harness.set_data(stream, star_honorary_fee_position::streamflow::program_id(), stream_account_data(&contract));
+        let result = vault.register_investor(&mut harness, stream, investors[0].quote_ata);
+        assert_eq!(result, Err(program_error(ErrorCode::DistributionNotComplete)));
+    }
+    
+    #[test]
+    fn test_crank_pages_follow_the_registry() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let cranker = Pubkey::new_unique();
+        let investors: Vec<TestInvestor> = (0..3).map(|_| vault.add_investor(&mut harness, 100_000)).collect();
+        vault.seed_claim(&mut harness, 1_000_000);
+        
+        // Page 0 holds the first two investors, in registry order
+        let page = vault.page_accounts(&harness);
+        let swapped = vec![page[2].clone(), page[3].clone(), page[0].clone(), page[1].clone()];
+        let result = vault.crank_with(&mut harness, cranker, swapped);
+        assert_eq!(result, Err(program_error(ErrorCode::InvestorAccountsMismatch)));
+        
+        let result = vault.crank_with(&mut harness, cranker, page[..2].to_vec());
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorCount)));
+        
+        let mut with_extra = page.clone();
+        with_extra.extend([readonly(investors[2].stream), writable(investors[2].quote_ata)]);
+        let result = vault.crank_with(&mut harness, cranker, with_extra);
+        assert_eq!(result, Err(program_error(ErrorCode::InvalidInvestorCount)));
+        
+        // Substituting an unregistered ATA for a registered one is caught too
+        let mut substituted = page.clone();
+        substituted[1] = writable(investors[2].quote_ata);
+        let result = vault.crank_with(&mut harness, cranker, substituted);
+        assert_eq!(result, Err(program_error(ErrorCode::InvestorAccountsMismatch)));
+        
+        vault.crank_with(&mut harness, cranker, page).unwrap();
+        assert_eq!(vault.progress_state(&harness).pagination_cursor, 1);
+        
+        // The last page is a partial one
+        assert_eq!(vault.page_accounts(&harness).len(), 2);
+        vault.crank_day(&mut harness, cranker).unwrap();
+        assert_eq!(balances(&harness, &investors), vec![100_000; 3]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,391 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::*;
+    use star_honorary_fee_position::constants::SECONDS_PER_DAY;
+    use star_honorary_fee_position::cp_amm;
+    use star_honorary_fee_position::damm_v2::{self, COLLECT_FEE_MODE_ONLY_B};
+    use star_honorary_fee_position::errors::ErrorCode;
+    use star_honorary_fee_position::events::{HonoraryPositionInitialized, QuoteFeesClaimed};
+    use star_honorary_fee_position::instructions::InitializePositionParams;
+    use star_honorary_fee_position::state::{AmmKind, PositionState};
+    use anchor_lang::prelude::{ProgramError, Pubkey};
+    
+    /// CP-AMM pool of the vault's quote mint and a new base mint, served by the CP-AMM mock
+    fn clmm_pool(harness: &mut Harness, vault: &TestVault, quote_is_token_0: bool) -> MockPoolConfig {
+        let base_mint = Pubkey::new_unique();
+        harness.add_mint(base_mint, 9);
+        
+        let (token_0_mint, token_1_mint) = if quote_is_token_0 {
+            (vault.quote_mint, base_mint)
+        } else {
+            (base_mint, vault.quote_mint)
+        };
+        let pool = MockPoolConfig::new(token_0_mint, token_1_mint)
+            .with_current_tick(1000)
+            .with_tick_spacing(10);
+        harness.set_data(pool.pool_id, cp_amm::program_id(), clmm_pool_data(&pool));
+        harness.add_token_account(pool.token_0_vault, token_0_mint, pool.pool_id, 0);
+        harness.add_token_account(pool.token_1_vault, token_1_mint, pool.pool_id, 0);
+        harness.mock_program(cp_amm::program_id(), cp_amm_mock());
+        
+        pool
+    }
+    
+    /// DAMM v2 pool of a new base mint and the vault's quote mint, served by the DAMM v2 mock
+    fn damm_v2_pool(harness: &mut Harness, vault: &TestVault, collect_fee_mode: u8, quote_is_token_b: bool) -> MockPoolConfig {
+        let base_mint = Pubkey::new_unique();
+        harness.add_mint(base_mint, 9);
+        
+        let (token_a_mint, token_b_mint) = if quote_is_token_b {
+            (base_mint, vault.quote_mint)
+        } else {
+            (vault.quote_mint, base_mint)
+        };
+        let pool = MockPoolConfig::new(token_a_mint, token_b_mint);
+        harness.set_data(pool.pool_id, damm_v2::program_id(), damm_v2_pool_data(&pool, collect_fee_mode));
+        harness.add_token_account(pool.token_0_vault, token_a_mint, damm_v2::pool_authority(), 0);
+        harness.add_token_account(pool.token_1_vault, token_b_mint, damm_v2::pool_authority(), 0);
+        harness.mock_program(damm_v2::program_id(), damm_v2_mock());
+        
+        pool
+    }
+    
+    /// DAMM v2 deposit of 200 base (token A) and 1,000 quote (token B)
+    fn damm_v2_params() -> InitializePositionParams {
+        InitializePositionParams {
+            amount_0_max: 200,
+            ..position_params(AmmKind::MeteoraDammV2, 0, 0)
+        }
+    }
+    
+    /// Vault with one investor holding half of Y0 locked and a CP-AMM position holding 1,000 quote
+    fn clmm_vault(harness: &mut Harness) -> (TestVault, TestInvestor, MockPoolConfig, TestPosition) {
+        let vault = TestVault::new(harness);
+        let investor = vault.add_investor(harness, 500_000);
+        let pool = clmm_pool(harness, &vault, true);
+        let position = vault.new_position(harness, &pool, AmmKind::RaydiumClmm, 5_000);
+        let params = InitializePositionParams {
+            amount_1_max: 0,
+            ..position_params(AmmKind::RaydiumClmm, 1100, 1200)
+        };
+        vault.initialize_position(harness, &position, params).unwrap();
+        (vault, investor, pool, position)
+    }
+    
+    /// Crank the day's first page, claiming from `position`
+    fn crank_claiming(harness: &mut Harness, vault: &TestVault, position: &TestPosition) -> Result<(), ProgramError> {
+        let mut accounts = position.crank_group();
+        accounts.extend(vault.page_accounts(harness));
+        vault.crank_with(harness, Pubkey::new_unique(), accounts)
+    }
+    
+    /// Crank the rest of the day's pages
+    fn finish_day(harness: &mut Harness, vault: &TestVault) {
+        while !vault.progress_state(harness).day_complete {
+            vault.crank_page(harness, Pubkey::new_unique()).unwrap();
+        }
+    }
+    
+    #[test]
+    fn test_initialize_position_opens_clmm_position() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let pool = clmm_pool(&mut harness, &vault, true);
+        let position = vault.new_position(&mut harness, &pool, AmmKind::RaydiumClmm, 5_000);
+        
+        // Quote is token 0, so the range sits above the price and only quote is deposited
+        let params = InitializePositionParams {
+            amount_1_max: 0,
+            ..position_params(AmmKind::RaydiumClmm, 1100, 1200)
+        };
+        vault.initialize_position(&mut harness, &position, params).unwrap();
+        
+        // CP-AMM pulled the deposit from the payer's quote account
+        assert_eq!(harness.token_balance(&position.payer_quote_account), 4_000);
+        assert_eq!(harness.token_balance(&position.payer_base_account), 5_000);
+        assert_eq!(harness.token_balance(&pool.token_0_vault), 1_000);
+        
+        // The position owner PDA holds the position NFT
+        let nft = harness.token_account(&position.venue[6]);
+        assert_eq!(nft.mint, position.position_nft_mint);
+        assert_eq!(nft.owner, vault.position_owner);
+        assert_eq!(nft.amount, 1);
+        
+        // The position state records the venue accounts and the liquidity CP-AMM minted
+        let state: PositionState = harness.anchor_account(&position.position_state);
+        assert_eq!(state.vault, vault.vault);
+        assert_eq!(state.amm_kind, AmmKind::RaydiumClmm);
+        assert_eq!(state.pool_id, pool.pool_id);
+        assert_eq!(state.position_nft_mint, position.position_nft_mint);
+        assert_eq!(state.position_id, position.venue[0]);
+        assert_eq!(state.personal_position, position.venue[1]);
+        assert_eq!(state.tick_array_lower, position.venue[4]);
+        assert_eq!(state.tick_array_upper, position.venue[5]);
+        assert_eq!((state.tick_lower, state.tick_upper), (1100, 1200));
+        assert_eq!(state.liquidity, 1_000);
+        assert_eq!(state.base_mint, position.base_mint);
+        assert_eq!(state.base_treasury, position.base_treasury);
+        assert!(state.is_quote_token_0);
+        
+        // An empty base treasury exists for the crank's base-fee check
+        let base_treasury = harness.token_account(&position.base_treasury);
+        assert_eq!(base_treasury.mint, position.base_mint);
+        assert_eq!(base_treasury.owner, vault.treasury_authority);
+        assert_eq!(base_treasury.amount, 0);
+        
+        // The pool is registered for the crank
+        assert_eq!(vault.policy_state(&harness).position_pools, vec![pool.pool_id]);
+        
+        let events = harness.events::<HonoraryPositionInitialized>();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].position_id, position.venue[0]);
+        assert_eq!(events[0].liquidity, 1_000);
+        assert!(events[0].is_quote_token_0);
+    }
+    
+    #[test]
+    fn test_initialize_position_clmm_quote_as_token_1() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let pool = clmm_pool(&mut harness, &vault, false);
+        let position = vault.new_position(&mut harness, &pool, AmmKind::RaydiumClmm, 5_000);
+        
+        // Quote is token 1: the range sits below the price and token 1 is funded from the quote account
+        let params = InitializePositionParams {
+            amount_0_max: 0,
+            ..position_params(AmmKind::RaydiumClmm, 800, 900)
+        };
+        vault.initialize_position(&mut harness, &position, params).unwrap();
+        
+        assert_eq!(harness.token_balance(&position.payer_quote_account), 4_000);
+        assert_eq!(harness.token_balance(&position.payer_base_account), 5_000);
+        assert_eq!(harness.token_balance(&pool.token_1_vault), 1_000);
+        
+        let state: PositionState = harness.anchor_account(&position.position_state);
+        assert!(!state.is_quote_token_0);
+        assert_eq!(state.base_mint, pool.token_0_mint);
+    }
+    
+    #[test]
+    fn test_initialize_position_rejects_bad_clmm_open() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let pool = clmm_pool(&mut harness, &vault, true);
+        let params = position_params(AmmKind::RaydiumClmm, 1100, 1200);
+        
+        // Vault accounts that are not the pool's
+        let mut position = vault.new_position(&mut harness, &pool, AmmKind::RaydiumClmm, 5_000);
+        position.venue[2] = Pubkey::new_unique();
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, params.clone()),
+            Err(program_error(ErrorCode::InvalidCpAmmAccount))
+        );
+        
+        // Too few venue accounts for CP-AMM
+        let mut position = vault.new_position(&mut harness, &pool, AmmKind::RaydiumClmm, 5_000);
+        position.venue.pop();
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, params.clone()),
+            Err(program_error(ErrorCode::PositionAccountsMismatch))
+        );
+        
+        // CP-AMM minted no liquidity
+        let position = vault.new_position(&mut harness, &pool, AmmKind::RaydiumClmm, 5_000);
+        let empty = InitializePositionParams { liquidity: 0, ..params };
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, empty),
+            Err(program_error(ErrorCode::ZeroPositionLiquidity))
+        );
+        
+        // Nothing was opened or registered
+        assert!(harness.is_closed(&position.position_state));
+        assert!(harness.is_closed(&position.venue[1]));
+        assert_eq!(harness.token_balance(&position.payer_quote_account), 5_000);
+        assert!(vault.policy_state(&harness).position_pools.is_empty());
+    }
+    
+    #[test]
+    fn test_crank_collects_clmm_quote_fees() {
+        let mut harness = Harness::new();
+        let (vault, investor, pool, position) = clmm_vault(&mut harness);
+        let personal_position = position.venue[1];
+        set_clmm_fees_owed(&mut harness, &personal_position, (600, 0));
+        
+        // The first page collects the position's fees into the quote treasury and pays them out
+        crank_claiming(&mut harness, &vault, &position).unwrap();
+        assert_eq!(clmm_fees_owed(&harness, &personal_position), (0, 0));
+        assert_eq!(harness.token_balance(&pool.token_0_vault), 400);
+        assert_eq!(harness.token_balance(&position.base_treasury), 0);
+        
+        let claimed = harness.events::<QuoteFeesClaimed>();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].amount, 600);
+        
+        // Half of Y0 is locked: investors get half, the creator the rest
+        finish_day(&mut harness, &vault);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 300);
+        assert_eq!(harness.token_balance(&vault.treasury), 0);
+        
+        // Fees earned since the position was last touched are collected too
+        harness.warp_by(SECONDS_PER_DAY);
+        harness.mock_program(cp_amm::program_id(), cp_amm_mock_accruing((100, 0)));
+        crank_claiming(&mut harness, &vault, &position).unwrap();
+        assert_eq!(harness.events::<QuoteFeesClaimed>()[0].amount, 100);
+        finish_day(&mut harness, &vault);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 350);
+        assert_eq!(vault.progress_state(&harness).total_claimed_lifetime, 700);
+    }
+    
+    #[test]
+    fn test_crank_refuses_clmm_base_fees() {
+        let mut harness = Harness::new();
+        let (vault, investor, pool, position) = clmm_vault(&mut harness);
+        let personal_position = position.venue[1];
+        harness.set_token_balance(&pool.token_1_vault, 5);
+        
+        // Base fees the position already records as owed are refused before collecting
+        set_clmm_fees_owed(&mut harness, &personal_position, (600, 5));
+        assert_eq!(
+            crank_claiming(&mut harness, &vault, &position),
+            Err(program_error(ErrorCode::BaseFeesDetected))
+        );
+        assert_eq!(clmm_fees_owed(&harness, &personal_position), (600, 5));
+        
+        // Base fees that only show up when collecting are refused too, and nothing moves
+        set_clmm_fees_owed(&mut harness, &personal_position, (600, 0));
+        harness.mock_program(cp_amm::program_id(), cp_amm_mock_accruing((0, 5)));
+        assert_eq!(
+            crank_claiming(&mut harness, &vault, &position),
+            Err(program_error(ErrorCode::BaseFeesDetected))
+        );
+        assert_eq!(clmm_fees_owed(&harness, &personal_position), (600, 0));
+        assert_eq!(harness.token_balance(&pool.token_0_vault), 1_000);
+        assert_eq!(harness.token_balance(&pool.token_1_vault), 5);
+        assert_eq!(harness.token_balance(&position.base_treasury), 0);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 0);
+        assert!(vault.progress_state(&harness).day_complete);
+        
+        // Venue accounts that are not the recorded position's are refused
+        harness.mock_program(cp_amm::program_id(), cp_amm_mock());
+        let mut other_position = position.clone();
+        other_position.venue[1] = Pubkey::new_unique();
+        assert_eq!(
+            crank_claiming(&mut harness, &vault, &other_position),
+            Err(program_error(ErrorCode::PositionAccountsMismatch))
+        );
+        
+        // Once the pool only pays quote, the day goes through
+        crank_claiming(&mut harness, &vault, &position).unwrap();
+        finish_day(&mut harness, &vault);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300);
+    }
+    
+    #[test]
+    fn test_initialize_position_opens_damm_v2_position() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let pool = damm_v2_pool(&mut harness, &vault, COLLECT_FEE_MODE_ONLY_B, true);
+        let position = vault.new_position(&mut harness, &pool, AmmKind::MeteoraDammV2, 5_000);
+        
+        vault.initialize_position(&mut harness, &position, damm_v2_params()).unwrap();
+        
+        // DAMM v2 pulled the deposit through the owner PDA's approvals, which were then revoked
+        assert_eq!(harness.token_balance(&position.payer_base_account), 4_800);
+        assert_eq!(harness.token_balance(&position.payer_quote_account), 4_000);
+        assert_eq!(harness.token_balance(&pool.token_0_vault), 200);
+        assert_eq!(harness.token_balance(&pool.token_1_vault), 1_000);
+        for account in [position.payer_base_account, position.payer_quote_account] {
+            let account = harness.token_account(&account);
+            assert!(account.delegate.is_none());
+            assert_eq!(account.delegated_amount, 0);
+        }
+        
+        // The position owner PDA holds the position NFT
+        let nft = harness.token_account(&damm_v2::position_nft_account_address(&position.position_nft_mint));
+        assert_eq!(nft.mint, position.position_nft_mint);
+        assert_eq!(nft.owner, vault.position_owner);
+        assert_eq!(nft.amount, 1);
+        
+        // Quote is token B; DAMM v2 positions have no ticks
+        let state: PositionState = harness.anchor_account(&position.position_state);
+        assert_eq!(state.amm_kind, AmmKind::MeteoraDammV2);
+        assert_eq!(state.position_id, damm_v2::position_address(&position.position_nft_mint));
+        assert_eq!(state.liquidity, 1_000);
+        assert_eq!(state.base_mint, pool.token_0_mint);
+        assert!(!state.is_quote_token_0);
+        assert_eq!((state.tick_lower, state.tick_upper), (0, 0));
+        assert_eq!(vault.policy_state(&harness).position_pools, vec![pool.pool_id]);
+    }
+    
+    #[test]
+    fn test_initialize_position_rejects_damm_v2_pool_not_quote_only() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        
+        // BothToken mode would pay fees in the base mint too
+        let pool = damm_v2_pool(&mut harness, &vault, 0, true);
+        let position = vault.new_position(&mut harness, &pool, AmmKind::MeteoraDammV2, 5_000);
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, damm_v2_params()),
+            Err(program_error(ErrorCode::PoolNotQuoteOnly))
+        );
+        
+        // OnlyB mode with the quote mint as token A pays every fee in base
+        let pool = damm_v2_pool(&mut harness, &vault, COLLECT_FEE_MODE_ONLY_B, false);
+        let position = vault.new_position(&mut harness, &pool, AmmKind::MeteoraDammV2, 5_000);
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, damm_v2_params()),
+            Err(program_error(ErrorCode::PoolNotQuoteOnly))
+        );
+        
+        // A quote-only pool opened with accounts DAMM v2 would not derive
+        let pool = damm_v2_pool(&mut harness, &vault, COLLECT_FEE_MODE_ONLY_B, true);
+        let mut position = vault.new_position(&mut harness, &pool, AmmKind::MeteoraDammV2, 5_000);
+        position.venue[4] = Pubkey::new_unique();
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, damm_v2_params()),
+            Err(program_error(ErrorCode::InvalidCpAmmAccount))
+        );
+        
+        position.venue[4] = damm_v2::pool_authority();
+        position.venue[0] = Pubkey::new_unique();
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, damm_v2_params()),
+            Err(program_error(ErrorCode::InvalidCpAmmAccount))
+        );
+        assert!(vault.policy_state(&harness).position_pools.is_empty());
+    }
+    
+    #[test]
+    fn test_crank_collects_damm_v2_quote_fees() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let investor = vault.add_investor(&mut harness, 500_000);
+        let pool = damm_v2_pool(&mut harness, &vault, COLLECT_FEE_MODE_ONLY_B, true);
+        let position = vault.new_position(&mut harness, &pool, AmmKind::MeteoraDammV2, 5_000);
+        vault.initialize_position(&mut harness, &position, damm_v2_params()).unwrap();
+        let position_account = position.venue[0];
+        
+        // Token B (quote) fees are claimed into the quote treasury and paid out
+        set_damm_v2_fees_pending(&mut harness, &position_account, (0, 600));
+        crank_claiming(&mut harness, &vault, &position).unwrap();
+        assert_eq!(harness.events::<QuoteFeesClaimed>()[0].amount, 600);
+        assert_eq!(damm_v2_fees_pending(&harness, &position_account), (0, 0));
+        assert_eq!(harness.token_balance(&pool.token_1_vault), 400);
+        finish_day(&mut harness, &vault);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300);
+        assert_eq!(harness.token_balance(&vault.creator_quote_ata), 300);
+        
+        // Token A (base) fees pending on the position stop the day
+        harness.warp_by(SECONDS_PER_DAY);
+        set_damm_v2_fees_pending(&mut harness, &position_account, (5, 100));
+        assert_eq!(
+            crank_claiming(&mut harness, &vault, &position),
+            Err(program_error(ErrorCode::BaseFeesDetected))
+        );
+        assert_eq!(damm_v2_fees_pending(&harness, &position_account), (5, 100));
+        assert_eq!(harness.token_balance(&position.base_treasury), 0);
+        assert_eq!(harness.token_balance(&investor.quote_ata), 300);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,22 @@
 #[cfg(test)]
 mod tests {
     use crate::utils::*;
+    use star_honorary_fee_position::amm::{self, AmmAdapter, MeteoraDammV2Adapter, OrcaWhirlpoolAdapter};
+    use star_honorary_fee_position::cp_amm::validate_tick_range;
+    use star_honorary_fee_position::constants::{MAX_TICK, MIN_TICK};
+    use star_honorary_fee_position::damm_v2::COLLECT_FEE_MODE_ONLY_B;
+    use star_honorary_fee_position::errors::ErrorCode;
+    use star_honorary_fee_position::state::AmmKind;
+    use star_honorary_fee_position::{damm_v2, whirlpool};
+    use anchor_lang::prelude::{AccountInfo, ProgramError, Pubkey};
+    
+    /// Run `check` against `data` held by an account owned by `owner`
+    fn with_account<T>(owner: Pubkey, mut data: Vec<u8>, check: impl FnOnce(&AccountInfo) -> T) -> T {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        check(&info)
+    }
     
     #[test]
     fn test_quote_only_tick_validation() {
@@ -50,6 +66,23 @@ mod tests {
         ));
     }
     
+    #[test]
+    fn test_tick_range_bounds_and_spacing() {
+        // Aligned range inside global bounds (VALID)
+        assert!(validate_tick_range(1100, 1200, 10).is_ok());
+        
+        // Inverted range (INVALID)
+        assert!(validate_tick_range(1200, 1100, 10).is_err());
+        
+        // Outside global tick bounds (INVALID)
+        assert!(validate_tick_range(MIN_TICK - 1, 0, 1).is_err());
+        assert!(validate_tick_range(0, MAX_TICK + 1, 1).is_err());
+        
+        // Not aligned to tick spacing (INVALID)
+        assert!(validate_tick_range(1105, 1200, 10).is_err());
+        assert!(validate_tick_range(1100, 1205, 10).is_err());
+    }
+    
     #[test]
     fn test_price_to_tick_conversions() {
         // Test that we can convert prices to ticks correctly
@@ -65,4 +98,219 @@ mod tests {
         let tick_half = price_to_tick(price_half);
         assert!(tick_half > -7000 && tick_half < -6900, "Price 0.5 should give tick ~-6931");
     }
+    
+    #[test]
+    fn test_damm_v2_quote_only_pool() {
+        let base_mint = Pubkey::new_unique();
+        let quote_mint = Pubkey::new_unique();
+        let pool = damm_v2::PoolSnapshot {
+            token_a_mint: base_mint,
+            token_b_mint: quote_mint,
+            token_a_vault: Pubkey::new_unique(),
+            token_b_vault: Pubkey::new_unique(),
+            sqrt_price: 1u128 << 64,
+            collect_fee_mode: COLLECT_FEE_MODE_ONLY_B,
+        };
+        
+        // OnlyB mode with quote as token B (VALID)
+        assert!(damm_v2::is_quote_only_pool(&pool, &quote_mint));
+        
+        // Quote is token A - fees would arrive in base (INVALID)
+        assert!(!damm_v2::is_quote_only_pool(&pool, &base_mint));
+        
+        // BothToken mode charges fees in both mints (INVALID)
+        let both_tokens = damm_v2::PoolSnapshot {
+            collect_fee_mode: 0,
+            ..pool
+        };
+        assert!(!damm_v2::is_quote_only_pool(&both_tokens, &quote_mint));
+    }
+    
+    #[test]
+    fn test_amm_adapter_dispatch() {
+        // Each venue is reached through its own program
+        assert_eq!(amm::program_id(AmmKind::RaydiumClmm), star_honorary_fee_position::cp_amm::program_id());
+        assert_eq!(amm::program_id(AmmKind::MeteoraDammV2), damm_v2::program_id());
+        assert_eq!(amm::program_id(AmmKind::OrcaWhirlpool), whirlpool::program_id());
+        
+        // CLMM opens with an extra metadata account; the others reuse their position accounts
+        assert_eq!(amm::open_account_count(AmmKind::RaydiumClmm), 8);
+        assert_eq!(amm::position_account_count(AmmKind::RaydiumClmm), 7);
+        for kind in [AmmKind::MeteoraDammV2, AmmKind::OrcaWhirlpool] {
+            assert_eq!(amm::open_account_count(kind), amm::position_account_count(kind));
+        }
+    }
+    
+    #[test]
+    fn test_whirlpool_quote_only_ticks() {
+        // Whirlpool shares the CLMM tick rule with token A as token0
+        let tick_current = 1000;
+        
+        // Quote is token A: range above the price (VALID)
+        assert!(validate_quote_only_ticks(tick_current, 1064, 1128, true));
+        
+        // Quote is token B: range below the price (VALID)
+        assert!(validate_quote_only_ticks(tick_current, 832, 896, false));
+        
+        // Range straddling the price would accrue base fees (INVALID)
+        assert!(!validate_quote_only_ticks(tick_current, 960, 1064, true));
+    }
+    
+    #[test]
+    fn test_whirlpool_account_fixture() {
+        let quote_mint = Pubkey::new_unique();
+        let base_mint = Pubkey::new_unique();
+        let config = MockPoolConfig::new(quote_mint, base_mint)
+            .with_current_tick(1000)
+            .with_tick_spacing(64);
+        let data = whirlpool_pool_data(&config);
+        assert_eq!(data.len(), 653);
+        
+        with_account(whirlpool::program_id(), data.clone(), |info| {
+            // Layout offsets pick the right fields out of a full Whirlpool account
+            let snapshot = whirlpool::read_pool(info).unwrap();
+            assert_eq!(snapshot.token_mint_a, quote_mint);
+            assert_eq!(snapshot.token_mint_b, base_mint);
+            assert_eq!(snapshot.token_vault_a, config.token_0_vault);
+            assert_eq!(snapshot.token_vault_b, config.token_1_vault);
+            assert_eq!(snapshot.tick_spacing, 64);
+            assert_eq!(snapshot.sqrt_price, config.sqrt_price_x64);
+            assert_eq!(snapshot.tick_current, 1000);
+            
+            let pool = OrcaWhirlpoolAdapter::read_pool(info).unwrap();
+            assert_eq!(pool.tick_current, Some(1000));
+            assert_eq!(pool.tick_spacing, Some(64));
+            
+            // Quote is token A: range above the price (VALID)
+            let above = position_params(AmmKind::OrcaWhirlpool, 1024, 1152);
+            assert!(OrcaWhirlpoolAdapter::validate_quote_only(info, &pool, true, &above).is_ok());
+            
+            // Quote is token B: range below the price (VALID)
+            let below = position_params(AmmKind::OrcaWhirlpool, 832, 960);
+            assert!(OrcaWhirlpoolAdapter::validate_quote_only(info, &pool, false, &below).is_ok());
+            
+            // Range straddling the price would accrue base fees (INVALID)
+            let straddling = position_params(AmmKind::OrcaWhirlpool, 960, 1088);
+            assert_eq!(
+                ProgramError::from(OrcaWhirlpoolAdapter::validate_quote_only(info, &pool, true, &straddling).unwrap_err()),
+                program_error(ErrorCode::PositionWouldAccrueBaseFees)
+            );
+            
+            // Range not aligned to the pool's tick spacing (INVALID)
+            let misaligned = position_params(AmmKind::OrcaWhirlpool, 1000, 1152);
+            assert_eq!(
+                ProgramError::from(OrcaWhirlpoolAdapter::validate_quote_only(info, &pool, true, &misaligned).unwrap_err()),
+                program_error(ErrorCode::TickNotAlignedToSpacing)
+            );
+        });
+        
+        // Account owned by another program (INVALID)
+        with_account(Pubkey::new_unique(), data.clone(), |info| {
+            assert_eq!(
+                ProgramError::from(whirlpool::read_pool(info).unwrap_err()),
+                program_error(ErrorCode::InvalidCpAmmAccount)
+            );
+        });
+        
+        // Another Whirlpool account type of the same size (INVALID)
+        let mut not_a_pool = data;
+        not_a_pool[..8].copy_from_slice(&account_discriminator("TickArray"));
+        with_account(whirlpool::program_id(), not_a_pool, |info| {
+            assert_eq!(
+                ProgramError::from(whirlpool::read_pool(info).unwrap_err()),
+                program_error(ErrorCode::InvalidCpAmmAccount)
+            );
+        });
+    }
+    
+    #[test]
+    fn test_damm_v2_pool_fixture() {
+        let base_mint = Pubkey::new_unique();
+        let quote_mint = Pubkey::new_unique();
+        let config = MockPoolConfig::new(base_mint, quote_mint);
+        let params = position_params(AmmKind::MeteoraDammV2, 0, 0);
+        
+        let only_b = damm_v2_pool_data(&config, COLLECT_FEE_MODE_ONLY_B);
+        assert_eq!(only_b.len(), 1112);
+        
+        with_account(damm_v2::program_id(), only_b.clone(), |info| {
+            // Layout offsets pick the right fields out of a full Pool account
+            let snapshot = damm_v2::read_pool(info).unwrap();
+            assert_eq!(snapshot.token_a_mint, base_mint);
+            assert_eq!(snapshot.token_b_mint, quote_mint);
+            assert_eq!(snapshot.token_a_vault, config.token_0_vault);
+            assert_eq!(snapshot.token_b_vault, config.token_1_vault);
+            assert_eq!(snapshot.sqrt_price, config.sqrt_price_x64);
+            assert_eq!(snapshot.collect_fee_mode, COLLECT_FEE_MODE_ONLY_B);
+            
+            // OnlyB mode with quote as token B (VALID)
+            assert!(damm_v2::is_quote_only_pool(&snapshot, &quote_mint));
+            let pool = MeteoraDammV2Adapter::read_pool(info).unwrap();
+            assert!(MeteoraDammV2Adapter::validate_quote_only(info, &pool, false, &params).is_ok());
+            
+            // Quote is token A - fees would arrive in base (INVALID)
+            assert!(!damm_v2::is_quote_only_pool(&snapshot, &base_mint));
+            assert_eq!(
+                ProgramError::from(MeteoraDammV2Adapter::validate_quote_only(info, &pool, true, &params).unwrap_err()),
+                program_error(ErrorCode::PoolNotQuoteOnly)
+            );
+        });
+        
+        // BothToken mode charges fees in both mints (INVALID)
+        with_account(damm_v2::program_id(), damm_v2_pool_data(&config, 0), |info| {
+            let snapshot = damm_v2::read_pool(info).unwrap();
+            assert_eq!(snapshot.collect_fee_mode, 0);
+            assert!(!damm_v2::is_quote_only_pool(&snapshot, &quote_mint));
+            
+            let pool = MeteoraDammV2Adapter::read_pool(info).unwrap();
+            assert_eq!(
+                ProgramError::from(MeteoraDammV2Adapter::validate_quote_only(info, &pool, false, &params).unwrap_err()),
+                program_error(ErrorCode::PoolNotQuoteOnly)
+            );
+        });
+        
+        // Another DAMM v2 account type (INVALID)
+        let mut not_a_pool = only_b;
+        not_a_pool[..8].copy_from_slice(&account_discriminator("Position"));
+        with_account(damm_v2::program_id(), not_a_pool, |info| {
+            assert_eq!(
+                ProgramError::from(damm_v2::read_pool(info).unwrap_err()),
+                program_error(ErrorCode::InvalidCpAmmAccount)
+            );
+        });
+    }
+    
+    #[test]
+    fn test_initialize_position_rejects_whirlpool_base_fee_range() {
+        let mut harness = Harness::new();
+        let vault = TestVault::new(&mut harness);
+        let base_mint = Pubkey::new_unique();
+        harness.add_mint(base_mint, 9);
+        
+        let pool = MockPoolConfig::new(vault.quote_mint, base_mint)
+            .with_current_tick(1000)
+            .with_tick_spacing(64);
+        harness.set_data(pool.pool_id, whirlpool::program_id(), whirlpool_pool_data(&pool));
+        let position = vault.new_position(&mut harness, &pool, AmmKind::OrcaWhirlpool, 1_000);
+        
+        // Quote is token A, so a range straddling the price would accrue base fees
+        let straddling = position_params(AmmKind::OrcaWhirlpool, 960, 1088);
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, straddling.clone()),
+            Err(program_error(ErrorCode::PositionWouldAccrueBaseFees))
+        );
+        
+        // A Whirlpool account that is not a pool is refused before any range check
+        let mut not_a_pool = whirlpool_pool_data(&pool);
+        not_a_pool[..8].copy_from_slice(&account_discriminator("TickArray"));
+        harness.set_data(pool.pool_id, whirlpool::program_id(), not_a_pool);
+        assert_eq!(
+            vault.initialize_position(&mut harness, &position, straddling),
+            Err(program_error(ErrorCode::InvalidCpAmmAccount))
+        );
+        
+        // Nothing was registered
+        assert!(harness.is_closed(&position.position_state));
+        assert!(vault.policy_state(&harness).position_pools.is_empty());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,404 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token;
+use star_honorary_fee_position::{cp_amm, damm_v2};
+use std::rc::Rc;
+
+use super::cp_amm_setup::{clmm_personal_position_data, damm_v2_position_data};
+use super::harness::{Harness, MockProgram};
+
+// PersonalPositionState: discriminator (8) | bump (1) | nft_mint (32) | pool_id (32) | tick_lower (4) |
+// tick_upper (4) | liquidity (16) | fee_growth_inside (32) | token_fees_owed_0 (8) | token_fees_owed_1 (8)
+const PERSONAL_POSITION_NFT_MINT: usize = 9;
+const PERSONAL_POSITION_LIQUIDITY: usize = 81;
+const PERSONAL_POSITION_FEES_OWED: usize = 129;
+
+// DAMM v2 Position: discriminator (8) | pool (32) | nft_mint (32) | fee checkpoints (64) |
+// fee_a_pending (8) | fee_b_pending (8) | unlocked_liquidity (16)
+const DAMM_V2_POSITION_NFT_MINT: usize = 40;
+const DAMM_V2_POSITION_FEE_PENDING: usize = 136;
+const DAMM_V2_POSITION_UNLOCKED_LIQUIDITY: usize = 152;
+
+/// Anchor instruction discriminator: sha256("global:<name>")[..8]
+pub fn instruction_discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// Arguments of CP-AMM's `open_position_v2`, as CP-AMM decodes them
+#[derive(AnchorDeserialize)]
+struct OpenPositionV2 {
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    _tick_array_lower_start_index: i32,
+    _tick_array_upper_start_index: i32,
+    liquidity: u128,
+    amount_0_max: u64,
+    amount_1_max: u64,
+    _with_metadata: bool,
+    _base_flag: Option<bool>,
+}
+
+/// Arguments of CP-AMM's `decrease_liquidity_v2`, as CP-AMM decodes them
+#[derive(AnchorDeserialize)]
+struct DecreaseLiquidityV2 {
+    liquidity: u128,
+    _amount_0_min: u64,
+    _amount_1_min: u64,
+}
+
+/// CP-AMM stand-in
+///
+/// `open_position_v2` pulls the maximum deposit from the payer's accounts into
+/// the pool vaults, writes the personal position with the requested liquidity
+/// and mints the position NFT into the owner's NFT account.
+/// `decrease_liquidity_v2` removes the liquidity from the position's books and
+/// pays its owed fees out of the pool vaults.
+pub fn cp_amm_mock() -> MockProgram {
+    cp_amm_mock_accruing((0, 0))
+}
+
+/// CP-AMM stand-in whose positions have earned `accrued` (token 0, token 1) fees
+/// not yet recorded as owed, which every `decrease_liquidity_v2` settles too
+pub fn cp_amm_mock_accruing(accrued: (u64, u64)) -> MockProgram {
+    Rc::new(move |ix, accounts| {
+        let (discriminator, mut args) = ix.data.split_at(8);
+        if discriminator == instruction_discriminator("open_position_v2") {
+            let args = OpenPositionV2::deserialize(&mut args)?;
+            clmm_open_position(accounts, &args)
+        } else if discriminator == instruction_discriminator("decrease_liquidity_v2") {
+            let args = DecreaseLiquidityV2::deserialize(&mut args)?;
+            clmm_decrease_liquidity(accounts, &args, accrued)
+        } else {
+            Err(ProgramError::InvalidInstructionData)
+        }
+    })
+}
+
+/// Record `fees_owed` (token 0, token 1) on a CP-AMM personal position
+pub fn set_clmm_fees_owed(harness: &mut Harness, personal_position: &Pubkey, fees_owed: (u64, u64)) {
+    let account = harness.accounts.get_mut(personal_position).expect("personal position not found");
+    let owed = &mut account.data[PERSONAL_POSITION_FEES_OWED..PERSONAL_POSITION_FEES_OWED + 16];
+    owed[..8].copy_from_slice(&fees_owed.0.to_le_bytes());
+    owed[8..].copy_from_slice(&fees_owed.1.to_le_bytes());
+}
+
+/// Fees (token 0, token 1) a CP-AMM personal position records as owed
+pub fn clmm_fees_owed(harness: &Harness, personal_position: &Pubkey) -> (u64, u64) {
+    let data = &harness.account(personal_position).expect("personal position not found").data;
+    let read = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    (read(PERSONAL_POSITION_FEES_OWED), read(PERSONAL_POSITION_FEES_OWED + 8))
+}
+
+fn clmm_open_position(accounts: &[AccountInfo], args: &OpenPositionV2) -> ProgramResult {
+    let [
+        payer,
+        nft_owner,
+        nft_mint,
+        nft_account,
+        _metadata_account,
+        pool,
+        _protocol_position,
+        _tick_array_lower,
+        _tick_array_upper,
+        personal_position,
+        token_account_0,
+        token_account_1,
+        token_vault_0,
+        token_vault_1,
+        ..
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if !payer.is_signer || !nft_owner.is_signer || !nft_mint.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    transfer_tokens(token_account_0, token_vault_0, payer.key, args.amount_0_max)?;
+    transfer_tokens(token_account_1, token_vault_1, payer.key, args.amount_1_max)?;
+    
+    create_account(
+        payer,
+        personal_position,
+        &cp_amm::program_id(),
+        &clmm_personal_position_data(
+            nft_mint.key,
+            pool.key,
+            args.tick_lower_index,
+            args.tick_upper_index,
+            args.liquidity,
+            (0, 0),
+        ),
+    )?;
+    mint_position_nft(payer, nft_mint, nft_account, nft_owner.key)
+}
+
+fn clmm_decrease_liquidity(accounts: &[AccountInfo], args: &DecreaseLiquidityV2, accrued: (u64, u64)) -> ProgramResult {
+    let [
+        nft_owner,
+        nft_account,
+        personal_position,
+        pool_state,
+        _protocol_position,
+        token_vault_0,
+        token_vault_1,
+        _tick_array_lower,
+        _tick_array_upper,
+        recipient_token_account_0,
+        recipient_token_account_1,
+        ..
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if !nft_owner.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let (owed_0, owed_1) = {
+        let mut data = personal_position.try_borrow_mut_data()?;
+        let nft = unpack_token_account(nft_account)?;
+        let nft_mint = &data[PERSONAL_POSITION_NFT_MINT..PERSONAL_POSITION_NFT_MINT + 32];
+        if nft.owner != *nft_owner.key || nft.amount != 1 || nft.mint.as_ref() != nft_mint {
+            return Err(ProgramError::IllegalOwner);
+        }
+        
+        let read_u64 = |data: &[u8], offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        let liquidity_bytes = &data[PERSONAL_POSITION_LIQUIDITY..PERSONAL_POSITION_LIQUIDITY + 16];
+        let liquidity = u128::from_le_bytes(liquidity_bytes.try_into().unwrap())
+            .checked_sub(args.liquidity)
+            .ok_or(ProgramError::InvalidArgument)?;
+        let owed_0 = read_u64(&data, PERSONAL_POSITION_FEES_OWED) + accrued.0;
+        let owed_1 = read_u64(&data, PERSONAL_POSITION_FEES_OWED + 8) + accrued.1;
+        
+        data[PERSONAL_POSITION_LIQUIDITY..PERSONAL_POSITION_LIQUIDITY + 16].copy_from_slice(&liquidity.to_le_bytes());
+        data[PERSONAL_POSITION_FEES_OWED..PERSONAL_POSITION_FEES_OWED + 16].fill(0);
+        (owed_0, owed_1)
+    };
+    
+    transfer_tokens(token_vault_0, recipient_token_account_0, pool_state.key, owed_0)?;
+    transfer_tokens(token_vault_1, recipient_token_account_1, pool_state.key, owed_1)
+}
+
+/// Arguments of DAMM v2's `add_liquidity`, as DAMM v2 decodes them
+#[derive(AnchorDeserialize)]
+struct AddLiquidity {
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+}
+
+/// Meteora DAMM v2 stand-in
+///
+/// `create_position` writes an empty position at the NFT mint's position
+/// address and mints the NFT to the owner. `add_liquidity` pulls the maximum
+/// amounts into the pool vaults with the NFT owner as transfer authority, as
+/// DAMM v2 does. `claim_position_fee` pays the position's pending fees out of
+/// the vaults, signed by the pool authority.
+pub fn damm_v2_mock() -> MockProgram {
+    Rc::new(|ix, accounts| {
+        let (discriminator, mut args) = ix.data.split_at(8);
+        if discriminator == instruction_discriminator("create_position") {
+            damm_v2_create_position(accounts)
+        } else if discriminator == instruction_discriminator("add_liquidity") {
+            let args = AddLiquidity::deserialize(&mut args)?;
+            damm_v2_add_liquidity(accounts, &args)
+        } else if discriminator == instruction_discriminator("claim_position_fee") {
+            damm_v2_claim_position_fee(accounts)
+        } else {
+            Err(ProgramError::InvalidInstructionData)
+        }
+    })
+}
+
+/// Record `fee_pending` (token A, token B) on a DAMM v2 position
+pub fn set_damm_v2_fees_pending(harness: &mut Harness, position: &Pubkey, fee_pending: (u64, u64)) {
+    let account = harness.accounts.get_mut(position).expect("position not found");
+    let pending = &mut account.data[DAMM_V2_POSITION_FEE_PENDING..DAMM_V2_POSITION_FEE_PENDING + 16];
+    pending[..8].copy_from_slice(&fee_pending.0.to_le_bytes());
+    pending[8..].copy_from_slice(&fee_pending.1.to_le_bytes());
+}
+
+/// Fees (token A, token B) pending on a DAMM v2 position
+pub fn damm_v2_fees_pending(harness: &Harness, position: &Pubkey) -> (u64, u64) {
+    let data = &harness.account(position).expect("position not found").data;
+    let read = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    (read(DAMM_V2_POSITION_FEE_PENDING), read(DAMM_V2_POSITION_FEE_PENDING + 8))
+}
+
+fn damm_v2_create_position(accounts: &[AccountInfo]) -> ProgramResult {
+    let [owner, nft_mint, nft_account, pool, position, _pool_authority, payer, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if !payer.is_signer || !nft_mint.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *position.key != damm_v2::position_address(nft_mint.key)
+        || *nft_account.key != damm_v2::position_nft_account_address(nft_mint.key)
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    create_account(
+        payer,
+        position,
+        &damm_v2::program_id(),
+        &damm_v2_position_data(pool.key, nft_mint.key, (0, 0), 0),
+    )?;
+    mint_position_nft(payer, nft_mint, nft_account, owner.key)
+}
+
+fn damm_v2_add_liquidity(accounts: &[AccountInfo], args: &AddLiquidity) -> ProgramResult {
+    let [
+        _pool,
+        position,
+        token_a_account,
+        token_b_account,
+        token_a_vault,
+        token_b_vault,
+        _token_a_mint,
+        _token_b_mint,
+        nft_account,
+        owner,
+        ..
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    require_damm_v2_nft_owner(position, nft_account, owner)?;
+    
+    transfer_tokens(token_a_account, token_a_vault, owner.key, args.token_a_amount_threshold)?;
+    transfer_tokens(token_b_account, token_b_vault, owner.key, args.token_b_amount_threshold)?;
+    
+    let mut data = position.try_borrow_mut_data()?;
+    let liquidity_range = DAMM_V2_POSITION_UNLOCKED_LIQUIDITY..DAMM_V2_POSITION_UNLOCKED_LIQUIDITY + 16;
+    let liquidity = u128::from_le_bytes(data[liquidity_range.clone()].try_into().unwrap()) + args.liquidity_delta;
+    data[liquidity_range].copy_from_slice(&liquidity.to_le_bytes());
+    Ok(())
+}
+
+fn damm_v2_claim_position_fee(accounts: &[AccountInfo]) -> ProgramResult {
+    let [
+        pool_authority,
+        _pool,
+        position,
+        token_a_account,
+        token_b_account,
+        token_a_vault,
+        token_b_vault,
+        _token_a_mint,
+        _token_b_mint,
+        nft_account,
+        owner,
+        ..
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    require_damm_v2_nft_owner(position, nft_account, owner)?;
+    
+    let (fee_a, fee_b) = {
+        let mut data = position.try_borrow_mut_data()?;
+        let pending = &mut data[DAMM_V2_POSITION_FEE_PENDING..DAMM_V2_POSITION_FEE_PENDING + 16];
+        let fees = (
+            u64::from_le_bytes(pending[..8].try_into().unwrap()),
+            u64::from_le_bytes(pending[8..].try_into().unwrap()),
+        );
+        pending.fill(0);
+        fees
+    };
+    
+    transfer_tokens(token_a_vault, token_a_account, pool_authority.key, fee_a)?;
+    transfer_tokens(token_b_vault, token_b_account, pool_authority.key, fee_b)
+}
+
+/// Require `owner` to sign and hold the position's NFT in `nft_account`
+fn require_damm_v2_nft_owner(position: &AccountInfo, nft_account: &AccountInfo, owner: &AccountInfo) -> ProgramResult {
+    if !owner.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let nft = unpack_token_account(nft_account)?;
+    let data = position.try_borrow_data()?;
+    let nft_mint = &data[DAMM_V2_POSITION_NFT_MINT..DAMM_V2_POSITION_NFT_MINT + 32];
+    if nft.owner != *owner.key || nft.amount != 1 || nft.mint.as_ref() != nft_mint {
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
+
+/// Move `amount` between two token accounts of the same mint, authorized by `authority`
+pub fn transfer_tokens(from: &AccountInfo, to: &AccountInfo, authority: &Pubkey, amount: u64) -> ProgramResult {
+    let mut source = unpack_token_account(from)?;
+    let mut destination = unpack_token_account(to)?;
+    if source.mint != destination.mint {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let delegated = source.delegate.contains(authority) && source.delegated_amount >= amount;
+    if source.owner != *authority && !delegated {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    source.amount = source.amount.checked_sub(amount).ok_or(ProgramError::InsufficientFunds)?;
+    destination.amount += amount;
+    if source.owner != *authority {
+        source.delegated_amount -= amount;
+    }
+    
+    source.pack_into_slice(&mut from.try_borrow_mut_data()?[..spl_token::state::Account::LEN]);
+    destination.pack_into_slice(&mut to.try_borrow_mut_data()?[..spl_token::state::Account::LEN]);
+    Ok(())
+}
+
+fn unpack_token_account(info: &AccountInfo) -> std::result::Result<spl_token::state::Account, ProgramError> {
+    let data = info.try_borrow_data()?;
+    if data.len() < spl_token::state::Account::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    spl_token::state::Account::unpack_from_slice(&data[..spl_token::state::Account::LEN])
+}
+
+/// Create `account` owned by `owner` holding `data`, funded by `payer`
+fn create_account(payer: &AccountInfo, account: &AccountInfo, owner: &Pubkey, data: &[u8]) -> ProgramResult {
+    if account.lamports() > 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    let rent = Rent::default().minimum_balance(data.len());
+    **payer.try_borrow_mut_lamports()? = payer
+        .lamports()
+        .checked_sub(rent)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **account.try_borrow_mut_lamports()? = rent;
+    
+    account.realloc(data.len(), false)?;
+    account.try_borrow_mut_data()?.copy_from_slice(data);
+    account.assign(owner);
+    Ok(())
+}
+
+/// Create the position NFT mint with its single token held by `owner`
+fn mint_position_nft(payer: &AccountInfo, nft_mint: &AccountInfo, nft_account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    let mut mint = vec![0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        supply: 1,
+        decimals: 0,
+        is_initialized: true,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut mint);
+    create_account(payer, nft_mint, &spl_token::ID, &mint)?;
+    
+    let mut account = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint: *nft_mint.key,
+        owner: *owner,
+        amount: 1,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut account);
+    create_account(payer, nft_account, &spl_token::ID, &account)
+}
\ No newline at end of file
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use solana_sdk::signature::Keypair;
+use anchor_lang::solana_program::hash::hash;
+use solana_sdk::signature::{Keypair, Signer};
 
 /// Mock CP-AMM pool configuration
 #[derive(Debug, Clone)]
@@ -32,6 +33,146 @@ impl MockPoolConfig {
         self.current_tick = tick;
         self
     }
+    
+    pub fn with_tick_spacing(mut self, tick_spacing: u16) -> Self {
+        self.tick_spacing = tick_spacing;
+        self
+    }
+}
+
+/// Anchor account discriminator: sha256("account:<name>")[..8]
+pub fn account_discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("account:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// CP-AMM `PoolState` account data for `pool`, serialized field by field (1544 bytes)
+pub fn clmm_pool_data(pool: &MockPoolConfig) -> Vec<u8> {
+    let mut data = account_discriminator("PoolState").to_vec();
+    data.push(254); // bump
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // amm_config
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // owner
+    data.extend_from_slice(pool.token_0_mint.as_ref());
+    data.extend_from_slice(pool.token_1_mint.as_ref());
+    data.extend_from_slice(pool.token_0_vault.as_ref());
+    data.extend_from_slice(pool.token_1_vault.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // observation_key
+    data.extend_from_slice(&[6, 9]); // mint_decimals_0, mint_decimals_1
+    data.extend_from_slice(&pool.tick_spacing.to_le_bytes());
+    data.extend_from_slice(&1_000_000u128.to_le_bytes()); // liquidity
+    data.extend_from_slice(&pool.sqrt_price_x64.to_le_bytes());
+    data.extend_from_slice(&pool.current_tick.to_le_bytes());
+    data.extend_from_slice(&[0; 4]); // padding3, padding4
+    data.extend_from_slice(&[0xEF; 32]); // fee_growth_global_0_x64, fee_growth_global_1_x64
+    data.extend_from_slice(&[0; 16]); // protocol_fees_token_0, protocol_fees_token_1
+    data.extend_from_slice(&[0; 64]); // swap in / out amounts
+    data.push(0); // status
+    data.extend_from_slice(&[0; 7]); // padding
+    data.extend_from_slice(&[0xAB; 3 * 169]); // reward_infos
+    data.extend_from_slice(&[0; 128]); // tick_array_bitmap
+    data.extend_from_slice(&[0; 64]); // fee totals, open_time, recent_epoch
+    data.extend_from_slice(&[0; 448]); // padding1, padding2
+    data
+}
+
+/// CP-AMM `PersonalPositionState` account data, serialized field by field (281 bytes)
+pub fn clmm_personal_position_data(
+    nft_mint: &Pubkey,
+    pool: &Pubkey,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+    fees_owed: (u64, u64),
+) -> Vec<u8> {
+    let mut data = account_discriminator("PersonalPositionState").to_vec();
+    data.push(253); // bump
+    data.extend_from_slice(nft_mint.as_ref());
+    data.extend_from_slice(pool.as_ref());
+    data.extend_from_slice(&tick_lower.to_le_bytes());
+    data.extend_from_slice(&tick_upper.to_le_bytes());
+    data.extend_from_slice(&liquidity.to_le_bytes());
+    data.extend_from_slice(&[0xEF; 32]); // fee_growth_inside_0_last_x64, fee_growth_inside_1_last_x64
+    data.extend_from_slice(&fees_owed.0.to_le_bytes());
+    data.extend_from_slice(&fees_owed.1.to_le_bytes());
+    data.extend_from_slice(&[0; 3 * 24]); // reward_infos
+    data.extend_from_slice(&[0; 8]); // recent_epoch
+    data.extend_from_slice(&[0; 56]); // padding
+    data
+}
+
+/// Meteora DAMM v2 `Position` account data, serialized field by field (408 bytes)
+pub fn damm_v2_position_data(pool: &Pubkey, nft_mint: &Pubkey, fee_pending: (u64, u64), unlocked_liquidity: u128) -> Vec<u8> {
+    let mut data = account_discriminator("Position").to_vec();
+    data.extend_from_slice(pool.as_ref());
+    data.extend_from_slice(nft_mint.as_ref());
+    data.extend_from_slice(&[0xEF; 64]); // fee_a_per_token_checkpoint, fee_b_per_token_checkpoint
+    data.extend_from_slice(&fee_pending.0.to_le_bytes());
+    data.extend_from_slice(&fee_pending.1.to_le_bytes());
+    data.extend_from_slice(&unlocked_liquidity.to_le_bytes());
+    data.extend_from_slice(&0u128.to_le_bytes()); // vested_liquidity
+    data.extend_from_slice(&0u128.to_le_bytes()); // permanent_locked_liquidity
+    data.extend_from_slice(&[0; 16]); // metrics
+    data.extend_from_slice(&[0; 2 * 48]); // reward_infos
+    data.extend_from_slice(&[0; 96]); // padding
+    data
+}
+
+/// Orca `Whirlpool` account data for `pool`, serialized field by field (653 bytes)
+pub fn whirlpool_pool_data(pool: &MockPoolConfig) -> Vec<u8> {
+    let mut data = account_discriminator("Whirlpool").to_vec();
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // whirlpools_config
+    data.push(255); // whirlpool_bump
+    data.extend_from_slice(&pool.tick_spacing.to_le_bytes());
+    data.extend_from_slice(&pool.tick_spacing.to_le_bytes()); // fee_tier_index_seed
+    data.extend_from_slice(&3000u16.to_le_bytes()); // fee_rate
+    data.extend_from_slice(&300u16.to_le_bytes()); // protocol_fee_rate
+    data.extend_from_slice(&1_000_000u128.to_le_bytes()); // liquidity
+    data.extend_from_slice(&pool.sqrt_price_x64.to_le_bytes());
+    data.extend_from_slice(&pool.current_tick.to_le_bytes());
+    data.extend_from_slice(&7u64.to_le_bytes()); // protocol_fee_owed_a
+    data.extend_from_slice(&11u64.to_le_bytes()); // protocol_fee_owed_b
+    data.extend_from_slice(pool.token_0_mint.as_ref());
+    data.extend_from_slice(pool.token_0_vault.as_ref());
+    data.extend_from_slice(&u128::MAX.to_le_bytes()); // fee_growth_global_a
+    data.extend_from_slice(pool.token_1_mint.as_ref());
+    data.extend_from_slice(pool.token_1_vault.as_ref());
+    data.extend_from_slice(&u128::MAX.to_le_bytes()); // fee_growth_global_b
+    data.extend_from_slice(&0u64.to_le_bytes()); // reward_last_updated_timestamp
+    data.extend_from_slice(&[0xAB; 3 * 128]); // reward_infos
+    data
+}
+
+/// Meteora DAMM v2 `Pool` account data for `pool` (token A = token 0), serialized field by field (1112 bytes)
+pub fn damm_v2_pool_data(pool: &MockPoolConfig, collect_fee_mode: u8) -> Vec<u8> {
+    let mut data = account_discriminator("Pool").to_vec();
+    data.extend_from_slice(&[0xCD; 160]); // pool_fees
+    data.extend_from_slice(pool.token_0_mint.as_ref());
+    data.extend_from_slice(pool.token_1_mint.as_ref());
+    data.extend_from_slice(pool.token_0_vault.as_ref());
+    data.extend_from_slice(pool.token_1_vault.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // whitelisted_vault
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // partner
+    data.extend_from_slice(&1_000_000u128.to_le_bytes()); // liquidity
+    data.extend_from_slice(&0u128.to_le_bytes()); // padding
+    for fee in [7u64, 11, 13, 17] {
+        data.extend_from_slice(&fee.to_le_bytes()); // protocol / partner fees
+    }
+    data.extend_from_slice(&1u128.to_le_bytes()); // sqrt_min_price
+    data.extend_from_slice(&u128::MAX.to_le_bytes()); // sqrt_max_price
+    data.extend_from_slice(&pool.sqrt_price_x64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes()); // activation_point
+    data.extend_from_slice(&[1, 0, 0, 0]); // activation_type, pool_status, token_a_flag, token_b_flag
+    data.push(collect_fee_mode);
+    data.push(0); // pool_type
+    data.extend_from_slice(&[0; 2]); // padding_0
+    data.extend_from_slice(&[0xEF; 64]); // fee_a_per_liquidity, fee_b_per_liquidity
+    data.extend_from_slice(&0u128.to_le_bytes()); // permanent_lock_liquidity
+    data.extend_from_slice(&[0; 80]); // metrics
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // creator
+    data.extend_from_slice(&[0; 48]); // padding_1
+    data.extend_from_slice(&[0xAB; 2 * 192]); // reward_infos
+    data
 }
 
 /// Calculate tick for a given price
@@ -47,23 +188,8 @@ pub fn tick_to_price(tick: i32) -> f64 {
     1.0001f64.powi(tick)
 }
 
-/// Validate quote-only position ticks
-pub fn validate_quote_only_ticks(
-    current_tick: i32,
-    tick_lower: i32,
-    tick_upper: i32,
-    is_quote_token_0: bool,
-) -> bool {
-    if is_quote_token_0 {
-        // Quote is token0: position must be above current price
-        // This means we only provide token0 (quote) liquidity
-        tick_lower > current_tick
-    } else {
-        // Quote is token1: position must be below current price
-        // This means we only provide token1 (quote) liquidity
-        tick_upper < current_tick
-    }
-}
+/// Quote-only tick validation shared with the on-chain program
+pub use star_honorary_fee_position::cp_amm::validate_quote_only_ticks;
 
 #[cfg(test)]
 mod tests {
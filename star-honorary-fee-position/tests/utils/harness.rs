@@ -0,0 +1,651 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    program_pack::Pack,
+    program_stubs::{self, SyscallStubs},
+    system_instruction::SystemInstruction,
+    system_program, sysvar,
+};
+use anchor_lang::{Event, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+        ExtensionType, StateWithExtensionsMut,
+    },
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Once;
+
+/// Handler run in place of a program that is not available in-process (e.g. an AMM)
+pub type MockProgram = Rc<dyn Fn(&Instruction, &[AccountInfo]) -> ProgramResult>;
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::default());
+    static EVENT_DATA: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+    static MOCK_PROGRAMS: RefCell<HashMap<Pubkey, MockProgram>> = RefCell::new(HashMap::new());
+}
+
+/// Account stored by the harness between instructions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+/// Runs program instructions in-process against an in-memory account store
+///
+/// Instructions go straight through `star_honorary_fee_position::entry`. CPIs into
+/// the system, SPL Token and Token-2022 programs run their real processors, other
+/// programs can be replaced with `mock_program`. The clock, rent and emitted events
+/// are served by the harness. Accounts only change when an instruction succeeds,
+/// like a transaction that rolls back on error.
+pub struct Harness {
+    pub accounts: HashMap<Pubkey, TestAccount>,
+    pub clock: Clock,
+    events: Vec<Vec<u8>>,
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(HarnessStubs));
+        });
+        MOCK_PROGRAMS.with(|mocks| mocks.borrow_mut().clear());
+        
+        let mut harness = Self {
+            accounts: HashMap::new(),
+            clock: Clock {
+                slot: 1,
+                epoch: 1,
+                unix_timestamp: 1_700_000_000,
+                ..Default::default()
+            },
+            events: Vec::new(),
+        };
+        
+        for program in [
+            star_honorary_fee_position::ID,
+            system_program::ID,
+            spl_token::ID,
+            spl_token_2022::ID,
+            anchor_spl::associated_token::ID,
+            star_honorary_fee_position::cp_amm::memo_program_id(),
+        ] {
+            harness.add_program(program);
+        }
+        
+        harness
+    }
+    
+    /// Register an executable account for `program`
+    pub fn add_program(&mut self, program: Pubkey) {
+        self.accounts.insert(
+            program,
+            TestAccount {
+                lamports: 1,
+                owner: anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+                executable: true,
+                ..Default::default()
+            },
+        );
+    }
+    
+    /// Serve CPIs into `program` with `handler`
+    pub fn mock_program(&mut self, program: Pubkey, handler: MockProgram) {
+        self.add_program(program);
+        MOCK_PROGRAMS.with(|mocks| mocks.borrow_mut().insert(program, handler));
+    }
+    
+    pub fn now(&self) -> i64 {
+        self.clock.unix_timestamp
+    }
+    
+    /// Move the clock to `unix_timestamp`
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        self.clock.unix_timestamp = unix_timestamp;
+        self.clock.slot += 1;
+    }
+    
+    /// Advance the clock by `secs`
+    pub fn warp_by(&mut self, secs: i64) {
+        self.warp_to(self.clock.unix_timestamp + secs);
+    }
+    
+    pub fn set_account(&mut self, key: Pubkey, account: TestAccount) {
+        self.accounts.insert(key, account);
+    }
+    
+    pub fn account(&self, key: &Pubkey) -> Option<&TestAccount> {
+        self.accounts.get(key)
+    }
+    
+    /// Whether `key` holds no lamports and no data (never created or closed)
+    pub fn is_closed(&self, key: &Pubkey) -> bool {
+        self.accounts
+            .get(key)
+            .is_none_or(|account| account.lamports == 0 && account.data.is_empty())
+    }
+    
+    /// Store a system-owned wallet
+    pub fn add_wallet(&mut self, key: Pubkey, lamports: u64) {
+        self.set_account(
+            key,
+            TestAccount {
+                lamports,
+                owner: system_program::ID,
+                ..Default::default()
+            },
+        );
+    }
+    
+    /// Store raw data owned by `owner`
+    pub fn set_data(&mut self, key: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let lamports = Rent::default().minimum_balance(data.len());
+        self.set_account(
+            key,
+            TestAccount {
+                lamports,
+                data,
+                owner,
+                executable: false,
+            },
+        );
+    }
+    
+    /// Store a program-owned Anchor account
+    pub fn set_anchor_account<T: AccountSerialize + Owner>(&mut self, key: Pubkey, state: &T) {
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        self.set_data(key, T::owner(), data);
+    }
+    
+    /// Deserialize a program-owned Anchor account
+    pub fn anchor_account<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).expect("account not found");
+        T::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+    
+    /// Read-modify-write an Anchor account, keeping its allocated size
+    pub fn update_anchor_account<T: AccountSerialize + AccountDeserialize>(
+        &mut self,
+        key: &Pubkey,
+        update: impl FnOnce(&mut T),
+    ) {
+        let mut state = self.anchor_account::<T>(key);
+        update(&mut state);
+        
+        let account = self.accounts.get_mut(key).unwrap();
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        data.resize(data.len().max(account.data.len()), 0);
+        account.data = data;
+    }
+    
+    /// Store an initialized SPL Token mint
+    pub fn add_mint(&mut self, key: Pubkey, decimals: u8) {
+        let mut data = vec![0u8; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: Some(Pubkey::new_unique()).into(),
+            supply: u64::MAX / 2,
+            decimals,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        }
+        .pack_into_slice(&mut data);
+        self.set_data(key, spl_token::ID, data);
+    }
+    
+    /// Store an initialized SPL Token account
+    pub fn add_token_account(&mut self, key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) {
+        let mut data = vec![0u8; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        self.set_data(key, spl_token::ID, data);
+    }
+    
+    /// Store a Token-2022 mint charging `fee_bps` (up to `maximum_fee`) on every transfer
+    pub fn add_transfer_fee_mint(&mut self, key: Pubkey, decimals: u8, fee_bps: u16, maximum_fee: u64) {
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        
+        let mut mint =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data).unwrap();
+        let transfer_fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: fee_bps.into(),
+        };
+        let config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = transfer_fee;
+        config.newer_transfer_fee = transfer_fee;
+        mint.base = spl_token_2022::state::Mint {
+            mint_authority: Some(Pubkey::new_unique()).into(),
+            supply: u64::MAX / 2,
+            decimals,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        
+        self.set_data(key, spl_token_2022::ID, data);
+    }
+    
+    /// Store a Token-2022 account of a transfer fee mint
+    pub fn add_transfer_fee_token_account(&mut self, key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) {
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+            ExtensionType::TransferFeeAmount,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        
+        let mut account =
+            StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(&mut data).unwrap();
+        account.init_extension::<TransferFeeAmount>(true).unwrap();
+        account.base = spl_token_2022::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token_2022::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        account.pack_base();
+        account.init_account_type().unwrap();
+        
+        self.set_data(key, spl_token_2022::ID, data);
+    }
+    
+    /// Base SPL Token account state (also valid for Token-2022 accounts)
+    pub fn token_account(&self, key: &Pubkey) -> spl_token::state::Account {
+        let account = self.accounts.get(key).expect("token account not found");
+        spl_token::state::Account::unpack_from_slice(&account.data[..spl_token::state::Account::LEN]).unwrap()
+    }
+    
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        self.token_account(key).amount
+    }
+    
+    /// Overwrite the balance of a token account (stand-in for fees arriving)
+    pub fn set_token_balance(&mut self, key: &Pubkey, amount: u64) {
+        let mut token_account = self.token_account(key);
+        token_account.amount = amount;
+        let account = self.accounts.get_mut(key).unwrap();
+        token_account.pack_into_slice(&mut account.data[..spl_token::state::Account::LEN]);
+    }
+    
+    /// Freeze or thaw a token account, as its mint's freeze authority would
+    pub fn set_frozen(&mut self, key: &Pubkey, frozen: bool) {
+        let mut token_account = self.token_account(key);
+        token_account.state = if frozen {
+            spl_token::state::AccountState::Frozen
+        } else {
+            spl_token::state::AccountState::Initialized
+        };
+        let account = self.accounts.get_mut(key).unwrap();
+        token_account.pack_into_slice(&mut account.data[..spl_token::state::Account::LEN]);
+    }
+    
+    /// Close an account the way its owner program would: no lamports, no data
+    pub fn close_account(&mut self, key: &Pubkey) {
+        self.set_account(
+            *key,
+            TestAccount {
+                owner: system_program::ID,
+                ..Default::default()
+            },
+        );
+    }
+    
+    /// Run a program instruction built from Anchor's generated account and data structs
+    pub fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        remaining_accounts: Vec<AccountMeta>,
+        data: impl InstructionData,
+    ) -> std::result::Result<(), ProgramError> {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(remaining_accounts);
+        
+        self.process_instruction(Instruction {
+            program_id: star_honorary_fee_position::ID,
+            accounts: metas,
+            data: data.data(),
+        })
+    }
+    
+    /// Run a raw program instruction, committing account changes only on success
+    pub fn process_instruction(&mut self, ix: Instruction) -> std::result::Result<(), ProgramError> {
+        CLOCK.with(|clock| *clock.borrow_mut() = self.clock.clone());
+        EVENT_DATA.with(|events| events.borrow_mut().clear());
+        
+        // One AccountInfo per key; duplicated metas share it like the runtime does
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut flags: HashMap<Pubkey, (bool, bool)> = HashMap::new();
+        for meta in &ix.accounts {
+            let entry = flags.entry(meta.pubkey).or_insert_with(|| {
+                keys.push(meta.pubkey);
+                (false, false)
+            });
+            entry.0 |= meta.is_signer;
+            entry.1 |= meta.is_writable;
+        }
+        
+        let mut infos: HashMap<Pubkey, AccountInfo<'static>> = HashMap::new();
+        for key in &keys {
+            let (is_signer, is_writable) = flags[key];
+            let account = self.sysvar_account(key)
+                .or_else(|| self.accounts.get(key).cloned())
+                .unwrap_or_else(|| TestAccount {
+                    owner: system_program::ID,
+                    ..Default::default()
+                });
+            infos.insert(*key, leak_account_info(*key, account, is_signer, is_writable));
+        }
+        
+        let account_infos: &'static [AccountInfo<'static>] = Box::leak(
+            ix.accounts
+                .iter()
+                .map(|meta| infos[&meta.pubkey].clone())
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        );
+        
+        star_honorary_fee_position::entry(&ix.program_id, account_infos, &ix.data)?;
+        
+        for (key, info) in infos {
+            if sysvar::is_sysvar_id(&key) || info.executable {
+                continue;
+            }
+            // The runtime drops accounts left without lamports (e.g. closed token accounts)
+            if info.lamports() == 0 {
+                self.accounts.remove(&key);
+                continue;
+            }
+            self.accounts.insert(
+                key,
+                TestAccount {
+                    lamports: info.lamports(),
+                    data: info.data.borrow().to_vec(),
+                    owner: *info.owner,
+                    executable: false,
+                },
+            );
+        }
+        self.events = EVENT_DATA.with(|events| events.borrow_mut().drain(..).collect());
+        
+        Ok(())
+    }
+    
+    /// Events of type `E` emitted by the last successful instruction
+    pub fn events<E: Event + AnchorDeserialize>(&self) -> Vec<E> {
+        self.events
+            .iter()
+            .filter(|data| data.len() >= 8 && data[..8] == E::DISCRIMINATOR)
+            .map(|data| E::try_from_slice(&data[8..]).unwrap())
+            .collect()
+    }
+    
+    fn sysvar_account(&self, key: &Pubkey) -> Option<TestAccount> {
+        let data = if *key == sysvar::clock::ID {
+            clock_bytes(&self.clock)
+        } else if *key == sysvar::rent::ID {
+            rent_bytes(&Rent::default())
+        } else {
+            return None;
+        };
+        
+        Some(TestAccount {
+            lamports: 1,
+            data,
+            owner: sysvar::ID,
+            executable: false,
+        })
+    }
+}
+
+/// Error an instruction fails with when it returns `error`
+pub fn program_error(error: star_honorary_fee_position::errors::ErrorCode) -> ProgramError {
+    ProgramError::from(anchor_lang::error::Error::from(error))
+}
+
+/// Account meta list entry for a writable, non-signer account
+pub fn writable(key: Pubkey) -> AccountMeta {
+    AccountMeta::new(key, false)
+}
+
+/// Account meta list entry for a read-only, non-signer account
+pub fn readonly(key: Pubkey) -> AccountMeta {
+    AccountMeta::new_readonly(key, false)
+}
+
+fn clock_bytes(clock: &Clock) -> Vec<u8> {
+    [
+        clock.slot.to_le_bytes(),
+        clock.epoch_start_timestamp.to_le_bytes(),
+        clock.epoch.to_le_bytes(),
+        clock.leader_schedule_epoch.to_le_bytes(),
+        clock.unix_timestamp.to_le_bytes(),
+    ]
+    .concat()
+}
+
+fn rent_bytes(rent: &Rent) -> Vec<u8> {
+    let mut data = Vec::with_capacity(17);
+    data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
+
+/// Build an `AccountInfo` over leaked buffers laid out like the runtime's input
+///
+/// The key is preceded by its original data length and the data by its current
+/// length, with `MAX_PERMITTED_DATA_INCREASE` bytes of headroom, so
+/// `AccountInfo::realloc` and the account closing it relies on work in-process.
+fn leak_account_info(
+    key: Pubkey,
+    account: TestAccount,
+    is_signer: bool,
+    is_writable: bool,
+) -> AccountInfo<'static> {
+    let data_len = account.data.len();
+    
+    let key_words: &'static mut [u64; 5] = Box::leak(Box::new([0u64; 5]));
+    let key_ptr = key_words.as_mut_ptr() as *mut u8;
+    let data_words = (data_len + MAX_PERMITTED_DATA_INCREASE).div_ceil(8) + 1;
+    let data_buf: &'static mut [u64] = Box::leak(vec![0u64; data_words].into_boxed_slice());
+    let data_ptr = data_buf.as_mut_ptr() as *mut u8;
+    
+    // SAFETY: both buffers are leaked, 8-byte aligned and large enough for the
+    // headers written in front of the key and data
+    let (key_ref, data) = unsafe {
+        (key_ptr as *mut u32).write(data_len as u32);
+        std::ptr::copy_nonoverlapping(key.as_ref().as_ptr(), key_ptr.add(4), 32);
+        (data_ptr as *mut u64).write(data_len as u64);
+        std::ptr::copy_nonoverlapping(account.data.as_ptr(), data_ptr.add(8), data_len);
+        (
+            &*(key_ptr.add(4) as *const Pubkey),
+            std::slice::from_raw_parts_mut(data_ptr.add(8), data_len),
+        )
+    };
+    
+    AccountInfo::new(
+        key_ref,
+        is_signer,
+        is_writable,
+        Box::leak(Box::new(account.lamports)),
+        data,
+        Box::leak(Box::new(account.owner)),
+        account.executable,
+        0,
+    )
+}
+
+/// Resize an account's data in place, within the headroom left by `leak_account_info`
+fn resize_data(info: &AccountInfo, new_len: usize) -> ProgramResult {
+    // SAFETY: written by `leak_account_info` in front of the key
+    let original_len = unsafe { info.original_data_len() };
+    let mut data = info.try_borrow_mut_data()?;
+    if new_len > original_len + MAX_PERMITTED_DATA_INCREASE {
+        return Err(ProgramError::InvalidRealloc);
+    }
+    
+    // SAFETY: the buffer behind every harness account has room for the new length
+    unsafe {
+        let data_ptr = data.as_mut_ptr();
+        *(data_ptr.offset(-8) as *mut u64) = new_len as u64;
+        *data = std::slice::from_raw_parts_mut(data_ptr, new_len);
+    }
+    data.fill(0);
+    
+    Ok(())
+}
+
+/// The subset of the system program the program's CPIs use
+fn process_system_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let instruction: SystemInstruction =
+        decode_system_instruction(data).ok_or(ProgramError::InvalidInstructionData)?;
+    
+    match instruction {
+        SystemInstruction::CreateAccount { lamports, space, owner } => {
+            let (from, to) = (&accounts[0], &accounts[1]);
+            if to.lamports() > 0 || !to.data_is_empty() {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            move_lamports(from, to, lamports)?;
+            resize_data(to, space as usize)?;
+            to.assign(&owner);
+        }
+        SystemInstruction::Transfer { lamports } => {
+            move_lamports(&accounts[0], &accounts[1], lamports)?;
+        }
+        SystemInstruction::Allocate { space } => {
+            resize_data(&accounts[0], space as usize)?;
+        }
+        SystemInstruction::Assign { owner } => {
+            accounts[0].assign(&owner);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    
+    Ok(())
+}
+
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    let remaining = from.lamports().checked_sub(lamports).ok_or(ProgramError::InsufficientFunds)?;
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+/// Decode the system instruction variants used above (bincode, u32 variant tag)
+fn decode_system_instruction(data: &[u8]) -> Option<SystemInstruction> {
+    let tag = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+    let u64_at = |offset: usize| Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?));
+    let pubkey_at = |offset: usize| Pubkey::try_from(data.get(offset..offset + 32)?).ok();
+    
+    match tag {
+        0 => Some(SystemInstruction::CreateAccount {
+            lamports: u64_at(4)?,
+            space: u64_at(12)?,
+            owner: pubkey_at(20)?,
+        }),
+        1 => Some(SystemInstruction::Assign { owner: pubkey_at(4)? }),
+        2 => Some(SystemInstruction::Transfer { lamports: u64_at(4)? }),
+        8 => Some(SystemInstruction::Allocate { space: u64_at(4)? }),
+        _ => None,
+    }
+}
+
+struct HarnessStubs;
+
+impl SyscallStubs for HarnessStubs {
+    fn sol_log(&self, _message: &str) {}
+    
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        EVENT_DATA.with(|events| events.borrow_mut().push(fields.concat()));
+    }
+    
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = CLOCK.with(|clock| clock.borrow().clone());
+        unsafe { (var_addr as *mut Clock).write(clock) };
+        SUCCESS
+    }
+    
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { (var_addr as *mut Rent).write(Rent::default()) };
+        SUCCESS
+    }
+    
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let mut accounts = Vec::with_capacity(instruction.accounts.len());
+        
+        for meta in &instruction.accounts {
+            let mut info = account_infos
+                .iter()
+                .find(|info| *info.key == meta.pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?
+                .clone();
+            
+            // Only the caller's own PDAs may be signed for
+            let pda_signed = signers_seeds.iter().any(|seeds| {
+                Pubkey::create_program_address(seeds, &star_honorary_fee_position::ID)
+                    .is_ok_and(|pda| pda == meta.pubkey)
+            });
+            if meta.is_signer && !info.is_signer && !pda_signed {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if meta.is_writable && !info.is_writable {
+                return Err(ProgramError::InvalidArgument);
+            }
+            
+            info.is_signer = meta.is_signer;
+            info.is_writable = meta.is_writable;
+            accounts.push(info);
+        }
+        
+        let program_id = instruction.program_id;
+        if program_id == spl_token::ID {
+            spl_token::processor::Processor::process(&program_id, &accounts, &instruction.data)
+        } else if program_id == spl_token_2022::ID {
+            spl_token_2022::processor::Processor::process(&program_id, &accounts, &instruction.data)
+        } else if program_id == system_program::ID {
+            process_system_instruction(&accounts, &instruction.data)
+        } else {
+            let mock = MOCK_PROGRAMS.with(|mocks| mocks.borrow().get(&program_id).cloned());
+            match mock {
+                Some(handler) => handler(instruction, &accounts),
+                None => Err(ProgramError::IncorrectProgramId),
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,14 @@
+pub mod amm_mock;
 pub mod cp_amm_setup;
+pub mod harness;
 pub mod streamflow_mock;
+pub mod vault_setup;
 
+pub use amm_mock::*;
 pub use cp_amm_setup::*;
+pub use harness::*;
 pub use streamflow_mock::*;
+pub use vault_setup::*;
 
 use anchor_lang::prelude::*;
 use solana_program_test::*;
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use star_honorary_fee_position::constants::STREAMFLOW_MIN_VERSION;
+use star_honorary_fee_position::streamflow::StreamflowContract;
 
 /// Mock Streamflow stream data structure
 /// Simplified version of actual Streamflow Stream account
@@ -30,36 +32,35 @@ impl MockStreamData {
         }
     }
     
-    /// Calculate locked amount at given time
-    pub fn calculate_locked_amount(&self, current_time: i64) -> u64 {
-        // Before cliff: everything is locked
-        if current_time < self.cliff_time {
-            return self.total_amount;
-        }
-        
-        // After end: nothing is locked
-        if current_time >= self.end_time {
-            return 0;
-        }
-        
-        // During vesting: calculate based on linear vesting
-        let elapsed = current_time - self.start_time;
-        let duration = self.end_time - self.start_time;
+    /// Build the Streamflow contract this mock describes
+    ///
+    /// Linear vesting from `start_time` to `end_time` is expressed as a
+    /// one-second period release, with the amount accrued before the cliff
+    /// released as the cliff amount.
+    pub fn to_contract(&self) -> StreamflowContract {
+        let duration = (self.end_time - self.start_time).max(0) as u64;
+        let amount_per_period = if duration > 0 { self.total_amount / duration } else { 0 };
+        let cliff_elapsed = (self.cliff_time - self.start_time).max(0) as u64;
         
-        if duration == 0 {
-            return 0;
+        StreamflowContract {
+            magic: 1,
+            version: STREAMFLOW_MIN_VERSION,
+            amount_withdrawn: self.withdrawn_amount,
+            end_time: self.end_time.max(0) as u64,
+            recipient: self.beneficiary,
+            start_time: self.start_time.max(0) as u64,
+            net_amount_deposited: self.total_amount,
+            period: 1,
+            amount_per_period,
+            cliff: self.cliff_time.max(0) as u64,
+            cliff_amount: amount_per_period.saturating_mul(cliff_elapsed),
+            ..Default::default()
         }
-        
-        let vested = self.total_amount
-            .checked_mul(elapsed as u64)
-            .unwrap()
-            .checked_div(duration as u64)
-            .unwrap();
-        
-        // Locked = total - vested - withdrawn
-        self.total_amount
-            .saturating_sub(vested)
-            .saturating_sub(self.withdrawn_amount)
+    }
+    
+    /// Calculate locked amount at given time using the on-chain parser's math
+    pub fn calculate_locked_amount(&self, current_time: i64) -> u64 {
+        self.to_contract().locked_amount(current_time)
     }
 }
 
@@ -123,10 +124,35 @@ pub fn create_fully_locked_stream(
     )
 }
 
+/// Serialize a contract into Streamflow stream account data
+///
+/// Writes the fields at the offsets the program's parser reads from.
+pub fn stream_account_data(contract: &StreamflowContract) -> Vec<u8> {
+    let mut data = vec![0u8; 457];
+    let mut put = |offset: usize, bytes: &[u8]| data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    
+    put(0, &contract.magic.to_le_bytes());
+    put(8, &[contract.version]);
+    put(17, &contract.amount_withdrawn.to_le_bytes());
+    put(25, &contract.canceled_at.to_le_bytes());
+    put(33, &contract.end_time.to_le_bytes());
+    put(49, contract.sender.as_ref());
+    put(113, contract.recipient.as_ref());
+    put(177, contract.mint.as_ref());
+    put(409, &contract.start_time.to_le_bytes());
+    put(417, &contract.net_amount_deposited.to_le_bytes());
+    put(425, &contract.period.to_le_bytes());
+    put(433, &contract.amount_per_period.to_le_bytes());
+    put(441, &contract.cliff.to_le_bytes());
+    put(449, &contract.cliff_amount.to_le_bytes());
+    
+    data
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use solana_sdk::signature::Keypair;
+    use solana_sdk::signature::{Keypair, Signer};
     
     #[test]
     fn test_fully_locked_stream() {
@@ -167,4 +193,19 @@ mod tests {
         let locked = stream.calculate_locked_amount(start_time + 50);
         assert!(locked >= 450 && locked <= 550); // Should be ~500
     }
+    
+    #[test]
+    fn test_cancelled_and_withdrawn_streams() {
+        let beneficiary = Keypair::new().pubkey();
+        let mut stream = MockStreamData::new(beneficiary, 1000, 0, 100, 0);
+        
+        // Withdrawals within the vested amount do not reduce locked further
+        stream.withdrawn_amount = 300;
+        assert_eq!(stream.calculate_locked_amount(50), 500);
+        
+        // Cancelled streams have nothing locked
+        let mut contract = stream.to_contract();
+        contract.canceled_at = 60;
+        assert_eq!(contract.locked_amount(50), 0);
+    }
 }
\ No newline at end of file
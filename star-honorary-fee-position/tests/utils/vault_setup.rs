@@ -0,0 +1,610 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use star_honorary_fee_position::constants::*;
+use star_honorary_fee_position::instructions::{
+    CloseVaultParams, InitializePolicyParams, InitializePositionParams, RemoveInvestorParams, SetCreatorSplitParams,
+};
+use star_honorary_fee_position::state::{
+    AmmKind, CapOverflowMode, CreatorSplit, InvestorRegistry, PolicyState, ProgressState, SplitRecipient,
+};
+use star_honorary_fee_position::streamflow::{self, StreamflowContract};
+use star_honorary_fee_position::{accounts, amm, cp_amm, damm_v2, instruction, whirlpool};
+
+use super::cp_amm_setup::MockPoolConfig;
+use super::harness::*;
+use super::streamflow_mock::*;
+
+/// Quote decimals used by every test vault
+pub const QUOTE_DECIMALS: u8 = 6;
+
+/// Metaplex token metadata program (passed through to CP-AMM's `open_position_v2`)
+pub const METADATA_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// An investor registered with a test vault
+#[derive(Debug, Clone, Copy)]
+pub struct TestInvestor {
+    pub wallet: Pubkey,
+    pub stream: Pubkey,
+    pub quote_ata: Pubkey,
+}
+
+/// A vault set up through `initialize_policy`, with its mints and PDAs
+#[derive(Debug, Clone, Copy)]
+pub struct TestVault {
+    pub vault: Pubkey,
+    pub creator: Pubkey,
+    pub quote_mint: Pubkey,
+    pub vesting_mint: Pubkey,
+    pub stream_sender: Pubkey,
+    pub creator_quote_ata: Pubkey,
+    pub policy: Pubkey,
+    pub progress: Pubkey,
+    pub registry: Pubkey,
+    pub creator_split: Pubkey,
+    pub treasury: Pubkey,
+    pub treasury_authority: Pubkey,
+    pub position_owner: Pubkey,
+    pub quote_token_program: Pubkey,
+}
+
+/// An honorary position opened (or about to be opened) in a test pool
+#[derive(Debug, Clone)]
+pub struct TestPosition {
+    pub amm_kind: AmmKind,
+    pub pool: Pubkey,
+    pub base_mint: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub position_state: Pubkey,
+    pub base_treasury: Pubkey,
+    pub payer_quote_account: Pubkey,
+    pub payer_base_account: Pubkey,
+    
+    /// Venue accounts `initialize_position` opens with, in the adapter's layout
+    pub venue: Vec<Pubkey>,
+}
+
+impl TestPosition {
+    /// Venue accounts the crank and `remove_position` expect (the opening layout minus any open-only accounts)
+    pub fn position_venue(&self) -> Vec<AccountMeta> {
+        self.venue[..amm::position_account_count(self.amm_kind)]
+            .iter()
+            .copied()
+            .map(writable)
+            .collect()
+    }
+    
+    /// Crank position group: header then position venue accounts
+    pub fn crank_group(&self) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            readonly(self.position_state),
+            readonly(amm::program_id(self.amm_kind)),
+            writable(self.pool),
+            writable(self.base_treasury),
+            readonly(self.base_mint),
+        ];
+        metas.extend(self.position_venue());
+        metas
+    }
+}
+
+/// PDA of the program under `[VAULT_SEED, vault, ..seeds]`
+pub fn vault_pda(vault: &Pubkey, seeds: &[&[u8]]) -> Pubkey {
+    let mut all_seeds: Vec<&[u8]> = vec![VAULT_SEED, vault.as_ref()];
+    all_seeds.extend_from_slice(seeds);
+    Pubkey::find_program_address(&all_seeds, &star_honorary_fee_position::ID).0
+}
+
+/// Daily distributions, half the fees to investors, two investors per page
+pub fn default_policy_params(allowed_stream_sender: Pubkey) -> InitializePolicyParams {
+    InitializePolicyParams {
+        investor_fee_share_bps: 5000,
+        daily_cap_lamports: None,
+        min_payout_lamports: 0,
+        cap_overflow_mode: CapOverflowMode::Creator,
+        crank_reward_bps: 0,
+        crank_reward_cap_lamports: None,
+        y0_total_allocation: 1_000_000,
+        distribution_interval_secs: SECONDS_PER_DAY,
+        align_to_epoch: false,
+        max_day_duration_secs: SECONDS_PER_DAY,
+        investors_per_page: 2,
+        policy_change_delay_days: 0,
+        guardian: None,
+        allowed_stream_sender,
+    }
+}
+
+/// `initialize_position` params for `[tick_lower, tick_upper)` depositing up to 1,000 of each mint
+pub fn position_params(amm_kind: AmmKind, tick_lower: i32, tick_upper: i32) -> InitializePositionParams {
+    InitializePositionParams {
+        amm_kind,
+        tick_lower,
+        tick_upper,
+        tick_array_lower_start_index: 0,
+        tick_array_upper_start_index: 0,
+        liquidity: 1_000,
+        amount_0_max: 1_000,
+        amount_1_max: 1_000,
+    }
+}
+
+impl TestVault {
+    /// Vault with `default_policy_params`
+    pub fn new(harness: &mut Harness) -> Self {
+        Self::with_params(harness, |_| {})
+    }
+    
+    /// Vault whose `initialize_policy` params are adjusted by `configure`
+    pub fn with_params(harness: &mut Harness, configure: impl FnOnce(&mut InitializePolicyParams)) -> Self {
+        let quote_mint = Pubkey::new_unique();
+        harness.add_mint(quote_mint, QUOTE_DECIMALS);
+        Self::with_quote_mint(harness, quote_mint, configure)
+    }
+    
+    /// Vault paying out in an existing `quote_mint` (SPL Token or Token-2022)
+    pub fn with_quote_mint(
+        harness: &mut Harness,
+        quote_mint: Pubkey,
+        configure: impl FnOnce(&mut InitializePolicyParams),
+    ) -> Self {
+        let vault = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let vesting_mint = Pubkey::new_unique();
+        let stream_sender = Pubkey::new_unique();
+        let quote_token_program = harness.account(&quote_mint).unwrap().owner;
+        
+        harness.add_wallet(creator, 10_000_000_000);
+        harness.add_mint(vesting_mint, 9);
+        
+        let test_vault = Self {
+            vault,
+            creator,
+            quote_mint,
+            vesting_mint,
+            stream_sender,
+            creator_quote_ata: Pubkey::new_unique(),
+            policy: vault_pda(&vault, &[POLICY_SEED]),
+            progress: vault_pda(&vault, &[PROGRESS_SEED]),
+            registry: vault_pda(&vault, &[REGISTRY_SEED]),
+            creator_split: vault_pda(&vault, &[CREATOR_SPLIT_SEED]),
+            treasury: vault_pda(&vault, &[TREASURY_SEED]),
+            treasury_authority: vault_pda(&vault, &[TREASURY_SEED, b"authority"]),
+            position_owner: vault_pda(&vault, &[POSITION_OWNER_SEED]),
+            quote_token_program,
+        };
+        test_vault.add_quote_account(harness, test_vault.creator_quote_ata, creator);
+        
+        let mut params = default_policy_params(stream_sender);
+        configure(&mut params);
+        
+        harness
+            .process(
+                accounts::InitializePolicy {
+                    creator,
+                    vault,
+                    quote_mint,
+                    vesting_mint,
+                    creator_quote_ata: test_vault.creator_quote_ata,
+                    policy: test_vault.policy,
+                    progress: test_vault.progress,
+                    registry: test_vault.registry,
+                    creator_split: test_vault.creator_split,
+                    program_quote_treasury: test_vault.treasury,
+                    treasury_authority: test_vault.treasury_authority,
+                    token_program: quote_token_program,
+                    system_program: System::id(),
+                    rent: sysvar::rent::ID,
+                },
+                vec![],
+                instruction::InitializePolicy { params },
+            )
+            .expect("initialize_policy");
+        
+        test_vault
+    }
+    
+    pub fn policy_state(&self, harness: &Harness) -> PolicyState {
+        harness.anchor_account(&self.policy)
+    }
+    
+    pub fn progress_state(&self, harness: &Harness) -> ProgressState {
+        harness.anchor_account(&self.progress)
+    }
+    
+    pub fn registry_state(&self, harness: &Harness) -> InvestorRegistry {
+        harness.anchor_account(&self.registry)
+    }
+    
+    pub fn creator_split_state(&self, harness: &Harness) -> CreatorSplit {
+        harness.anchor_account(&self.creator_split)
+    }
+    
+    /// Create an empty quote token account for `owner` under the quote mint's token program
+    pub fn add_quote_account(&self, harness: &mut Harness, key: Pubkey, owner: Pubkey) {
+        if self.quote_token_program == spl_token::ID {
+            harness.add_token_account(key, self.quote_mint, owner, 0);
+        } else {
+            harness.add_transfer_fee_token_account(key, self.quote_mint, owner, 0);
+        }
+    }
+    
+    /// Stream locking `locked` vesting tokens for `beneficiary` until well past the test horizon
+    pub fn locked_stream(&self, harness: &Harness, beneficiary: Pubkey, locked: u64) -> StreamflowContract {
+        let mut contract = create_fully_locked_stream(beneficiary, locked, harness.now()).to_contract();
+        contract.sender = self.stream_sender;
+        contract.mint = self.vesting_mint;
+        contract
+    }
+    
+    /// Register an investor whose stream keeps `locked` tokens locked
+    pub fn add_investor(&self, harness: &mut Harness, locked: u64) -> TestInvestor {
+        let wallet = Pubkey::new_unique();
+        let contract = self.locked_stream(harness, wallet, locked);
+        self.add_investor_with_stream(harness, wallet, &contract)
+    }
+    
+    /// Register an investor holding the Streamflow stream `contract`
+    pub fn add_investor_with_stream(
+        &self,
+        harness: &mut Harness,
+        wallet: Pubkey,
+        contract: &StreamflowContract,
+    ) -> TestInvestor {
+        let investor = TestInvestor {
+            wallet,
+            stream: Pubkey::new_unique(),
+            quote_ata: Pubkey::new_unique(),
+        };
+        harness.set_data(investor.stream, streamflow::program_id(), stream_account_data(contract));
+        self.add_quote_account(harness, investor.quote_ata, wallet);
+        
+        self.register_investor(harness, investor.stream, investor.quote_ata)
+            .expect("add_investor");
+        
+        investor
+    }
+    
+    /// Send `add_investor` for existing stream and quote accounts
+    pub fn register_investor(
+        &self,
+        harness: &mut Harness,
+        stream: Pubkey,
+        investor_quote_ata: Pubkey,
+    ) -> std::result::Result<(), ProgramError> {
+        harness.process(
+            accounts::AddInvestor {
+                creator: self.creator,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                registry: self.registry,
+                stream,
+                investor_quote_ata,
+            },
+            vec![],
+            instruction::AddInvestor {},
+        )
+    }
+    
+    /// Send `remove_investor` for the investor holding `stream`
+    pub fn remove_investor(&self, harness: &mut Harness, stream: Pubkey) -> std::result::Result<(), ProgramError> {
+        harness.process(
+            accounts::RemoveInvestor {
+                creator: self.creator,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                registry: self.registry,
+            },
+            vec![],
+            instruction::RemoveInvestor {
+                params: RemoveInvestorParams { stream },
+            },
+        )
+    }
+    
+    /// Replace the creator split through `set_creator_split`
+    pub fn set_creator_split(
+        &self,
+        harness: &mut Harness,
+        recipients: Vec<SplitRecipient>,
+    ) -> std::result::Result<(), ProgramError> {
+        let recipient_accounts = recipients.iter().map(|recipient| readonly(recipient.quote_ata)).collect();
+        harness.process(
+            accounts::SetCreatorSplit {
+                creator: self.creator,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                creator_split: self.creator_split,
+            },
+            recipient_accounts,
+            instruction::SetCreatorSplit {
+                params: SetCreatorSplitParams { recipients },
+            },
+        )
+    }
+    
+    /// Close the vault through `close_vault`, passing `streams` as remaining accounts
+    pub fn close_vault(
+        &self,
+        harness: &mut Harness,
+        recipient: Pubkey,
+        creator_opt_out: bool,
+        streams: &[Pubkey],
+    ) -> std::result::Result<(), ProgramError> {
+        harness.process(
+            accounts::CloseVault {
+                creator: self.creator,
+                recipient,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                registry: self.registry,
+                creator_split: self.creator_split,
+                program_quote_treasury: self.treasury,
+                treasury_authority: self.treasury_authority,
+                creator_quote_ata: self.creator_quote_ata,
+                quote_mint: self.quote_mint,
+                quote_token_program: self.quote_token_program,
+                clock: sysvar::clock::ID,
+            },
+            streams.iter().copied().map(readonly).collect(),
+            instruction::CloseVault {
+                params: CloseVaultParams { creator_opt_out },
+            },
+        )
+    }
+    
+    /// Pause distributions as `authority` (creator or guardian)
+    pub fn pause(&self, harness: &mut Harness, authority: Pubkey) -> std::result::Result<(), ProgramError> {
+        harness.process(
+            accounts::Pause {
+                authority,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                clock: sysvar::clock::ID,
+            },
+            vec![],
+            instruction::Pause {},
+        )
+    }
+    
+    /// Resume distributions as `authority` (creator or guardian)
+    pub fn unpause(&self, harness: &mut Harness, authority: Pubkey) -> std::result::Result<(), ProgramError> {
+        harness.process(
+            accounts::Unpause {
+                authority,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                clock: sysvar::clock::ID,
+            },
+            vec![],
+            instruction::Unpause {},
+        )
+    }
+    
+    /// Force-close a stale day, passing the split recipients when the snapshot was complete
+    pub fn force_close_day(&self, harness: &mut Harness, caller: Pubkey) -> std::result::Result<(), ProgramError> {
+        let recipients = if self.progress_state(harness).locked_snapshot_complete {
+            self.split_recipient_accounts(harness)
+        } else {
+            vec![]
+        };
+        harness.process(
+            accounts::ForceCloseDay {
+                caller,
+                vault: self.vault,
+                policy: self.policy,
+                progress: self.progress,
+                registry: self.registry,
+                creator_split: self.creator_split,
+                program_quote_treasury: self.treasury,
+                treasury_authority: self.treasury_authority,
+                creator_quote_ata: self.creator_quote_ata,
+                quote_mint: self.quote_mint,
+                quote_token_program: self.quote_token_program,
+                clock: sysvar::clock::ID,
+            },
+            recipients,
+            instruction::ForceCloseDay {},
+        )
+    }
+    
+    /// Position in `pool` funded from new creator token accounts holding `funding` of each mint
+    ///
+    /// The pool account and the base mint must already be in the harness. Venue
+    /// accounts the venue derives from the position NFT mint are derived the same way.
+    pub fn new_position(
+        &self,
+        harness: &mut Harness,
+        pool: &MockPoolConfig,
+        amm_kind: AmmKind,
+        funding: u64,
+    ) -> TestPosition {
+        let base_mint = if pool.token_0_mint == self.quote_mint { pool.token_1_mint } else { pool.token_0_mint };
+        let position_nft_mint = Pubkey::new_unique();
+        let nft_ata = get_associated_token_address(&self.position_owner, &position_nft_mint);
+        let venue = match amm_kind {
+            AmmKind::RaydiumClmm => vec![
+                Pubkey::new_unique(), // protocol_position
+                Pubkey::new_unique(), // personal_position
+                pool.token_0_vault,
+                pool.token_1_vault,
+                Pubkey::new_unique(), // tick_array_lower
+                Pubkey::new_unique(), // tick_array_upper
+                nft_ata,
+                Pubkey::new_unique(), // metadata_account
+            ],
+            AmmKind::MeteoraDammV2 => vec![
+                damm_v2::position_address(&position_nft_mint),
+                pool.token_0_vault,
+                pool.token_1_vault,
+                damm_v2::position_nft_account_address(&position_nft_mint),
+                damm_v2::pool_authority(),
+                damm_v2::event_authority(),
+            ],
+            AmmKind::OrcaWhirlpool => vec![
+                whirlpool::position_address(&position_nft_mint).0,
+                nft_ata,
+                pool.token_0_vault,
+                pool.token_1_vault,
+                Pubkey::new_unique(), // tick_array_lower
+                Pubkey::new_unique(), // tick_array_upper
+            ],
+        };
+        let position = TestPosition {
+            amm_kind,
+            pool: pool.pool_id,
+            base_mint,
+            position_nft_mint,
+            position_state: vault_pda(&self.vault, &[POSITION_STATE_SEED, pool.pool_id.as_ref()]),
+            base_treasury: vault_pda(&self.vault, &[BASE_TREASURY_SEED, pool.pool_id.as_ref()]),
+            payer_quote_account: Pubkey::new_unique(),
+            payer_base_account: Pubkey::new_unique(),
+            venue,
+        };
+        
+        self.add_quote_account(harness, position.payer_quote_account, self.creator);
+        harness.set_token_balance(&position.payer_quote_account, funding);
+        harness.add_token_account(position.payer_base_account, base_mint, self.creator, funding);
+        
+        position
+    }
+    
+    /// Send `initialize_position` for `position` with its venue accounts
+    pub fn initialize_position(
+        &self,
+        harness: &mut Harness,
+        position: &TestPosition,
+        params: InitializePositionParams,
+    ) -> std::result::Result<(), ProgramError> {
+        let base_token_program = harness.account(&position.base_mint).unwrap().owner;
+        harness.process(
+            accounts::InitializePosition {
+                payer: self.creator,
+                vault: self.vault,
+                policy: self.policy,
+                position_state: position.position_state,
+                position_owner_pda: self.position_owner,
+                pool: position.pool,
+                quote_mint: self.quote_mint,
+                base_mint: position.base_mint,
+                program_base_treasury: position.base_treasury,
+                treasury_authority: self.treasury_authority,
+                position_nft_mint: position.position_nft_mint,
+                payer_quote_account: position.payer_quote_account,
+                payer_base_account: position.payer_base_account,
+                amm_program: amm::program_id(params.amm_kind),
+                token_program: spl_token::ID,
+                token_program_2022: anchor_spl::token_2022::ID,
+                quote_token_program: self.quote_token_program,
+                base_token_program,
+                memo_program: cp_amm::memo_program_id(),
+                system_program: System::id(),
+                rent: sysvar::rent::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                metadata_program: METADATA_PROGRAM_ID,
+            },
+            position.venue.iter().copied().map(writable).collect(),
+            instruction::InitializePosition { params },
+        )
+    }
+    
+    /// Make `amount` of quote claimable by the next day's first page
+    ///
+    /// Stands in for an AMM fee claim: quote rolled over into the next day is
+    /// counted by its first page exactly like fees collected from a position.
+    pub fn seed_claim(&self, harness: &mut Harness, amount: u64) {
+        let balance = harness.token_balance(&self.treasury);
+        harness.set_token_balance(&self.treasury, balance + amount);
+        harness.update_anchor_account::<ProgressState>(&self.progress, |progress| {
+            progress.rolled_over_claimed_quote += amount;
+        });
+    }
+    
+    pub fn crank_accounts(&self, cranker: Pubkey, cranker_quote_ata: Option<Pubkey>) -> accounts::CrankDistribution {
+        accounts::CrankDistribution {
+            cranker,
+            vault: self.vault,
+            policy: self.policy,
+            progress: self.progress,
+            registry: self.registry,
+            creator_split: self.creator_split,
+            position_owner_pda: self.position_owner,
+            program_quote_treasury: self.treasury,
+            treasury_authority: self.treasury_authority,
+            creator_quote_ata: self.creator_quote_ata,
+            cranker_quote_ata,
+            quote_mint: self.quote_mint,
+            token_program: spl_token::ID,
+            token_program_2022: anchor_spl::token_2022::ID,
+            quote_token_program: self.quote_token_program,
+            memo_program: star_honorary_fee_position::cp_amm::memo_program_id(),
+            clock: sysvar::clock::ID,
+        }
+    }
+    
+    /// Investor accounts of the page at `cursor`, in registry order
+    pub fn investor_accounts(&self, harness: &Harness, cursor: u32) -> Vec<AccountMeta> {
+        self.registry_state(harness)
+            .page(cursor)
+            .iter()
+            .flat_map(|entry| [readonly(entry.stream), writable(entry.investor_quote_ata)])
+            .collect()
+    }
+    
+    /// Quote ATAs of the creator split recipients, in split order
+    pub fn split_recipient_accounts(&self, harness: &Harness) -> Vec<AccountMeta> {
+        self.creator_split_state(harness)
+            .recipients
+            .iter()
+            .map(|recipient| writable(recipient.quote_ata))
+            .collect()
+    }
+    
+    /// Remaining accounts the crank expects for the current page
+    pub fn page_accounts(&self, harness: &Harness) -> Vec<AccountMeta> {
+        let progress = self.progress_state(harness);
+        let registry = self.registry_state(harness);
+        let cursor = if progress.day_complete { 0 } else { progress.pagination_cursor };
+        
+        let mut metas = self.investor_accounts(harness, cursor);
+        let is_last_page = cursor + 1 >= registry.page_count();
+        if is_last_page && progress.locked_snapshot_complete && !progress.day_complete {
+            metas.extend(self.split_recipient_accounts(harness));
+        }
+        metas
+    }
+    
+    /// Send one crank page with the accounts the current cursor expects
+    pub fn crank_page(&self, harness: &mut Harness, cranker: Pubkey) -> std::result::Result<(), ProgramError> {
+        let page = self.page_accounts(harness);
+        self.crank_with(harness, cranker, page)
+    }
+    
+    /// Send one crank page with explicit remaining accounts
+    pub fn crank_with(
+        &self,
+        harness: &mut Harness,
+        cranker: Pubkey,
+        page: Vec<AccountMeta>,
+    ) -> std::result::Result<(), ProgramError> {
+        harness.process(self.crank_accounts(cranker, None), page, instruction::CrankDistribution {})
+    }
+    
+    /// Crank pages until the current day closes
+    pub fn crank_day(&self, harness: &mut Harness, cranker: Pubkey) -> std::result::Result<(), ProgramError> {
+        self.crank_page(harness, cranker)?;
+        while !self.progress_state(harness).day_complete {
+            self.crank_page(harness, cranker)?;
+        }
+        Ok(())
+    }
+}
\ No newline at end of file